@@ -27,8 +27,10 @@ fn main() {
             &[
                 "BgpAddressFamilyL2vpnEvpn",
                 "BgpAF",
+                "HugePageSize",
                 "IfType",
                 "IfRole",
+                "L4Protocol",
                 "LogLevel",
                 "OspfNetworkType",
                 "PacketDriver",
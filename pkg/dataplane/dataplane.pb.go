@@ -24,13 +24,122 @@ const (
 	_ = protoimpl.EnforceVersion(protoimpl.MaxVersion - 20)
 )
 
+type ObjectKind int32
+
+const (
+	ObjectKind_OBJECT_KIND_UNKNOWN ObjectKind = 0
+	ObjectKind_OBJECT_KIND_VRF     ObjectKind = 1
+	ObjectKind_OBJECT_KIND_VPC     ObjectKind = 2
+	ObjectKind_OBJECT_KIND_PEERING ObjectKind = 3
+	ObjectKind_OBJECT_KIND_DEVICE  ObjectKind = 4 // named after the device hostname
+)
+
+// Enum value maps for ObjectKind.
+var (
+	ObjectKind_name = map[int32]string{
+		0: "OBJECT_KIND_UNKNOWN",
+		1: "OBJECT_KIND_VRF",
+		2: "OBJECT_KIND_VPC",
+		3: "OBJECT_KIND_PEERING",
+		4: "OBJECT_KIND_DEVICE",
+	}
+	ObjectKind_value = map[string]int32{
+		"OBJECT_KIND_UNKNOWN": 0,
+		"OBJECT_KIND_VRF":     1,
+		"OBJECT_KIND_VPC":     2,
+		"OBJECT_KIND_PEERING": 3,
+		"OBJECT_KIND_DEVICE":  4,
+	}
+)
+
+func (x ObjectKind) Enum() *ObjectKind {
+	p := new(ObjectKind)
+	*p = x
+	return p
+}
+
+func (x ObjectKind) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ObjectKind) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[0].Descriptor()
+}
+
+func (ObjectKind) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[0]
+}
+
+func (x ObjectKind) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ObjectKind.Descriptor instead.
+func (ObjectKind) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{0}
+}
+
+type ApplyState int32
+
+const (
+	ApplyState_APPLY_STATE_UNKNOWN ApplyState = 0
+	ApplyState_APPLY_STATE_APPLIED ApplyState = 1 // programmed in the dataplane
+	ApplyState_APPLY_STATE_FAILED  ApplyState = 2
+	ApplyState_APPLY_STATE_SKIPPED ApplyState = 3 // not attempted, e.g. because an earlier object failed
+)
+
+// Enum value maps for ApplyState.
+var (
+	ApplyState_name = map[int32]string{
+		0: "APPLY_STATE_UNKNOWN",
+		1: "APPLY_STATE_APPLIED",
+		2: "APPLY_STATE_FAILED",
+		3: "APPLY_STATE_SKIPPED",
+	}
+	ApplyState_value = map[string]int32{
+		"APPLY_STATE_UNKNOWN": 0,
+		"APPLY_STATE_APPLIED": 1,
+		"APPLY_STATE_FAILED":  2,
+		"APPLY_STATE_SKIPPED": 3,
+	}
+)
+
+func (x ApplyState) Enum() *ApplyState {
+	p := new(ApplyState)
+	*p = x
+	return p
+}
+
+func (x ApplyState) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (ApplyState) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[1].Descriptor()
+}
+
+func (ApplyState) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[1]
+}
+
+func (x ApplyState) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use ApplyState.Descriptor instead.
+func (ApplyState) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{1}
+}
+
 type Error int32
 
 const (
-	Error_ERROR_NONE              Error = 0
-	Error_ERROR_VALIDATION_FAILED Error = 1
-	Error_ERROR_APPLY_FAILED      Error = 2
-	Error_ERROR_UNKNOWN_ERROR     Error = 3
+	Error_ERROR_NONE                Error = 0
+	Error_ERROR_VALIDATION_FAILED   Error = 1
+	Error_ERROR_APPLY_FAILED        Error = 2
+	Error_ERROR_UNKNOWN_ERROR       Error = 3
+	Error_ERROR_GENERATION_CONFLICT Error = 4 // running generation is not the expected one
+	Error_ERROR_LOCKED              Error = 5 // config is locked by another owner
 )
 
 // Enum value maps for Error.
@@ -40,12 +149,16 @@ var (
 		1: "ERROR_VALIDATION_FAILED",
 		2: "ERROR_APPLY_FAILED",
 		3: "ERROR_UNKNOWN_ERROR",
+		4: "ERROR_GENERATION_CONFLICT",
+		5: "ERROR_LOCKED",
 	}
 	Error_value = map[string]int32{
-		"ERROR_NONE":              0,
-		"ERROR_VALIDATION_FAILED": 1,
-		"ERROR_APPLY_FAILED":      2,
-		"ERROR_UNKNOWN_ERROR":     3,
+		"ERROR_NONE":                0,
+		"ERROR_VALIDATION_FAILED":   1,
+		"ERROR_APPLY_FAILED":        2,
+		"ERROR_UNKNOWN_ERROR":       3,
+		"ERROR_GENERATION_CONFLICT": 4,
+		"ERROR_LOCKED":              5,
 	}
 )
 
@@ -60,11 +173,11 @@ func (x Error) String() string {
 }
 
 func (Error) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[0].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[2].Descriptor()
 }
 
 func (Error) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[0]
+	return &file_proto_dataplane_proto_enumTypes[2]
 }
 
 func (x Error) Number() protoreflect.EnumNumber {
@@ -73,7 +186,216 @@ func (x Error) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use Error.Descriptor instead.
 func (Error) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{0}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{2}
+}
+
+// Operational state of an interface, VTEP or VNI
+type OperState int32
+
+const (
+	OperState_OPER_STATE_UNKNOWN OperState = 0
+	OperState_OPER_STATE_UP      OperState = 1
+	OperState_OPER_STATE_DOWN    OperState = 2
+)
+
+// Enum value maps for OperState.
+var (
+	OperState_name = map[int32]string{
+		0: "OPER_STATE_UNKNOWN",
+		1: "OPER_STATE_UP",
+		2: "OPER_STATE_DOWN",
+	}
+	OperState_value = map[string]int32{
+		"OPER_STATE_UNKNOWN": 0,
+		"OPER_STATE_UP":      1,
+		"OPER_STATE_DOWN":    2,
+	}
+)
+
+func (x OperState) Enum() *OperState {
+	p := new(OperState)
+	*p = x
+	return p
+}
+
+func (x OperState) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (OperState) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[3].Descriptor()
+}
+
+func (OperState) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[3]
+}
+
+func (x OperState) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use OperState.Descriptor instead.
+func (OperState) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{3}
+}
+
+// BGP finite state machine states
+type BgpSessionState int32
+
+const (
+	BgpSessionState_BGP_SESSION_STATE_IDLE         BgpSessionState = 0
+	BgpSessionState_BGP_SESSION_STATE_CONNECT      BgpSessionState = 1
+	BgpSessionState_BGP_SESSION_STATE_ACTIVE       BgpSessionState = 2
+	BgpSessionState_BGP_SESSION_STATE_OPEN_SENT    BgpSessionState = 3
+	BgpSessionState_BGP_SESSION_STATE_OPEN_CONFIRM BgpSessionState = 4
+	BgpSessionState_BGP_SESSION_STATE_ESTABLISHED  BgpSessionState = 5
+)
+
+// Enum value maps for BgpSessionState.
+var (
+	BgpSessionState_name = map[int32]string{
+		0: "BGP_SESSION_STATE_IDLE",
+		1: "BGP_SESSION_STATE_CONNECT",
+		2: "BGP_SESSION_STATE_ACTIVE",
+		3: "BGP_SESSION_STATE_OPEN_SENT",
+		4: "BGP_SESSION_STATE_OPEN_CONFIRM",
+		5: "BGP_SESSION_STATE_ESTABLISHED",
+	}
+	BgpSessionState_value = map[string]int32{
+		"BGP_SESSION_STATE_IDLE":         0,
+		"BGP_SESSION_STATE_CONNECT":      1,
+		"BGP_SESSION_STATE_ACTIVE":       2,
+		"BGP_SESSION_STATE_OPEN_SENT":    3,
+		"BGP_SESSION_STATE_OPEN_CONFIRM": 4,
+		"BGP_SESSION_STATE_ESTABLISHED":  5,
+	}
+)
+
+func (x BgpSessionState) Enum() *BgpSessionState {
+	p := new(BgpSessionState)
+	*p = x
+	return p
+}
+
+func (x BgpSessionState) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (BgpSessionState) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[4].Descriptor()
+}
+
+func (BgpSessionState) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[4]
+}
+
+func (x BgpSessionState) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use BgpSessionState.Descriptor instead.
+func (BgpSessionState) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{4}
+}
+
+// Dataplane components with separately adjustable log levels
+type LogComponent int32
+
+const (
+	LogComponent_LOG_COMPONENT_CORE    LogComponent = 0
+	LogComponent_LOG_COMPONENT_DPDK    LogComponent = 1
+	LogComponent_LOG_COMPONENT_NAT     LogComponent = 2
+	LogComponent_LOG_COMPONENT_ROUTING LogComponent = 3
+)
+
+// Enum value maps for LogComponent.
+var (
+	LogComponent_name = map[int32]string{
+		0: "LOG_COMPONENT_CORE",
+		1: "LOG_COMPONENT_DPDK",
+		2: "LOG_COMPONENT_NAT",
+		3: "LOG_COMPONENT_ROUTING",
+	}
+	LogComponent_value = map[string]int32{
+		"LOG_COMPONENT_CORE":    0,
+		"LOG_COMPONENT_DPDK":    1,
+		"LOG_COMPONENT_NAT":     2,
+		"LOG_COMPONENT_ROUTING": 3,
+	}
+)
+
+func (x LogComponent) Enum() *LogComponent {
+	p := new(LogComponent)
+	*p = x
+	return p
+}
+
+func (x LogComponent) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (LogComponent) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[5].Descriptor()
+}
+
+func (LogComponent) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[5]
+}
+
+func (x LogComponent) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use LogComponent.Descriptor instead.
+func (LogComponent) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{5}
+}
+
+// Progress of an UpdateConfig sent with async_apply
+type OperationState int32
+
+const (
+	OperationState_OPERATION_STATE_RUNNING OperationState = 0
+	OperationState_OPERATION_STATE_DONE    OperationState = 1
+)
+
+// Enum value maps for OperationState.
+var (
+	OperationState_name = map[int32]string{
+		0: "OPERATION_STATE_RUNNING",
+		1: "OPERATION_STATE_DONE",
+	}
+	OperationState_value = map[string]int32{
+		"OPERATION_STATE_RUNNING": 0,
+		"OPERATION_STATE_DONE":    1,
+	}
+)
+
+func (x OperationState) Enum() *OperationState {
+	p := new(OperationState)
+	*p = x
+	return p
+}
+
+func (x OperationState) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (OperationState) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[6].Descriptor()
+}
+
+func (OperationState) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[6]
+}
+
+func (x OperationState) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use OperationState.Descriptor instead.
+func (OperationState) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{6}
 }
 
 // OSPF Network Type
@@ -113,11 +435,11 @@ func (x OspfNetworkType) String() string {
 }
 
 func (OspfNetworkType) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[1].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[7].Descriptor()
 }
 
 func (OspfNetworkType) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[1]
+	return &file_proto_dataplane_proto_enumTypes[7]
 }
 
 func (x OspfNetworkType) Number() protoreflect.EnumNumber {
@@ -126,7 +448,7 @@ func (x OspfNetworkType) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use OspfNetworkType.Descriptor instead.
 func (OspfNetworkType) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{1}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{7}
 }
 
 // Defines interface representation on the Gateway
@@ -166,11 +488,11 @@ func (x IfType) String() string {
 }
 
 func (IfType) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[2].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[8].Descriptor()
 }
 
 func (IfType) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[2]
+	return &file_proto_dataplane_proto_enumTypes[8]
 }
 
 func (x IfType) Number() protoreflect.EnumNumber {
@@ -179,7 +501,7 @@ func (x IfType) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use IfType.Descriptor instead.
 func (IfType) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{2}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{8}
 }
 
 // For physical interface - fabric-facing or external-facing
@@ -213,11 +535,11 @@ func (x IfRole) String() string {
 }
 
 func (IfRole) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[3].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[9].Descriptor()
 }
 
 func (IfRole) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[3]
+	return &file_proto_dataplane_proto_enumTypes[9]
 }
 
 func (x IfRole) Number() protoreflect.EnumNumber {
@@ -226,7 +548,63 @@ func (x IfRole) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use IfRole.Descriptor instead.
 func (IfRole) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{3}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{9}
+}
+
+// L4 protocol matched by a filter
+type L4Protocol int32
+
+const (
+	L4Protocol_L4_PROTOCOL_ANY    L4Protocol = 0
+	L4Protocol_L4_PROTOCOL_TCP    L4Protocol = 1
+	L4Protocol_L4_PROTOCOL_UDP    L4Protocol = 2
+	L4Protocol_L4_PROTOCOL_ICMP   L4Protocol = 3
+	L4Protocol_L4_PROTOCOL_ICMPV6 L4Protocol = 4
+)
+
+// Enum value maps for L4Protocol.
+var (
+	L4Protocol_name = map[int32]string{
+		0: "L4_PROTOCOL_ANY",
+		1: "L4_PROTOCOL_TCP",
+		2: "L4_PROTOCOL_UDP",
+		3: "L4_PROTOCOL_ICMP",
+		4: "L4_PROTOCOL_ICMPV6",
+	}
+	L4Protocol_value = map[string]int32{
+		"L4_PROTOCOL_ANY":    0,
+		"L4_PROTOCOL_TCP":    1,
+		"L4_PROTOCOL_UDP":    2,
+		"L4_PROTOCOL_ICMP":   3,
+		"L4_PROTOCOL_ICMPV6": 4,
+	}
+)
+
+func (x L4Protocol) Enum() *L4Protocol {
+	p := new(L4Protocol)
+	*p = x
+	return p
+}
+
+func (x L4Protocol) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (L4Protocol) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[10].Descriptor()
+}
+
+func (L4Protocol) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[10]
+}
+
+func (x L4Protocol) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use L4Protocol.Descriptor instead.
+func (L4Protocol) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{10}
 }
 
 // AFIs supported for BGP peering
@@ -263,11 +641,11 @@ func (x BgpAF) String() string {
 }
 
 func (BgpAF) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[4].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[11].Descriptor()
 }
 
 func (BgpAF) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[4]
+	return &file_proto_dataplane_proto_enumTypes[11]
 }
 
 func (x BgpAF) Number() protoreflect.EnumNumber {
@@ -276,7 +654,54 @@ func (x BgpAF) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use BgpAF.Descriptor instead.
 func (BgpAF) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{4}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{11}
+}
+
+// Huge page size backing DPDK memory
+type HugePageSize int32
+
+const (
+	HugePageSize_HUGEPAGE_2M HugePageSize = 0
+	HugePageSize_HUGEPAGE_1G HugePageSize = 1
+)
+
+// Enum value maps for HugePageSize.
+var (
+	HugePageSize_name = map[int32]string{
+		0: "HUGEPAGE_2M",
+		1: "HUGEPAGE_1G",
+	}
+	HugePageSize_value = map[string]int32{
+		"HUGEPAGE_2M": 0,
+		"HUGEPAGE_1G": 1,
+	}
+)
+
+func (x HugePageSize) Enum() *HugePageSize {
+	p := new(HugePageSize)
+	*p = x
+	return p
+}
+
+func (x HugePageSize) String() string {
+	return protoimpl.X.EnumStringOf(x.Descriptor(), protoreflect.EnumNumber(x))
+}
+
+func (HugePageSize) Descriptor() protoreflect.EnumDescriptor {
+	return file_proto_dataplane_proto_enumTypes[12].Descriptor()
+}
+
+func (HugePageSize) Type() protoreflect.EnumType {
+	return &file_proto_dataplane_proto_enumTypes[12]
+}
+
+func (x HugePageSize) Number() protoreflect.EnumNumber {
+	return protoreflect.EnumNumber(x)
+}
+
+// Deprecated: Use HugePageSize.Descriptor instead.
+func (HugePageSize) EnumDescriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{12}
 }
 
 // Log-level for dataplane and DPDK
@@ -319,11 +744,11 @@ func (x LogLevel) String() string {
 }
 
 func (LogLevel) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[5].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[13].Descriptor()
 }
 
 func (LogLevel) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[5]
+	return &file_proto_dataplane_proto_enumTypes[13]
 }
 
 func (x LogLevel) Number() protoreflect.EnumNumber {
@@ -332,7 +757,7 @@ func (x LogLevel) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use LogLevel.Descriptor instead.
 func (LogLevel) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{5}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{13}
 }
 
 // Backend driver for packet processing
@@ -366,11 +791,11 @@ func (x PacketDriver) String() string {
 }
 
 func (PacketDriver) Descriptor() protoreflect.EnumDescriptor {
-	return file_proto_dataplane_proto_enumTypes[6].Descriptor()
+	return file_proto_dataplane_proto_enumTypes[14].Descriptor()
 }
 
 func (PacketDriver) Type() protoreflect.EnumType {
-	return &file_proto_dataplane_proto_enumTypes[6]
+	return &file_proto_dataplane_proto_enumTypes[14]
 }
 
 func (x PacketDriver) Number() protoreflect.EnumNumber {
@@ -379,7 +804,7 @@ func (x PacketDriver) Number() protoreflect.EnumNumber {
 
 // Deprecated: Use PacketDriver.Descriptor instead.
 func (PacketDriver) EnumDescriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{6}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{14}
 }
 
 type GetConfigRequest struct {
@@ -419,10 +844,14 @@ func (*GetConfigRequest) Descriptor() ([]byte, []int) {
 }
 
 type UpdateConfigRequest struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Config        *GatewayConfig         `protobuf:"bytes,1,opt,name=config,proto3" json:"config,omitempty"`
-	unknownFields protoimpl.UnknownFields
-	sizeCache     protoimpl.SizeCache
+	state                 protoimpl.MessageState `protogen:"open.v1"`
+	Config                *GatewayConfig         `protobuf:"bytes,1,opt,name=config,proto3" json:"config,omitempty"`
+	ExpectedGeneration    *int64                 `protobuf:"varint,2,opt,name=expected_generation,json=expectedGeneration,proto3,oneof" json:"expected_generation,omitempty"`      // if set, only apply if the running generation is still this one
+	Owner                 string                 `protobuf:"bytes,3,opt,name=owner,proto3" json:"owner,omitempty"`                                                                 // identity of the writer, must hold the config lock if there is one
+	ConfirmTimeoutSeconds uint32                 `protobuf:"varint,4,opt,name=confirm_timeout_seconds,json=confirmTimeoutSeconds,proto3" json:"confirm_timeout_seconds,omitempty"` // if not zero, reverted unless confirmed with ConfirmConfig within this time
+	AsyncApply            bool                   `protobuf:"varint,5,opt,name=async_apply,json=asyncApply,proto3" json:"async_apply,omitempty"`                                    // if set, return at once with an operation id, the verdict is in the operation
+	unknownFields         protoimpl.UnknownFields
+	sizeCache             protoimpl.SizeCache
 }
 
 func (x *UpdateConfigRequest) Reset() {
@@ -450,91 +879,1758 @@ func (x *UpdateConfigRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use UpdateConfigRequest.ProtoReflect.Descriptor instead.
-func (*UpdateConfigRequest) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{1}
+// Deprecated: Use UpdateConfigRequest.ProtoReflect.Descriptor instead.
+func (*UpdateConfigRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{1}
+}
+
+func (x *UpdateConfigRequest) GetConfig() *GatewayConfig {
+	if x != nil {
+		return x.Config
+	}
+	return nil
+}
+
+func (x *UpdateConfigRequest) GetExpectedGeneration() int64 {
+	if x != nil && x.ExpectedGeneration != nil {
+		return *x.ExpectedGeneration
+	}
+	return 0
+}
+
+func (x *UpdateConfigRequest) GetOwner() string {
+	if x != nil {
+		return x.Owner
+	}
+	return ""
+}
+
+func (x *UpdateConfigRequest) GetConfirmTimeoutSeconds() uint32 {
+	if x != nil {
+		return x.ConfirmTimeoutSeconds
+	}
+	return 0
+}
+
+func (x *UpdateConfigRequest) GetAsyncApply() bool {
+	if x != nil {
+		return x.AsyncApply
+	}
+	return false
+}
+
+type UpdateConfigResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Error         Error                  `protobuf:"varint,1,opt,name=error,proto3,enum=config.Error" json:"error,omitempty"`
+	Message       string                 `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	OperationId   string                 `protobuf:"bytes,3,opt,name=operation_id,json=operationId,proto3" json:"operation_id,omitempty"` // set for async_apply updates, see GetOperation
+	Results       []*ObjectResult        `protobuf:"bytes,4,rep,name=results,proto3" json:"results,omitempty"`                            // what became of the device and each VRF, VPC and peering, notably on ERROR_APPLY_FAILED
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *UpdateConfigResponse) Reset() {
+	*x = UpdateConfigResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[2]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *UpdateConfigResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*UpdateConfigResponse) ProtoMessage() {}
+
+func (x *UpdateConfigResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[2]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use UpdateConfigResponse.ProtoReflect.Descriptor instead.
+func (*UpdateConfigResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{2}
+}
+
+func (x *UpdateConfigResponse) GetError() Error {
+	if x != nil {
+		return x.Error
+	}
+	return Error_ERROR_NONE
+}
+
+func (x *UpdateConfigResponse) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+func (x *UpdateConfigResponse) GetOperationId() string {
+	if x != nil {
+		return x.OperationId
+	}
+	return ""
+}
+
+func (x *UpdateConfigResponse) GetResults() []*ObjectResult {
+	if x != nil {
+		return x.Results
+	}
+	return nil
+}
+
+// Outcome of applying one config object, identified by kind and name
+type ObjectResult struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Kind          ObjectKind             `protobuf:"varint,1,opt,name=kind,proto3,enum=config.ObjectKind" json:"kind,omitempty"`
+	Name          string                 `protobuf:"bytes,2,opt,name=name,proto3" json:"name,omitempty"`
+	State         ApplyState             `protobuf:"varint,3,opt,name=state,proto3,enum=config.ApplyState" json:"state,omitempty"`
+	Reason        string                 `protobuf:"bytes,4,opt,name=reason,proto3" json:"reason,omitempty"` // why the object failed or was skipped
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ObjectResult) Reset() {
+	*x = ObjectResult{}
+	mi := &file_proto_dataplane_proto_msgTypes[3]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ObjectResult) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ObjectResult) ProtoMessage() {}
+
+func (x *ObjectResult) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[3]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ObjectResult.ProtoReflect.Descriptor instead.
+func (*ObjectResult) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{3}
+}
+
+func (x *ObjectResult) GetKind() ObjectKind {
+	if x != nil {
+		return x.Kind
+	}
+	return ObjectKind_OBJECT_KIND_UNKNOWN
+}
+
+func (x *ObjectResult) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *ObjectResult) GetState() ApplyState {
+	if x != nil {
+		return x.State
+	}
+	return ApplyState_APPLY_STATE_UNKNOWN
+}
+
+func (x *ObjectResult) GetReason() string {
+	if x != nil {
+		return x.Reason
+	}
+	return ""
+}
+
+type GetConfigGenerationRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetConfigGenerationRequest) Reset() {
+	*x = GetConfigGenerationRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[4]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetConfigGenerationRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetConfigGenerationRequest) ProtoMessage() {}
+
+func (x *GetConfigGenerationRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[4]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetConfigGenerationRequest.ProtoReflect.Descriptor instead.
+func (*GetConfigGenerationRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{4}
+}
+
+type GetConfigGenerationResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Generation    int64                  `protobuf:"varint,1,opt,name=generation,proto3" json:"generation,omitempty"`
+	ContentHash   string                 `protobuf:"bytes,2,opt,name=content_hash,json=contentHash,proto3" json:"content_hash,omitempty"` // hex SHA-256 of the normalized config, generation excluded
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetConfigGenerationResponse) Reset() {
+	*x = GetConfigGenerationResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[5]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetConfigGenerationResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetConfigGenerationResponse) ProtoMessage() {}
+
+func (x *GetConfigGenerationResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[5]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetConfigGenerationResponse.ProtoReflect.Descriptor instead.
+func (*GetConfigGenerationResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *GetConfigGenerationResponse) GetGeneration() int64 {
+	if x != nil {
+		return x.Generation
+	}
+	return 0
+}
+
+func (x *GetConfigGenerationResponse) GetContentHash() string {
+	if x != nil {
+		return x.ContentHash
+	}
+	return ""
+}
+
+type GetStatusRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetStatusRequest) Reset() {
+	*x = GetStatusRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[6]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetStatusRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetStatusRequest) ProtoMessage() {}
+
+func (x *GetStatusRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[6]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetStatusRequest.ProtoReflect.Descriptor instead.
+func (*GetStatusRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{6}
+}
+
+// Packet and byte counters of an interface since dataplane start
+type InterfaceCounters struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	RxPackets     uint64                 `protobuf:"varint,1,opt,name=rx_packets,json=rxPackets,proto3" json:"rx_packets,omitempty"`
+	RxBytes       uint64                 `protobuf:"varint,2,opt,name=rx_bytes,json=rxBytes,proto3" json:"rx_bytes,omitempty"`
+	RxErrors      uint64                 `protobuf:"varint,3,opt,name=rx_errors,json=rxErrors,proto3" json:"rx_errors,omitempty"`
+	RxDrops       uint64                 `protobuf:"varint,4,opt,name=rx_drops,json=rxDrops,proto3" json:"rx_drops,omitempty"`
+	TxPackets     uint64                 `protobuf:"varint,5,opt,name=tx_packets,json=txPackets,proto3" json:"tx_packets,omitempty"`
+	TxBytes       uint64                 `protobuf:"varint,6,opt,name=tx_bytes,json=txBytes,proto3" json:"tx_bytes,omitempty"`
+	TxErrors      uint64                 `protobuf:"varint,7,opt,name=tx_errors,json=txErrors,proto3" json:"tx_errors,omitempty"`
+	TxDrops       uint64                 `protobuf:"varint,8,opt,name=tx_drops,json=txDrops,proto3" json:"tx_drops,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *InterfaceCounters) Reset() {
+	*x = InterfaceCounters{}
+	mi := &file_proto_dataplane_proto_msgTypes[7]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *InterfaceCounters) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*InterfaceCounters) ProtoMessage() {}
+
+func (x *InterfaceCounters) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[7]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use InterfaceCounters.ProtoReflect.Descriptor instead.
+func (*InterfaceCounters) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{7}
+}
+
+func (x *InterfaceCounters) GetRxPackets() uint64 {
+	if x != nil {
+		return x.RxPackets
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetRxBytes() uint64 {
+	if x != nil {
+		return x.RxBytes
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetRxErrors() uint64 {
+	if x != nil {
+		return x.RxErrors
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetRxDrops() uint64 {
+	if x != nil {
+		return x.RxDrops
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetTxPackets() uint64 {
+	if x != nil {
+		return x.TxPackets
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetTxBytes() uint64 {
+	if x != nil {
+		return x.TxBytes
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetTxErrors() uint64 {
+	if x != nil {
+		return x.TxErrors
+	}
+	return 0
+}
+
+func (x *InterfaceCounters) GetTxDrops() uint64 {
+	if x != nil {
+		return x.TxDrops
+	}
+	return 0
+}
+
+type InterfaceStatus struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Name          string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
+	OperState     OperState              `protobuf:"varint,2,opt,name=oper_state,json=operState,proto3,enum=config.OperState" json:"oper_state,omitempty"`
+	Counters      *InterfaceCounters     `protobuf:"bytes,3,opt,name=counters,proto3" json:"counters,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *InterfaceStatus) Reset() {
+	*x = InterfaceStatus{}
+	mi := &file_proto_dataplane_proto_msgTypes[8]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *InterfaceStatus) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*InterfaceStatus) ProtoMessage() {}
+
+func (x *InterfaceStatus) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[8]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use InterfaceStatus.ProtoReflect.Descriptor instead.
+func (*InterfaceStatus) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{8}
+}
+
+func (x *InterfaceStatus) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *InterfaceStatus) GetOperState() OperState {
+	if x != nil {
+		return x.OperState
+	}
+	return OperState_OPER_STATE_UNKNOWN
+}
+
+func (x *InterfaceStatus) GetCounters() *InterfaceCounters {
+	if x != nil {
+		return x.Counters
+	}
+	return nil
+}
+
+type BgpNeighborStatus struct {
+	state              protoimpl.MessageState `protogen:"open.v1"`
+	Vrf                string                 `protobuf:"bytes,1,opt,name=vrf,proto3" json:"vrf,omitempty"`
+	Address            string                 `protobuf:"bytes,2,opt,name=address,proto3" json:"address,omitempty"`
+	State              BgpSessionState        `protobuf:"varint,3,opt,name=state,proto3,enum=config.BgpSessionState" json:"state,omitempty"`
+	PrefixesReceived   uint64                 `protobuf:"varint,4,opt,name=prefixes_received,json=prefixesReceived,proto3" json:"prefixes_received,omitempty"`
+	PrefixesSent       uint64                 `protobuf:"varint,5,opt,name=prefixes_sent,json=prefixesSent,proto3" json:"prefixes_sent,omitempty"`
+	EstablishedSeconds uint64                 `protobuf:"varint,6,opt,name=established_seconds,json=establishedSeconds,proto3" json:"established_seconds,omitempty"` // time since the session was established, 0 if it is not
+	unknownFields      protoimpl.UnknownFields
+	sizeCache          protoimpl.SizeCache
+}
+
+func (x *BgpNeighborStatus) Reset() {
+	*x = BgpNeighborStatus{}
+	mi := &file_proto_dataplane_proto_msgTypes[9]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *BgpNeighborStatus) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BgpNeighborStatus) ProtoMessage() {}
+
+func (x *BgpNeighborStatus) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[9]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BgpNeighborStatus.ProtoReflect.Descriptor instead.
+func (*BgpNeighborStatus) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{9}
+}
+
+func (x *BgpNeighborStatus) GetVrf() string {
+	if x != nil {
+		return x.Vrf
+	}
+	return ""
+}
+
+func (x *BgpNeighborStatus) GetAddress() string {
+	if x != nil {
+		return x.Address
+	}
+	return ""
+}
+
+func (x *BgpNeighborStatus) GetState() BgpSessionState {
+	if x != nil {
+		return x.State
+	}
+	return BgpSessionState_BGP_SESSION_STATE_IDLE
+}
+
+func (x *BgpNeighborStatus) GetPrefixesReceived() uint64 {
+	if x != nil {
+		return x.PrefixesReceived
+	}
+	return 0
+}
+
+func (x *BgpNeighborStatus) GetPrefixesSent() uint64 {
+	if x != nil {
+		return x.PrefixesSent
+	}
+	return 0
+}
+
+func (x *BgpNeighborStatus) GetEstablishedSeconds() uint64 {
+	if x != nil {
+		return x.EstablishedSeconds
+	}
+	return 0
+}
+
+type VniStatus struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Vni           uint32                 `protobuf:"varint,1,opt,name=vni,proto3" json:"vni,omitempty"`
+	Vpc           string                 `protobuf:"bytes,2,opt,name=vpc,proto3" json:"vpc,omitempty"`
+	OperState     OperState              `protobuf:"varint,3,opt,name=oper_state,json=operState,proto3,enum=config.OperState" json:"oper_state,omitempty"`
+	RemoteVteps   []string               `protobuf:"bytes,4,rep,name=remote_vteps,json=remoteVteps,proto3" json:"remote_vteps,omitempty"` // addresses of the VTEPs learned for this VNI
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *VniStatus) Reset() {
+	*x = VniStatus{}
+	mi := &file_proto_dataplane_proto_msgTypes[10]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *VniStatus) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*VniStatus) ProtoMessage() {}
+
+func (x *VniStatus) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[10]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use VniStatus.ProtoReflect.Descriptor instead.
+func (*VniStatus) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{10}
+}
+
+func (x *VniStatus) GetVni() uint32 {
+	if x != nil {
+		return x.Vni
+	}
+	return 0
+}
+
+func (x *VniStatus) GetVpc() string {
+	if x != nil {
+		return x.Vpc
+	}
+	return ""
+}
+
+func (x *VniStatus) GetOperState() OperState {
+	if x != nil {
+		return x.OperState
+	}
+	return OperState_OPER_STATE_UNKNOWN
+}
+
+func (x *VniStatus) GetRemoteVteps() []string {
+	if x != nil {
+		return x.RemoteVteps
+	}
+	return nil
+}
+
+type VtepStatus struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Address       string                 `protobuf:"bytes,1,opt,name=address,proto3" json:"address,omitempty"`
+	OperState     OperState              `protobuf:"varint,2,opt,name=oper_state,json=operState,proto3,enum=config.OperState" json:"oper_state,omitempty"`
+	Vnis          []*VniStatus           `protobuf:"bytes,3,rep,name=vnis,proto3" json:"vnis,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *VtepStatus) Reset() {
+	*x = VtepStatus{}
+	mi := &file_proto_dataplane_proto_msgTypes[11]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *VtepStatus) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*VtepStatus) ProtoMessage() {}
+
+func (x *VtepStatus) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[11]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use VtepStatus.ProtoReflect.Descriptor instead.
+func (*VtepStatus) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{11}
+}
+
+func (x *VtepStatus) GetAddress() string {
+	if x != nil {
+		return x.Address
+	}
+	return ""
+}
+
+func (x *VtepStatus) GetOperState() OperState {
+	if x != nil {
+		return x.OperState
+	}
+	return OperState_OPER_STATE_UNKNOWN
+}
+
+func (x *VtepStatus) GetVnis() []*VniStatus {
+	if x != nil {
+		return x.Vnis
+	}
+	return nil
+}
+
+// What the dataplane is actually running, as opposed to what is configured
+type GetStatusResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Generation    int64                  `protobuf:"varint,1,opt,name=generation,proto3" json:"generation,omitempty"` // generation of the currently applied config
+	Interfaces    []*InterfaceStatus     `protobuf:"bytes,2,rep,name=interfaces,proto3" json:"interfaces,omitempty"`
+	BgpNeighbors  []*BgpNeighborStatus   `protobuf:"bytes,3,rep,name=bgp_neighbors,json=bgpNeighbors,proto3" json:"bgp_neighbors,omitempty"`
+	Vtep          *VtepStatus            `protobuf:"bytes,4,opt,name=vtep,proto3,oneof" json:"vtep,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetStatusResponse) Reset() {
+	*x = GetStatusResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[12]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetStatusResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetStatusResponse) ProtoMessage() {}
+
+func (x *GetStatusResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[12]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetStatusResponse.ProtoReflect.Descriptor instead.
+func (*GetStatusResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{12}
+}
+
+func (x *GetStatusResponse) GetGeneration() int64 {
+	if x != nil {
+		return x.Generation
+	}
+	return 0
+}
+
+func (x *GetStatusResponse) GetInterfaces() []*InterfaceStatus {
+	if x != nil {
+		return x.Interfaces
+	}
+	return nil
+}
+
+func (x *GetStatusResponse) GetBgpNeighbors() []*BgpNeighborStatus {
+	if x != nil {
+		return x.BgpNeighbors
+	}
+	return nil
+}
+
+func (x *GetStatusResponse) GetVtep() *VtepStatus {
+	if x != nil {
+		return x.Vtep
+	}
+	return nil
+}
+
+type GetCountersRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetCountersRequest) Reset() {
+	*x = GetCountersRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[13]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetCountersRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetCountersRequest) ProtoMessage() {}
+
+func (x *GetCountersRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[13]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetCountersRequest.ProtoReflect.Descriptor instead.
+func (*GetCountersRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{13}
+}
+
+// Packet and byte counters for one direction since dataplane start
+type TrafficCounters struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Packets       uint64                 `protobuf:"varint,1,opt,name=packets,proto3" json:"packets,omitempty"`
+	Bytes         uint64                 `protobuf:"varint,2,opt,name=bytes,proto3" json:"bytes,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *TrafficCounters) Reset() {
+	*x = TrafficCounters{}
+	mi := &file_proto_dataplane_proto_msgTypes[14]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *TrafficCounters) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TrafficCounters) ProtoMessage() {}
+
+func (x *TrafficCounters) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[14]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TrafficCounters.ProtoReflect.Descriptor instead.
+func (*TrafficCounters) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{14}
+}
+
+func (x *TrafficCounters) GetPackets() uint64 {
+	if x != nil {
+		return x.Packets
+	}
+	return 0
+}
+
+func (x *TrafficCounters) GetBytes() uint64 {
+	if x != nil {
+		return x.Bytes
+	}
+	return 0
+}
+
+// Counters of a VPC, rx is traffic entering the VPC and tx traffic leaving it
+type VpcCounters struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Vpc           string                 `protobuf:"bytes,1,opt,name=vpc,proto3" json:"vpc,omitempty"`
+	Rx            *TrafficCounters       `protobuf:"bytes,2,opt,name=rx,proto3" json:"rx,omitempty"`
+	Tx            *TrafficCounters       `protobuf:"bytes,3,opt,name=tx,proto3" json:"tx,omitempty"`
+	NatSessions   uint64                 `protobuf:"varint,4,opt,name=nat_sessions,json=natSessions,proto3" json:"nat_sessions,omitempty"` // active stateful NAT sessions
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *VpcCounters) Reset() {
+	*x = VpcCounters{}
+	mi := &file_proto_dataplane_proto_msgTypes[15]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *VpcCounters) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*VpcCounters) ProtoMessage() {}
+
+func (x *VpcCounters) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[15]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use VpcCounters.ProtoReflect.Descriptor instead.
+func (*VpcCounters) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{15}
+}
+
+func (x *VpcCounters) GetVpc() string {
+	if x != nil {
+		return x.Vpc
+	}
+	return ""
+}
+
+func (x *VpcCounters) GetRx() *TrafficCounters {
+	if x != nil {
+		return x.Rx
+	}
+	return nil
+}
+
+func (x *VpcCounters) GetTx() *TrafficCounters {
+	if x != nil {
+		return x.Tx
+	}
+	return nil
+}
+
+func (x *VpcCounters) GetNatSessions() uint64 {
+	if x != nil {
+		return x.NatSessions
+	}
+	return 0
+}
+
+// Counters of one expose entry, from the point of view of the exposing VPC
+type ExposeCounters struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Vpc           string                 `protobuf:"bytes,1,opt,name=vpc,proto3" json:"vpc,omitempty"`      // VPC of the PeeringEntryFor holding the expose
+	Index         uint32                 `protobuf:"varint,2,opt,name=index,proto3" json:"index,omitempty"` // position of the expose in that PeeringEntryFor
+	Rx            *TrafficCounters       `protobuf:"bytes,3,opt,name=rx,proto3" json:"rx,omitempty"`
+	Tx            *TrafficCounters       `protobuf:"bytes,4,opt,name=tx,proto3" json:"tx,omitempty"`
+	NatSessions   uint64                 `protobuf:"varint,5,opt,name=nat_sessions,json=natSessions,proto3" json:"nat_sessions,omitempty"` // active stateful NAT sessions
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ExposeCounters) Reset() {
+	*x = ExposeCounters{}
+	mi := &file_proto_dataplane_proto_msgTypes[16]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ExposeCounters) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ExposeCounters) ProtoMessage() {}
+
+func (x *ExposeCounters) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[16]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ExposeCounters.ProtoReflect.Descriptor instead.
+func (*ExposeCounters) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{16}
+}
+
+func (x *ExposeCounters) GetVpc() string {
+	if x != nil {
+		return x.Vpc
+	}
+	return ""
+}
+
+func (x *ExposeCounters) GetIndex() uint32 {
+	if x != nil {
+		return x.Index
+	}
+	return 0
+}
+
+func (x *ExposeCounters) GetRx() *TrafficCounters {
+	if x != nil {
+		return x.Rx
+	}
+	return nil
+}
+
+func (x *ExposeCounters) GetTx() *TrafficCounters {
+	if x != nil {
+		return x.Tx
+	}
+	return nil
+}
+
+func (x *ExposeCounters) GetNatSessions() uint64 {
+	if x != nil {
+		return x.NatSessions
+	}
+	return 0
+}
+
+type PeeringCounters struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Name          string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"` // VpcPeering name
+	Exposes       []*ExposeCounters      `protobuf:"bytes,2,rep,name=exposes,proto3" json:"exposes,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *PeeringCounters) Reset() {
+	*x = PeeringCounters{}
+	mi := &file_proto_dataplane_proto_msgTypes[17]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *PeeringCounters) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PeeringCounters) ProtoMessage() {}
+
+func (x *PeeringCounters) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[17]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PeeringCounters.ProtoReflect.Descriptor instead.
+func (*PeeringCounters) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{17}
+}
+
+func (x *PeeringCounters) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *PeeringCounters) GetExposes() []*ExposeCounters {
+	if x != nil {
+		return x.Exposes
+	}
+	return nil
+}
+
+type GetCountersResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Generation    int64                  `protobuf:"varint,1,opt,name=generation,proto3" json:"generation,omitempty"`                      // generation of the currently applied config
+	TimestampMs   uint64                 `protobuf:"varint,2,opt,name=timestamp_ms,json=timestampMs,proto3" json:"timestamp_ms,omitempty"` // dataplane time of the sample, milliseconds since the Unix epoch
+	Vpcs          []*VpcCounters         `protobuf:"bytes,3,rep,name=vpcs,proto3" json:"vpcs,omitempty"`
+	Peerings      []*PeeringCounters     `protobuf:"bytes,4,rep,name=peerings,proto3" json:"peerings,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *GetCountersResponse) Reset() {
+	*x = GetCountersResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[18]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *GetCountersResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*GetCountersResponse) ProtoMessage() {}
+
+func (x *GetCountersResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[18]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use GetCountersResponse.ProtoReflect.Descriptor instead.
+func (*GetCountersResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{18}
+}
+
+func (x *GetCountersResponse) GetGeneration() int64 {
+	if x != nil {
+		return x.Generation
+	}
+	return 0
+}
+
+func (x *GetCountersResponse) GetTimestampMs() uint64 {
+	if x != nil {
+		return x.TimestampMs
+	}
+	return 0
+}
+
+func (x *GetCountersResponse) GetVpcs() []*VpcCounters {
+	if x != nil {
+		return x.Vpcs
+	}
+	return nil
+}
+
+func (x *GetCountersResponse) GetPeerings() []*PeeringCounters {
+	if x != nil {
+		return x.Peerings
+	}
+	return nil
+}
+
+type ComponentLogLevel struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Component     LogComponent           `protobuf:"varint,1,opt,name=component,proto3,enum=config.LogComponent" json:"component,omitempty"`
+	Level         LogLevel               `protobuf:"varint,2,opt,name=level,proto3,enum=config.LogLevel" json:"level,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ComponentLogLevel) Reset() {
+	*x = ComponentLogLevel{}
+	mi := &file_proto_dataplane_proto_msgTypes[19]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ComponentLogLevel) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ComponentLogLevel) ProtoMessage() {}
+
+func (x *ComponentLogLevel) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[19]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ComponentLogLevel.ProtoReflect.Descriptor instead.
+func (*ComponentLogLevel) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{19}
+}
+
+func (x *ComponentLogLevel) GetComponent() LogComponent {
+	if x != nil {
+		return x.Component
+	}
+	return LogComponent_LOG_COMPONENT_CORE
+}
+
+func (x *ComponentLogLevel) GetLevel() LogLevel {
+	if x != nil {
+		return x.Level
+	}
+	return LogLevel_ERROR
+}
+
+// Change log levels at runtime, without a config update or generation change
+type SetLogLevelRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Level         *LogLevel              `protobuf:"varint,1,opt,name=level,proto3,enum=config.LogLevel,oneof" json:"level,omitempty"` // for everything not listed in components, unchanged if unset
+	Components    []*ComponentLogLevel   `protobuf:"bytes,2,rep,name=components,proto3" json:"components,omitempty"`                   // at most one entry per component
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SetLogLevelRequest) Reset() {
+	*x = SetLogLevelRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[20]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SetLogLevelRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SetLogLevelRequest) ProtoMessage() {}
+
+func (x *SetLogLevelRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[20]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SetLogLevelRequest.ProtoReflect.Descriptor instead.
+func (*SetLogLevelRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{20}
+}
+
+func (x *SetLogLevelRequest) GetLevel() LogLevel {
+	if x != nil && x.Level != nil {
+		return *x.Level
+	}
+	return LogLevel_ERROR
+}
+
+func (x *SetLogLevelRequest) GetComponents() []*ComponentLogLevel {
+	if x != nil {
+		return x.Components
+	}
+	return nil
+}
+
+type SetLogLevelResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Error         Error                  `protobuf:"varint,1,opt,name=error,proto3,enum=config.Error" json:"error,omitempty"`
+	Message       string                 `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SetLogLevelResponse) Reset() {
+	*x = SetLogLevelResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[21]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SetLogLevelResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SetLogLevelResponse) ProtoMessage() {}
+
+func (x *SetLogLevelResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[21]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SetLogLevelResponse.ProtoReflect.Descriptor instead.
+func (*SetLogLevelResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{21}
+}
+
+func (x *SetLogLevelResponse) GetError() Error {
+	if x != nil {
+		return x.Error
+	}
+	return Error_ERROR_NONE
+}
+
+func (x *SetLogLevelResponse) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+// Take or renew the exclusive right to update the config, until released or the lease expires
+type AcquireConfigLockRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Owner         string                 `protobuf:"bytes,1,opt,name=owner,proto3" json:"owner,omitempty"`                                    // identity of the writer, not empty
+	LeaseSeconds  uint32                 `protobuf:"varint,2,opt,name=lease_seconds,json=leaseSeconds,proto3" json:"lease_seconds,omitempty"` // not zero
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *AcquireConfigLockRequest) Reset() {
+	*x = AcquireConfigLockRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[22]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *AcquireConfigLockRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AcquireConfigLockRequest) ProtoMessage() {}
+
+func (x *AcquireConfigLockRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[22]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AcquireConfigLockRequest.ProtoReflect.Descriptor instead.
+func (*AcquireConfigLockRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{22}
+}
+
+func (x *AcquireConfigLockRequest) GetOwner() string {
+	if x != nil {
+		return x.Owner
+	}
+	return ""
+}
+
+func (x *AcquireConfigLockRequest) GetLeaseSeconds() uint32 {
+	if x != nil {
+		return x.LeaseSeconds
+	}
+	return 0
+}
+
+type AcquireConfigLockResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Error         Error                  `protobuf:"varint,1,opt,name=error,proto3,enum=config.Error" json:"error,omitempty"`
+	Message       string                 `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	Owner         string                 `protobuf:"bytes,3,opt,name=owner,proto3" json:"owner,omitempty"`                                   // current holder of the lock, the requester if acquired
+	ExpiresAtMs   uint64                 `protobuf:"varint,4,opt,name=expires_at_ms,json=expiresAtMs,proto3" json:"expires_at_ms,omitempty"` // lease expiry of the current holder, ms since Unix epoch
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *AcquireConfigLockResponse) Reset() {
+	*x = AcquireConfigLockResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[23]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *AcquireConfigLockResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AcquireConfigLockResponse) ProtoMessage() {}
+
+func (x *AcquireConfigLockResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[23]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AcquireConfigLockResponse.ProtoReflect.Descriptor instead.
+func (*AcquireConfigLockResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{23}
+}
+
+func (x *AcquireConfigLockResponse) GetError() Error {
+	if x != nil {
+		return x.Error
+	}
+	return Error_ERROR_NONE
+}
+
+func (x *AcquireConfigLockResponse) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+func (x *AcquireConfigLockResponse) GetOwner() string {
+	if x != nil {
+		return x.Owner
+	}
+	return ""
+}
+
+func (x *AcquireConfigLockResponse) GetExpiresAtMs() uint64 {
+	if x != nil {
+		return x.ExpiresAtMs
+	}
+	return 0
+}
+
+type ReleaseConfigLockRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Owner         string                 `protobuf:"bytes,1,opt,name=owner,proto3" json:"owner,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ReleaseConfigLockRequest) Reset() {
+	*x = ReleaseConfigLockRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[24]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ReleaseConfigLockRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ReleaseConfigLockRequest) ProtoMessage() {}
+
+func (x *ReleaseConfigLockRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[24]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ReleaseConfigLockRequest.ProtoReflect.Descriptor instead.
+func (*ReleaseConfigLockRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{24}
+}
+
+func (x *ReleaseConfigLockRequest) GetOwner() string {
+	if x != nil {
+		return x.Owner
+	}
+	return ""
+}
+
+type ReleaseConfigLockResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Error         Error                  `protobuf:"varint,1,opt,name=error,proto3,enum=config.Error" json:"error,omitempty"`
+	Message       string                 `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ReleaseConfigLockResponse) Reset() {
+	*x = ReleaseConfigLockResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[25]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ReleaseConfigLockResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ReleaseConfigLockResponse) ProtoMessage() {}
+
+func (x *ReleaseConfigLockResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[25]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ReleaseConfigLockResponse.ProtoReflect.Descriptor instead.
+func (*ReleaseConfigLockResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{25}
+}
+
+func (x *ReleaseConfigLockResponse) GetError() Error {
+	if x != nil {
+		return x.Error
+	}
+	return Error_ERROR_NONE
+}
+
+func (x *ReleaseConfigLockResponse) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+// Keep a config applied with a confirm timeout
+type ConfirmConfigRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Generation    int64                  `protobuf:"varint,1,opt,name=generation,proto3" json:"generation,omitempty"` // generation of the config awaiting confirmation
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ConfirmConfigRequest) Reset() {
+	*x = ConfirmConfigRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[26]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ConfirmConfigRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ConfirmConfigRequest) ProtoMessage() {}
+
+func (x *ConfirmConfigRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[26]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ConfirmConfigRequest.ProtoReflect.Descriptor instead.
+func (*ConfirmConfigRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{26}
+}
+
+func (x *ConfirmConfigRequest) GetGeneration() int64 {
+	if x != nil {
+		return x.Generation
+	}
+	return 0
+}
+
+type ConfirmConfigResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Error         Error                  `protobuf:"varint,1,opt,name=error,proto3,enum=config.Error" json:"error,omitempty"`
+	Message       string                 `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *ConfirmConfigResponse) Reset() {
+	*x = ConfirmConfigResponse{}
+	mi := &file_proto_dataplane_proto_msgTypes[27]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ConfirmConfigResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ConfirmConfigResponse) ProtoMessage() {}
+
+func (x *ConfirmConfigResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[27]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ConfirmConfigResponse.ProtoReflect.Descriptor instead.
+func (*ConfirmConfigResponse) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{27}
+}
+
+func (x *ConfirmConfigResponse) GetError() Error {
+	if x != nil {
+		return x.Error
+	}
+	return Error_ERROR_NONE
+}
+
+func (x *ConfirmConfigResponse) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+// Outcome of applying one config section, e.g. "device", "underlay" or "overlay"
+type SectionResult struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Section       string                 `protobuf:"bytes,1,opt,name=section,proto3" json:"section,omitempty"`
+	Error         Error                  `protobuf:"varint,2,opt,name=error,proto3,enum=config.Error" json:"error,omitempty"`
+	Message       string                 `protobuf:"bytes,3,opt,name=message,proto3" json:"message,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *SectionResult) Reset() {
+	*x = SectionResult{}
+	mi := &file_proto_dataplane_proto_msgTypes[28]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *SectionResult) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SectionResult) ProtoMessage() {}
+
+func (x *SectionResult) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[28]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SectionResult.ProtoReflect.Descriptor instead.
+func (*SectionResult) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{28}
+}
+
+func (x *SectionResult) GetSection() string {
+	if x != nil {
+		return x.Section
+	}
+	return ""
+}
+
+func (x *SectionResult) GetError() Error {
+	if x != nil {
+		return x.Error
+	}
+	return Error_ERROR_NONE
+}
+
+func (x *SectionResult) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+type ApplyOperation struct {
+	state           protoimpl.MessageState `protogen:"open.v1"`
+	Id              string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	Generation      int64                  `protobuf:"varint,2,opt,name=generation,proto3" json:"generation,omitempty"` // generation of the config being applied
+	State           OperationState         `protobuf:"varint,3,opt,name=state,proto3,enum=config.OperationState" json:"state,omitempty"`
+	ProgressPercent uint32                 `protobuf:"varint,4,opt,name=progress_percent,json=progressPercent,proto3" json:"progress_percent,omitempty"`
+	Sections        []*SectionResult       `protobuf:"bytes,5,rep,name=sections,proto3" json:"sections,omitempty"`              // sections applied so far
+	Error           Error                  `protobuf:"varint,6,opt,name=error,proto3,enum=config.Error" json:"error,omitempty"` // final verdict, once done
+	Message         string                 `protobuf:"bytes,7,opt,name=message,proto3" json:"message,omitempty"`
+	Results         []*ObjectResult        `protobuf:"bytes,8,rep,name=results,proto3" json:"results,omitempty"` // what became of each object, once done
+	unknownFields   protoimpl.UnknownFields
+	sizeCache       protoimpl.SizeCache
+}
+
+func (x *ApplyOperation) Reset() {
+	*x = ApplyOperation{}
+	mi := &file_proto_dataplane_proto_msgTypes[29]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ApplyOperation) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ApplyOperation) ProtoMessage() {}
+
+func (x *ApplyOperation) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[29]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ApplyOperation.ProtoReflect.Descriptor instead.
+func (*ApplyOperation) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{29}
 }
 
-func (x *UpdateConfigRequest) GetConfig() *GatewayConfig {
+func (x *ApplyOperation) GetId() string {
 	if x != nil {
-		return x.Config
+		return x.Id
 	}
-	return nil
-}
-
-type UpdateConfigResponse struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Error         Error                  `protobuf:"varint,1,opt,name=error,proto3,enum=config.Error" json:"error,omitempty"`
-	Message       string                 `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
-	unknownFields protoimpl.UnknownFields
-	sizeCache     protoimpl.SizeCache
+	return ""
 }
 
-func (x *UpdateConfigResponse) Reset() {
-	*x = UpdateConfigResponse{}
-	mi := &file_proto_dataplane_proto_msgTypes[2]
-	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-	ms.StoreMessageInfo(mi)
+func (x *ApplyOperation) GetGeneration() int64 {
+	if x != nil {
+		return x.Generation
+	}
+	return 0
 }
 
-func (x *UpdateConfigResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *ApplyOperation) GetState() OperationState {
+	if x != nil {
+		return x.State
+	}
+	return OperationState_OPERATION_STATE_RUNNING
 }
 
-func (*UpdateConfigResponse) ProtoMessage() {}
-
-func (x *UpdateConfigResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[2]
+func (x *ApplyOperation) GetProgressPercent() uint32 {
 	if x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+		return x.ProgressPercent
 	}
-	return mi.MessageOf(x)
+	return 0
 }
 
-// Deprecated: Use UpdateConfigResponse.ProtoReflect.Descriptor instead.
-func (*UpdateConfigResponse) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{2}
+func (x *ApplyOperation) GetSections() []*SectionResult {
+	if x != nil {
+		return x.Sections
+	}
+	return nil
 }
 
-func (x *UpdateConfigResponse) GetError() Error {
+func (x *ApplyOperation) GetError() Error {
 	if x != nil {
 		return x.Error
 	}
 	return Error_ERROR_NONE
 }
 
-func (x *UpdateConfigResponse) GetMessage() string {
+func (x *ApplyOperation) GetMessage() string {
 	if x != nil {
 		return x.Message
 	}
 	return ""
 }
 
-type GetConfigGenerationRequest struct {
+func (x *ApplyOperation) GetResults() []*ObjectResult {
+	if x != nil {
+		return x.Results
+	}
+	return nil
+}
+
+type GetOperationRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
+	Id            string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
-func (x *GetConfigGenerationRequest) Reset() {
-	*x = GetConfigGenerationRequest{}
-	mi := &file_proto_dataplane_proto_msgTypes[3]
+func (x *GetOperationRequest) Reset() {
+	*x = GetOperationRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[30]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *GetConfigGenerationRequest) String() string {
+func (x *GetOperationRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetConfigGenerationRequest) ProtoMessage() {}
+func (*GetOperationRequest) ProtoMessage() {}
 
-func (x *GetConfigGenerationRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[3]
+func (x *GetOperationRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[30]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -545,33 +2641,42 @@ func (x *GetConfigGenerationRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetConfigGenerationRequest.ProtoReflect.Descriptor instead.
-func (*GetConfigGenerationRequest) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{3}
+// Deprecated: Use GetOperationRequest.ProtoReflect.Descriptor instead.
+func (*GetOperationRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{30}
 }
 
-type GetConfigGenerationResponse struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	Generation    int64                  `protobuf:"varint,1,opt,name=generation,proto3" json:"generation,omitempty"`
-	unknownFields protoimpl.UnknownFields
-	sizeCache     protoimpl.SizeCache
+func (x *GetOperationRequest) GetId() string {
+	if x != nil {
+		return x.Id
+	}
+	return ""
 }
 
-func (x *GetConfigGenerationResponse) Reset() {
-	*x = GetConfigGenerationResponse{}
-	mi := &file_proto_dataplane_proto_msgTypes[4]
+// Wait until the operation is done or the timeout expires, whichever comes first
+type WaitOperationRequest struct {
+	state          protoimpl.MessageState `protogen:"open.v1"`
+	Id             string                 `protobuf:"bytes,1,opt,name=id,proto3" json:"id,omitempty"`
+	TimeoutSeconds uint32                 `protobuf:"varint,2,opt,name=timeout_seconds,json=timeoutSeconds,proto3" json:"timeout_seconds,omitempty"`
+	unknownFields  protoimpl.UnknownFields
+	sizeCache      protoimpl.SizeCache
+}
+
+func (x *WaitOperationRequest) Reset() {
+	*x = WaitOperationRequest{}
+	mi := &file_proto_dataplane_proto_msgTypes[31]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
 
-func (x *GetConfigGenerationResponse) String() string {
+func (x *WaitOperationRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*GetConfigGenerationResponse) ProtoMessage() {}
+func (*WaitOperationRequest) ProtoMessage() {}
 
-func (x *GetConfigGenerationResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[4]
+func (x *WaitOperationRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[31]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -582,14 +2687,21 @@ func (x *GetConfigGenerationResponse) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use GetConfigGenerationResponse.ProtoReflect.Descriptor instead.
-func (*GetConfigGenerationResponse) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{4}
+// Deprecated: Use WaitOperationRequest.ProtoReflect.Descriptor instead.
+func (*WaitOperationRequest) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{31}
 }
 
-func (x *GetConfigGenerationResponse) GetGeneration() int64 {
+func (x *WaitOperationRequest) GetId() string {
 	if x != nil {
-		return x.Generation
+		return x.Id
+	}
+	return ""
+}
+
+func (x *WaitOperationRequest) GetTimeoutSeconds() uint32 {
+	if x != nil {
+		return x.TimeoutSeconds
 	}
 	return 0
 }
@@ -607,7 +2719,7 @@ type OspfInterface struct {
 
 func (x *OspfInterface) Reset() {
 	*x = OspfInterface{}
-	mi := &file_proto_dataplane_proto_msgTypes[5]
+	mi := &file_proto_dataplane_proto_msgTypes[32]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -619,7 +2731,7 @@ func (x *OspfInterface) String() string {
 func (*OspfInterface) ProtoMessage() {}
 
 func (x *OspfInterface) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[5]
+	mi := &file_proto_dataplane_proto_msgTypes[32]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -632,7 +2744,7 @@ func (x *OspfInterface) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use OspfInterface.ProtoReflect.Descriptor instead.
 func (*OspfInterface) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{5}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{32}
 }
 
 func (x *OspfInterface) GetPassive() bool {
@@ -674,7 +2786,7 @@ type OspfConfig struct {
 
 func (x *OspfConfig) Reset() {
 	*x = OspfConfig{}
-	mi := &file_proto_dataplane_proto_msgTypes[6]
+	mi := &file_proto_dataplane_proto_msgTypes[33]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -686,7 +2798,7 @@ func (x *OspfConfig) String() string {
 func (*OspfConfig) ProtoMessage() {}
 
 func (x *OspfConfig) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[6]
+	mi := &file_proto_dataplane_proto_msgTypes[33]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -699,7 +2811,7 @@ func (x *OspfConfig) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use OspfConfig.ProtoReflect.Descriptor instead.
 func (*OspfConfig) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{6}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{33}
 }
 
 func (x *OspfConfig) GetRouterId() string {
@@ -734,7 +2846,7 @@ type Interface struct {
 
 func (x *Interface) Reset() {
 	*x = Interface{}
-	mi := &file_proto_dataplane_proto_msgTypes[7]
+	mi := &file_proto_dataplane_proto_msgTypes[34]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -746,7 +2858,7 @@ func (x *Interface) String() string {
 func (*Interface) ProtoMessage() {}
 
 func (x *Interface) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[7]
+	mi := &file_proto_dataplane_proto_msgTypes[34]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -759,7 +2871,7 @@ func (x *Interface) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Interface.ProtoReflect.Descriptor instead.
 func (*Interface) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{7}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{34}
 }
 
 func (x *Interface) GetName() string {
@@ -839,7 +2951,7 @@ type PeeringIPs struct {
 
 func (x *PeeringIPs) Reset() {
 	*x = PeeringIPs{}
-	mi := &file_proto_dataplane_proto_msgTypes[8]
+	mi := &file_proto_dataplane_proto_msgTypes[35]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -851,7 +2963,7 @@ func (x *PeeringIPs) String() string {
 func (*PeeringIPs) ProtoMessage() {}
 
 func (x *PeeringIPs) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[8]
+	mi := &file_proto_dataplane_proto_msgTypes[35]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -864,7 +2976,7 @@ func (x *PeeringIPs) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use PeeringIPs.ProtoReflect.Descriptor instead.
 func (*PeeringIPs) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{8}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{35}
 }
 
 func (x *PeeringIPs) GetRule() isPeeringIPs_Rule {
@@ -922,7 +3034,7 @@ type PeeringAs struct {
 
 func (x *PeeringAs) Reset() {
 	*x = PeeringAs{}
-	mi := &file_proto_dataplane_proto_msgTypes[9]
+	mi := &file_proto_dataplane_proto_msgTypes[36]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -934,7 +3046,7 @@ func (x *PeeringAs) String() string {
 func (*PeeringAs) ProtoMessage() {}
 
 func (x *PeeringAs) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[9]
+	mi := &file_proto_dataplane_proto_msgTypes[36]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -947,7 +3059,7 @@ func (x *PeeringAs) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use PeeringAs.ProtoReflect.Descriptor instead.
 func (*PeeringAs) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{9}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{36}
 }
 
 func (x *PeeringAs) GetRule() isPeeringAs_Rule {
@@ -979,30 +3091,268 @@ type isPeeringAs_Rule interface {
 	isPeeringAs_Rule()
 }
 
-type PeeringAs_Cidr struct {
-	Cidr string `protobuf:"bytes,1,opt,name=cidr,proto3,oneof"`
+type PeeringAs_Cidr struct {
+	Cidr string `protobuf:"bytes,1,opt,name=cidr,proto3,oneof"`
+}
+
+type PeeringAs_Not struct {
+	Not string `protobuf:"bytes,2,opt,name=not,proto3,oneof"`
+}
+
+func (*PeeringAs_Cidr) isPeeringAs_Rule() {}
+
+func (*PeeringAs_Not) isPeeringAs_Rule() {}
+
+// Inclusive range of L4 ports
+type PortRange struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Start         uint32                 `protobuf:"varint,1,opt,name=start,proto3" json:"start,omitempty"`
+	End           uint32                 `protobuf:"varint,2,opt,name=end,proto3" json:"end,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *PortRange) Reset() {
+	*x = PortRange{}
+	mi := &file_proto_dataplane_proto_msgTypes[37]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *PortRange) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PortRange) ProtoMessage() {}
+
+func (x *PortRange) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[37]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PortRange.ProtoReflect.Descriptor instead.
+func (*PortRange) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{37}
+}
+
+func (x *PortRange) GetStart() uint32 {
+	if x != nil {
+		return x.Start
+	}
+	return 0
+}
+
+func (x *PortRange) GetEnd() uint32 {
+	if x != nil {
+		return x.End
+	}
+	return 0
+}
+
+// Addresses and ports stateful NAT allocates from
+type NatPool struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Cidrs         []string               `protobuf:"bytes,1,rep,name=cidrs,proto3" json:"cidrs,omitempty"`       // same address family as the exposed ips
+	Ports         *PortRange             `protobuf:"bytes,2,opt,name=ports,proto3,oneof" json:"ports,omitempty"` // source ports to allocate, dataplane default if unset
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *NatPool) Reset() {
+	*x = NatPool{}
+	mi := &file_proto_dataplane_proto_msgTypes[38]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *NatPool) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*NatPool) ProtoMessage() {}
+
+func (x *NatPool) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[38]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use NatPool.ProtoReflect.Descriptor instead.
+func (*NatPool) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{38}
+}
+
+func (x *NatPool) GetCidrs() []string {
+	if x != nil {
+		return x.Cidrs
+	}
+	return nil
+}
+
+func (x *NatPool) GetPorts() *PortRange {
+	if x != nil {
+		return x.Ports
+	}
+	return nil
+}
+
+// Stateful (many-to-few, port translating) source NAT, a.k.a. masquerade
+type StatefulNat struct {
+	state           protoimpl.MessageState `protogen:"open.v1"`
+	Pool            *NatPool               `protobuf:"bytes,1,opt,name=pool,proto3" json:"pool,omitempty"`
+	TcpIdleTimeout  *uint32                `protobuf:"varint,2,opt,name=tcp_idle_timeout,json=tcpIdleTimeout,proto3,oneof" json:"tcp_idle_timeout,omitempty"`    // seconds, dataplane default if unset
+	UdpIdleTimeout  *uint32                `protobuf:"varint,3,opt,name=udp_idle_timeout,json=udpIdleTimeout,proto3,oneof" json:"udp_idle_timeout,omitempty"`    // seconds, dataplane default if unset
+	IcmpIdleTimeout *uint32                `protobuf:"varint,4,opt,name=icmp_idle_timeout,json=icmpIdleTimeout,proto3,oneof" json:"icmp_idle_timeout,omitempty"` // seconds, dataplane default if unset
+	unknownFields   protoimpl.UnknownFields
+	sizeCache       protoimpl.SizeCache
+}
+
+func (x *StatefulNat) Reset() {
+	*x = StatefulNat{}
+	mi := &file_proto_dataplane_proto_msgTypes[39]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *StatefulNat) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StatefulNat) ProtoMessage() {}
+
+func (x *StatefulNat) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[39]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StatefulNat.ProtoReflect.Descriptor instead.
+func (*StatefulNat) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{39}
+}
+
+func (x *StatefulNat) GetPool() *NatPool {
+	if x != nil {
+		return x.Pool
+	}
+	return nil
+}
+
+func (x *StatefulNat) GetTcpIdleTimeout() uint32 {
+	if x != nil && x.TcpIdleTimeout != nil {
+		return *x.TcpIdleTimeout
+	}
+	return 0
+}
+
+func (x *StatefulNat) GetUdpIdleTimeout() uint32 {
+	if x != nil && x.UdpIdleTimeout != nil {
+		return *x.UdpIdleTimeout
+	}
+	return 0
+}
+
+func (x *StatefulNat) GetIcmpIdleTimeout() uint32 {
+	if x != nil && x.IcmpIdleTimeout != nil {
+		return *x.IcmpIdleTimeout
+	}
+	return 0
+}
+
+// Restricts the traffic allowed through an expose
+type L4Filter struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Protocol      L4Protocol             `protobuf:"varint,1,opt,name=protocol,proto3,enum=config.L4Protocol" json:"protocol,omitempty"`
+	Ports         []*PortRange           `protobuf:"bytes,2,rep,name=ports,proto3" json:"ports,omitempty"`                                  // destination ports, TCP and UDP only, empty for all
+	IcmpTypes     []uint32               `protobuf:"varint,3,rep,packed,name=icmp_types,json=icmpTypes,proto3" json:"icmp_types,omitempty"` // ICMP and ICMPv6 only, empty for all
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *L4Filter) Reset() {
+	*x = L4Filter{}
+	mi := &file_proto_dataplane_proto_msgTypes[40]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *L4Filter) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*L4Filter) ProtoMessage() {}
+
+func (x *L4Filter) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[40]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use L4Filter.ProtoReflect.Descriptor instead.
+func (*L4Filter) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{40}
 }
 
-type PeeringAs_Not struct {
-	Not string `protobuf:"bytes,2,opt,name=not,proto3,oneof"`
+func (x *L4Filter) GetProtocol() L4Protocol {
+	if x != nil {
+		return x.Protocol
+	}
+	return L4Protocol_L4_PROTOCOL_ANY
 }
 
-func (*PeeringAs_Cidr) isPeeringAs_Rule() {}
+func (x *L4Filter) GetPorts() []*PortRange {
+	if x != nil {
+		return x.Ports
+	}
+	return nil
+}
 
-func (*PeeringAs_Not) isPeeringAs_Rule() {}
+func (x *L4Filter) GetIcmpTypes() []uint32 {
+	if x != nil {
+		return x.IcmpTypes
+	}
+	return nil
+}
 
 // Defines a rule between exposing IP and translated
 type Expose struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Ips           []*PeeringIPs          `protobuf:"bytes,1,rep,name=ips,proto3" json:"ips,omitempty"`
-	As            []*PeeringAs           `protobuf:"bytes,2,rep,name=as,proto3" json:"as,omitempty"`
+	As            []*PeeringAs           `protobuf:"bytes,2,rep,name=as,proto3" json:"as,omitempty"`                                            // static prefix-to-prefix translation
+	StatefulNat   *StatefulNat           `protobuf:"bytes,3,opt,name=stateful_nat,json=statefulNat,proto3,oneof" json:"stateful_nat,omitempty"` // stateful translation, excludes `as`
+	Filters       []*L4Filter            `protobuf:"bytes,4,rep,name=filters,proto3" json:"filters,omitempty"`                                  // traffic must match one of them, empty allows all
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
 func (x *Expose) Reset() {
 	*x = Expose{}
-	mi := &file_proto_dataplane_proto_msgTypes[10]
+	mi := &file_proto_dataplane_proto_msgTypes[41]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1014,7 +3364,7 @@ func (x *Expose) String() string {
 func (*Expose) ProtoMessage() {}
 
 func (x *Expose) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[10]
+	mi := &file_proto_dataplane_proto_msgTypes[41]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1027,7 +3377,7 @@ func (x *Expose) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Expose.ProtoReflect.Descriptor instead.
 func (*Expose) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{10}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{41}
 }
 
 func (x *Expose) GetIps() []*PeeringIPs {
@@ -1044,6 +3394,20 @@ func (x *Expose) GetAs() []*PeeringAs {
 	return nil
 }
 
+func (x *Expose) GetStatefulNat() *StatefulNat {
+	if x != nil {
+		return x.StatefulNat
+	}
+	return nil
+}
+
+func (x *Expose) GetFilters() []*L4Filter {
+	if x != nil {
+		return x.Filters
+	}
+	return nil
+}
+
 // Defines a list of exposures per VPC
 type PeeringEntryFor struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
@@ -1055,7 +3419,7 @@ type PeeringEntryFor struct {
 
 func (x *PeeringEntryFor) Reset() {
 	*x = PeeringEntryFor{}
-	mi := &file_proto_dataplane_proto_msgTypes[11]
+	mi := &file_proto_dataplane_proto_msgTypes[42]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1067,7 +3431,7 @@ func (x *PeeringEntryFor) String() string {
 func (*PeeringEntryFor) ProtoMessage() {}
 
 func (x *PeeringEntryFor) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[11]
+	mi := &file_proto_dataplane_proto_msgTypes[42]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1080,7 +3444,7 @@ func (x *PeeringEntryFor) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use PeeringEntryFor.ProtoReflect.Descriptor instead.
 func (*PeeringEntryFor) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{11}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{42}
 }
 
 func (x *PeeringEntryFor) GetVpc() string {
@@ -1108,7 +3472,7 @@ type VpcPeering struct {
 
 func (x *VpcPeering) Reset() {
 	*x = VpcPeering{}
-	mi := &file_proto_dataplane_proto_msgTypes[12]
+	mi := &file_proto_dataplane_proto_msgTypes[43]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1120,7 +3484,7 @@ func (x *VpcPeering) String() string {
 func (*VpcPeering) ProtoMessage() {}
 
 func (x *VpcPeering) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[12]
+	mi := &file_proto_dataplane_proto_msgTypes[43]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1133,7 +3497,7 @@ func (x *VpcPeering) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VpcPeering.ProtoReflect.Descriptor instead.
 func (*VpcPeering) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{12}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{43}
 }
 
 func (x *VpcPeering) GetName() string {
@@ -1162,7 +3526,7 @@ type VPC struct {
 
 func (x *VPC) Reset() {
 	*x = VPC{}
-	mi := &file_proto_dataplane_proto_msgTypes[13]
+	mi := &file_proto_dataplane_proto_msgTypes[44]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1174,7 +3538,7 @@ func (x *VPC) String() string {
 func (*VPC) ProtoMessage() {}
 
 func (x *VPC) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[13]
+	mi := &file_proto_dataplane_proto_msgTypes[44]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1187,7 +3551,7 @@ func (x *VPC) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VPC.ProtoReflect.Descriptor instead.
 func (*VPC) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{13}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{44}
 }
 
 func (x *VPC) GetId() string {
@@ -1229,7 +3593,7 @@ type Overlay struct {
 
 func (x *Overlay) Reset() {
 	*x = Overlay{}
-	mi := &file_proto_dataplane_proto_msgTypes[14]
+	mi := &file_proto_dataplane_proto_msgTypes[45]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1241,7 +3605,7 @@ func (x *Overlay) String() string {
 func (*Overlay) ProtoMessage() {}
 
 func (x *Overlay) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[14]
+	mi := &file_proto_dataplane_proto_msgTypes[45]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1254,7 +3618,7 @@ func (x *Overlay) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Overlay.ProtoReflect.Descriptor instead.
 func (*Overlay) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{14}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{45}
 }
 
 func (x *Overlay) GetVpcs() []*VPC {
@@ -1283,7 +3647,7 @@ type BgpAddressFamilyIPv4 struct {
 
 func (x *BgpAddressFamilyIPv4) Reset() {
 	*x = BgpAddressFamilyIPv4{}
-	mi := &file_proto_dataplane_proto_msgTypes[15]
+	mi := &file_proto_dataplane_proto_msgTypes[46]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1295,7 +3659,7 @@ func (x *BgpAddressFamilyIPv4) String() string {
 func (*BgpAddressFamilyIPv4) ProtoMessage() {}
 
 func (x *BgpAddressFamilyIPv4) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[15]
+	mi := &file_proto_dataplane_proto_msgTypes[46]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1308,7 +3672,7 @@ func (x *BgpAddressFamilyIPv4) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpAddressFamilyIPv4.ProtoReflect.Descriptor instead.
 func (*BgpAddressFamilyIPv4) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{15}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{46}
 }
 
 func (x *BgpAddressFamilyIPv4) GetRedistributeConnected() bool {
@@ -1344,7 +3708,7 @@ type BgpAddressFamilyIPv6 struct {
 
 func (x *BgpAddressFamilyIPv6) Reset() {
 	*x = BgpAddressFamilyIPv6{}
-	mi := &file_proto_dataplane_proto_msgTypes[16]
+	mi := &file_proto_dataplane_proto_msgTypes[47]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1356,7 +3720,7 @@ func (x *BgpAddressFamilyIPv6) String() string {
 func (*BgpAddressFamilyIPv6) ProtoMessage() {}
 
 func (x *BgpAddressFamilyIPv6) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[16]
+	mi := &file_proto_dataplane_proto_msgTypes[47]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1369,7 +3733,7 @@ func (x *BgpAddressFamilyIPv6) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpAddressFamilyIPv6.ProtoReflect.Descriptor instead.
 func (*BgpAddressFamilyIPv6) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{16}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{47}
 }
 
 func (x *BgpAddressFamilyIPv6) GetRedistributeConnected() bool {
@@ -1403,7 +3767,7 @@ type BgpAddressFamilyL2VpnEvpn struct {
 
 func (x *BgpAddressFamilyL2VpnEvpn) Reset() {
 	*x = BgpAddressFamilyL2VpnEvpn{}
-	mi := &file_proto_dataplane_proto_msgTypes[17]
+	mi := &file_proto_dataplane_proto_msgTypes[48]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1415,7 +3779,7 @@ func (x *BgpAddressFamilyL2VpnEvpn) String() string {
 func (*BgpAddressFamilyL2VpnEvpn) ProtoMessage() {}
 
 func (x *BgpAddressFamilyL2VpnEvpn) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[17]
+	mi := &file_proto_dataplane_proto_msgTypes[48]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1428,7 +3792,7 @@ func (x *BgpAddressFamilyL2VpnEvpn) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpAddressFamilyL2VpnEvpn.ProtoReflect.Descriptor instead.
 func (*BgpAddressFamilyL2VpnEvpn) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{17}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{48}
 }
 
 func (x *BgpAddressFamilyL2VpnEvpn) GetAdvertiseAllVni() bool {
@@ -1451,7 +3815,7 @@ type BgpNeighborUpdateSource struct {
 
 func (x *BgpNeighborUpdateSource) Reset() {
 	*x = BgpNeighborUpdateSource{}
-	mi := &file_proto_dataplane_proto_msgTypes[18]
+	mi := &file_proto_dataplane_proto_msgTypes[49]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1463,7 +3827,7 @@ func (x *BgpNeighborUpdateSource) String() string {
 func (*BgpNeighborUpdateSource) ProtoMessage() {}
 
 func (x *BgpNeighborUpdateSource) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[18]
+	mi := &file_proto_dataplane_proto_msgTypes[49]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1476,7 +3840,7 @@ func (x *BgpNeighborUpdateSource) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpNeighborUpdateSource.ProtoReflect.Descriptor instead.
 func (*BgpNeighborUpdateSource) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{18}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{49}
 }
 
 func (x *BgpNeighborUpdateSource) GetSource() isBgpNeighborUpdateSource_Source {
@@ -1533,7 +3897,7 @@ type BgpNeighbor struct {
 
 func (x *BgpNeighbor) Reset() {
 	*x = BgpNeighbor{}
-	mi := &file_proto_dataplane_proto_msgTypes[19]
+	mi := &file_proto_dataplane_proto_msgTypes[50]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1545,7 +3909,7 @@ func (x *BgpNeighbor) String() string {
 func (*BgpNeighbor) ProtoMessage() {}
 
 func (x *BgpNeighbor) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[19]
+	mi := &file_proto_dataplane_proto_msgTypes[50]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1558,7 +3922,7 @@ func (x *BgpNeighbor) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use BgpNeighbor.ProtoReflect.Descriptor instead.
 func (*BgpNeighbor) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{19}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{50}
 }
 
 func (x *BgpNeighbor) GetAddress() string {
@@ -1602,7 +3966,7 @@ type RouteMap struct {
 
 func (x *RouteMap) Reset() {
 	*x = RouteMap{}
-	mi := &file_proto_dataplane_proto_msgTypes[20]
+	mi := &file_proto_dataplane_proto_msgTypes[51]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1614,7 +3978,7 @@ func (x *RouteMap) String() string {
 func (*RouteMap) ProtoMessage() {}
 
 func (x *RouteMap) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[20]
+	mi := &file_proto_dataplane_proto_msgTypes[51]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1627,7 +3991,7 @@ func (x *RouteMap) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use RouteMap.ProtoReflect.Descriptor instead.
 func (*RouteMap) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{20}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{51}
 }
 
 func (x *RouteMap) GetName() string {
@@ -1674,7 +4038,7 @@ type RouterConfig struct {
 
 func (x *RouterConfig) Reset() {
 	*x = RouterConfig{}
-	mi := &file_proto_dataplane_proto_msgTypes[21]
+	mi := &file_proto_dataplane_proto_msgTypes[52]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1686,7 +4050,7 @@ func (x *RouterConfig) String() string {
 func (*RouterConfig) ProtoMessage() {}
 
 func (x *RouterConfig) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[21]
+	mi := &file_proto_dataplane_proto_msgTypes[52]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1699,7 +4063,7 @@ func (x *RouterConfig) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use RouterConfig.ProtoReflect.Descriptor instead.
 func (*RouterConfig) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{21}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{52}
 }
 
 func (x *RouterConfig) GetAsn() string {
@@ -1764,7 +4128,7 @@ type VRF struct {
 
 func (x *VRF) Reset() {
 	*x = VRF{}
-	mi := &file_proto_dataplane_proto_msgTypes[22]
+	mi := &file_proto_dataplane_proto_msgTypes[53]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1776,7 +4140,7 @@ func (x *VRF) String() string {
 func (*VRF) ProtoMessage() {}
 
 func (x *VRF) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[22]
+	mi := &file_proto_dataplane_proto_msgTypes[53]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1789,7 +4153,7 @@ func (x *VRF) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use VRF.ProtoReflect.Descriptor instead.
 func (*VRF) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{22}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{53}
 }
 
 func (x *VRF) GetName() string {
@@ -1830,7 +4194,7 @@ type Underlay struct {
 
 func (x *Underlay) Reset() {
 	*x = Underlay{}
-	mi := &file_proto_dataplane_proto_msgTypes[23]
+	mi := &file_proto_dataplane_proto_msgTypes[54]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1842,7 +4206,7 @@ func (x *Underlay) String() string {
 func (*Underlay) ProtoMessage() {}
 
 func (x *Underlay) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[23]
+	mi := &file_proto_dataplane_proto_msgTypes[54]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1855,7 +4219,7 @@ func (x *Underlay) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Underlay.ProtoReflect.Descriptor instead.
 func (*Underlay) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{23}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{54}
 }
 
 func (x *Underlay) GetVrfs() []*VRF {
@@ -1865,18 +4229,147 @@ func (x *Underlay) GetVrfs() []*VRF {
 	return nil
 }
 
+// Connectivity from VPCs to networks outside the fabric
+type ExternalAttachment struct {
+	state              protoimpl.MessageState `protogen:"open.v1"`
+	Name               string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`                                                          // unique
+	Vrf                string                 `protobuf:"bytes,2,opt,name=vrf,proto3" json:"vrf,omitempty"`                                                            // underlay VRF holding the external interfaces
+	Upstreams          []*BgpNeighbor         `protobuf:"bytes,3,rep,name=upstreams,proto3" json:"upstreams,omitempty"`                                                // BGP sessions with upstream routers in that VRF
+	Vpcs               []string               `protobuf:"bytes,4,rep,name=vpcs,proto3" json:"vpcs,omitempty"`                                                          // names of the VPCs that may use this attachment
+	InjectDefaultRoute bool                   `protobuf:"varint,5,opt,name=inject_default_route,json=injectDefaultRoute,proto3" json:"inject_default_route,omitempty"` // advertise a default route into those VPCs
+	unknownFields      protoimpl.UnknownFields
+	sizeCache          protoimpl.SizeCache
+}
+
+func (x *ExternalAttachment) Reset() {
+	*x = ExternalAttachment{}
+	mi := &file_proto_dataplane_proto_msgTypes[55]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *ExternalAttachment) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ExternalAttachment) ProtoMessage() {}
+
+func (x *ExternalAttachment) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[55]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ExternalAttachment.ProtoReflect.Descriptor instead.
+func (*ExternalAttachment) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{55}
+}
+
+func (x *ExternalAttachment) GetName() string {
+	if x != nil {
+		return x.Name
+	}
+	return ""
+}
+
+func (x *ExternalAttachment) GetVrf() string {
+	if x != nil {
+		return x.Vrf
+	}
+	return ""
+}
+
+func (x *ExternalAttachment) GetUpstreams() []*BgpNeighbor {
+	if x != nil {
+		return x.Upstreams
+	}
+	return nil
+}
+
+func (x *ExternalAttachment) GetVpcs() []string {
+	if x != nil {
+		return x.Vpcs
+	}
+	return nil
+}
+
+func (x *ExternalAttachment) GetInjectDefaultRoute() bool {
+	if x != nil {
+		return x.InjectDefaultRoute
+	}
+	return false
+}
+
+// List of all external attachments
+type External struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	Attachments   []*ExternalAttachment  `protobuf:"bytes,1,rep,name=attachments,proto3" json:"attachments,omitempty"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *External) Reset() {
+	*x = External{}
+	mi := &file_proto_dataplane_proto_msgTypes[56]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *External) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*External) ProtoMessage() {}
+
+func (x *External) ProtoReflect() protoreflect.Message {
+	mi := &file_proto_dataplane_proto_msgTypes[56]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use External.ProtoReflect.Descriptor instead.
+func (*External) Descriptor() ([]byte, []int) {
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{56}
+}
+
+func (x *External) GetAttachments() []*ExternalAttachment {
+	if x != nil {
+		return x.Attachments
+	}
+	return nil
+}
+
 // Defines physical or system-level device
 type Ports struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Name          string                 `protobuf:"bytes,1,opt,name=name,proto3" json:"name,omitempty"`
 	SystemName    string                 `protobuf:"bytes,2,opt,name=system_name,json=systemName,proto3" json:"system_name,omitempty"`
+	PciAddress    *string                `protobuf:"bytes,3,opt,name=pci_address,json=pciAddress,proto3,oneof" json:"pci_address,omitempty"` // e.g. "0000:01:00.0", required for DPDK
+	Driver        *string                `protobuf:"bytes,4,opt,name=driver,proto3,oneof" json:"driver,omitempty"`                           // kernel driver the device is bound to, e.g. "vfio-pci"
+	RxQueues      *uint32                `protobuf:"varint,5,opt,name=rx_queues,json=rxQueues,proto3,oneof" json:"rx_queues,omitempty"`
+	TxQueues      *uint32                `protobuf:"varint,6,opt,name=tx_queues,json=txQueues,proto3,oneof" json:"tx_queues,omitempty"`
+	RxDescriptors *uint32                `protobuf:"varint,7,opt,name=rx_descriptors,json=rxDescriptors,proto3,oneof" json:"rx_descriptors,omitempty"` // RX ring size, power of two
+	TxDescriptors *uint32                `protobuf:"varint,8,opt,name=tx_descriptors,json=txDescriptors,proto3,oneof" json:"tx_descriptors,omitempty"` // TX ring size, power of two
+	Interface     *string                `protobuf:"bytes,9,opt,name=interface,proto3,oneof" json:"interface,omitempty"`                               // name of the Interface using this port
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
 func (x *Ports) Reset() {
 	*x = Ports{}
-	mi := &file_proto_dataplane_proto_msgTypes[24]
+	mi := &file_proto_dataplane_proto_msgTypes[57]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1888,7 +4381,7 @@ func (x *Ports) String() string {
 func (*Ports) ProtoMessage() {}
 
 func (x *Ports) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[24]
+	mi := &file_proto_dataplane_proto_msgTypes[57]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1901,7 +4394,7 @@ func (x *Ports) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Ports.ProtoReflect.Descriptor instead.
 func (*Ports) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{24}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{57}
 }
 
 func (x *Ports) GetName() string {
@@ -1918,16 +4411,72 @@ func (x *Ports) GetSystemName() string {
 	return ""
 }
 
+func (x *Ports) GetPciAddress() string {
+	if x != nil && x.PciAddress != nil {
+		return *x.PciAddress
+	}
+	return ""
+}
+
+func (x *Ports) GetDriver() string {
+	if x != nil && x.Driver != nil {
+		return *x.Driver
+	}
+	return ""
+}
+
+func (x *Ports) GetRxQueues() uint32 {
+	if x != nil && x.RxQueues != nil {
+		return *x.RxQueues
+	}
+	return 0
+}
+
+func (x *Ports) GetTxQueues() uint32 {
+	if x != nil && x.TxQueues != nil {
+		return *x.TxQueues
+	}
+	return 0
+}
+
+func (x *Ports) GetRxDescriptors() uint32 {
+	if x != nil && x.RxDescriptors != nil {
+		return *x.RxDescriptors
+	}
+	return 0
+}
+
+func (x *Ports) GetTxDescriptors() uint32 {
+	if x != nil && x.TxDescriptors != nil {
+		return *x.TxDescriptors
+	}
+	return 0
+}
+
+func (x *Ports) GetInterface() string {
+	if x != nil && x.Interface != nil {
+		return *x.Interface
+	}
+	return ""
+}
+
 // RTE EAL config options for DPDK
 type Eal struct {
-	state         protoimpl.MessageState `protogen:"open.v1"`
-	unknownFields protoimpl.UnknownFields
-	sizeCache     protoimpl.SizeCache
+	state          protoimpl.MessageState `protogen:"open.v1"`
+	Lcores         string                 `protobuf:"bytes,1,opt,name=lcores,proto3" json:"lcores,omitempty"`                                                           // CPU list of lcores, e.g. "0-3,8" (-l)
+	MainLcore      *uint32                `protobuf:"varint,2,opt,name=main_lcore,json=mainLcore,proto3,oneof" json:"main_lcore,omitempty"`                             // must be one of lcores (--main-lcore)
+	MemoryChannels *uint32                `protobuf:"varint,3,opt,name=memory_channels,json=memoryChannels,proto3,oneof" json:"memory_channels,omitempty"`              // number of memory channels (-n)
+	HugepageSize   HugePageSize           `protobuf:"varint,4,opt,name=hugepage_size,json=hugepageSize,proto3,enum=config.HugePageSize" json:"hugepage_size,omitempty"` // size of the pre-reserved huge pages
+	Hugepages      uint32                 `protobuf:"varint,5,opt,name=hugepages,proto3" json:"hugepages,omitempty"`                                                    // number of huge pages to use (-m), 0 for EAL default
+	PciAllow       []string               `protobuf:"bytes,6,rep,name=pci_allow,json=pciAllow,proto3" json:"pci_allow,omitempty"`                                       // PCI devices DPDK may probe, e.g. "0000:01:00.0" (-a)
+	ExtraArgs      []string               `protobuf:"bytes,7,rep,name=extra_args,json=extraArgs,proto3" json:"extra_args,omitempty"`                                    // appended verbatim after all other args
+	unknownFields  protoimpl.UnknownFields
+	sizeCache      protoimpl.SizeCache
 }
 
 func (x *Eal) Reset() {
 	*x = Eal{}
-	mi := &file_proto_dataplane_proto_msgTypes[25]
+	mi := &file_proto_dataplane_proto_msgTypes[58]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1939,7 +4488,7 @@ func (x *Eal) String() string {
 func (*Eal) ProtoMessage() {}
 
 func (x *Eal) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[25]
+	mi := &file_proto_dataplane_proto_msgTypes[58]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1952,7 +4501,56 @@ func (x *Eal) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Eal.ProtoReflect.Descriptor instead.
 func (*Eal) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{25}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{58}
+}
+
+func (x *Eal) GetLcores() string {
+	if x != nil {
+		return x.Lcores
+	}
+	return ""
+}
+
+func (x *Eal) GetMainLcore() uint32 {
+	if x != nil && x.MainLcore != nil {
+		return *x.MainLcore
+	}
+	return 0
+}
+
+func (x *Eal) GetMemoryChannels() uint32 {
+	if x != nil && x.MemoryChannels != nil {
+		return *x.MemoryChannels
+	}
+	return 0
+}
+
+func (x *Eal) GetHugepageSize() HugePageSize {
+	if x != nil {
+		return x.HugepageSize
+	}
+	return HugePageSize_HUGEPAGE_2M
+}
+
+func (x *Eal) GetHugepages() uint32 {
+	if x != nil {
+		return x.Hugepages
+	}
+	return 0
+}
+
+func (x *Eal) GetPciAllow() []string {
+	if x != nil {
+		return x.PciAllow
+	}
+	return nil
+}
+
+func (x *Eal) GetExtraArgs() []string {
+	if x != nil {
+		return x.ExtraArgs
+	}
+	return nil
 }
 
 // System level config options
@@ -1969,7 +4567,7 @@ type Device struct {
 
 func (x *Device) Reset() {
 	*x = Device{}
-	mi := &file_proto_dataplane_proto_msgTypes[26]
+	mi := &file_proto_dataplane_proto_msgTypes[59]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -1981,7 +4579,7 @@ func (x *Device) String() string {
 func (*Device) ProtoMessage() {}
 
 func (x *Device) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[26]
+	mi := &file_proto_dataplane_proto_msgTypes[59]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1994,7 +4592,7 @@ func (x *Device) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Device.ProtoReflect.Descriptor instead.
 func (*Device) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{26}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{59}
 }
 
 func (x *Device) GetDriver() PacketDriver {
@@ -2039,13 +4637,14 @@ type GatewayConfig struct {
 	Device        *Device                `protobuf:"bytes,2,opt,name=device,proto3" json:"device,omitempty"`
 	Underlay      *Underlay              `protobuf:"bytes,3,opt,name=underlay,proto3" json:"underlay,omitempty"`
 	Overlay       *Overlay               `protobuf:"bytes,4,opt,name=overlay,proto3" json:"overlay,omitempty"`
+	External      *External              `protobuf:"bytes,5,opt,name=external,proto3" json:"external,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
 
 func (x *GatewayConfig) Reset() {
 	*x = GatewayConfig{}
-	mi := &file_proto_dataplane_proto_msgTypes[27]
+	mi := &file_proto_dataplane_proto_msgTypes[60]
 	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 	ms.StoreMessageInfo(mi)
 }
@@ -2057,7 +4656,7 @@ func (x *GatewayConfig) String() string {
 func (*GatewayConfig) ProtoMessage() {}
 
 func (x *GatewayConfig) ProtoReflect() protoreflect.Message {
-	mi := &file_proto_dataplane_proto_msgTypes[27]
+	mi := &file_proto_dataplane_proto_msgTypes[60]
 	if x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2070,7 +4669,7 @@ func (x *GatewayConfig) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use GatewayConfig.ProtoReflect.Descriptor instead.
 func (*GatewayConfig) Descriptor() ([]byte, []int) {
-	return file_proto_dataplane_proto_rawDescGZIP(), []int{27}
+	return file_proto_dataplane_proto_rawDescGZIP(), []int{60}
 }
 
 func (x *GatewayConfig) GetGeneration() int64 {
@@ -2101,22 +4700,166 @@ func (x *GatewayConfig) GetOverlay() *Overlay {
 	return nil
 }
 
+func (x *GatewayConfig) GetExternal() *External {
+	if x != nil {
+		return x.External
+	}
+	return nil
+}
+
 var File_proto_dataplane_proto protoreflect.FileDescriptor
 
 const file_proto_dataplane_proto_rawDesc = "" +
 	"\n" +
 	"\x15proto/dataplane.proto\x12\x06config\"\x12\n" +
-	"\x10GetConfigRequest\"D\n" +
+	"\x10GetConfigRequest\"\x81\x02\n" +
 	"\x13UpdateConfigRequest\x12-\n" +
-	"\x06config\x18\x01 \x01(\v2\x15.config.GatewayConfigR\x06config\"U\n" +
+	"\x06config\x18\x01 \x01(\v2\x15.config.GatewayConfigR\x06config\x124\n" +
+	"\x13expected_generation\x18\x02 \x01(\x03H\x00R\x12expectedGeneration\x88\x01\x01\x12\x14\n" +
+	"\x05owner\x18\x03 \x01(\tR\x05owner\x126\n" +
+	"\x17confirm_timeout_seconds\x18\x04 \x01(\rR\x15confirmTimeoutSeconds\x12\x1f\n" +
+	"\vasync_apply\x18\x05 \x01(\bR\n" +
+	"asyncApplyB\x16\n" +
+	"\x14_expected_generation\"\xa8\x01\n" +
 	"\x14UpdateConfigResponse\x12#\n" +
 	"\x05error\x18\x01 \x01(\x0e2\r.config.ErrorR\x05error\x12\x18\n" +
-	"\amessage\x18\x02 \x01(\tR\amessage\"\x1c\n" +
-	"\x1aGetConfigGenerationRequest\"=\n" +
+	"\amessage\x18\x02 \x01(\tR\amessage\x12!\n" +
+	"\foperation_id\x18\x03 \x01(\tR\voperationId\x12.\n" +
+	"\aresults\x18\x04 \x03(\v2\x14.config.ObjectResultR\aresults\"\x8c\x01\n" +
+	"\fObjectResult\x12&\n" +
+	"\x04kind\x18\x01 \x01(\x0e2\x12.config.ObjectKindR\x04kind\x12\x12\n" +
+	"\x04name\x18\x02 \x01(\tR\x04name\x12(\n" +
+	"\x05state\x18\x03 \x01(\x0e2\x12.config.ApplyStateR\x05state\x12\x16\n" +
+	"\x06reason\x18\x04 \x01(\tR\x06reason\"\x1c\n" +
+	"\x1aGetConfigGenerationRequest\"`\n" +
 	"\x1bGetConfigGenerationResponse\x12\x1e\n" +
 	"\n" +
 	"generation\x18\x01 \x01(\x03R\n" +
-	"generation\"\xb1\x01\n" +
+	"generation\x12!\n" +
+	"\fcontent_hash\x18\x02 \x01(\tR\vcontentHash\"\x12\n" +
+	"\x10GetStatusRequest\"\xf7\x01\n" +
+	"\x11InterfaceCounters\x12\x1d\n" +
+	"\n" +
+	"rx_packets\x18\x01 \x01(\x04R\trxPackets\x12\x19\n" +
+	"\brx_bytes\x18\x02 \x01(\x04R\arxBytes\x12\x1b\n" +
+	"\trx_errors\x18\x03 \x01(\x04R\brxErrors\x12\x19\n" +
+	"\brx_drops\x18\x04 \x01(\x04R\arxDrops\x12\x1d\n" +
+	"\n" +
+	"tx_packets\x18\x05 \x01(\x04R\ttxPackets\x12\x19\n" +
+	"\btx_bytes\x18\x06 \x01(\x04R\atxBytes\x12\x1b\n" +
+	"\ttx_errors\x18\a \x01(\x04R\btxErrors\x12\x19\n" +
+	"\btx_drops\x18\b \x01(\x04R\atxDrops\"\x8e\x01\n" +
+	"\x0fInterfaceStatus\x12\x12\n" +
+	"\x04name\x18\x01 \x01(\tR\x04name\x120\n" +
+	"\n" +
+	"oper_state\x18\x02 \x01(\x0e2\x11.config.OperStateR\toperState\x125\n" +
+	"\bcounters\x18\x03 \x01(\v2\x19.config.InterfaceCountersR\bcounters\"\xf1\x01\n" +
+	"\x11BgpNeighborStatus\x12\x10\n" +
+	"\x03vrf\x18\x01 \x01(\tR\x03vrf\x12\x18\n" +
+	"\aaddress\x18\x02 \x01(\tR\aaddress\x12-\n" +
+	"\x05state\x18\x03 \x01(\x0e2\x17.config.BgpSessionStateR\x05state\x12+\n" +
+	"\x11prefixes_received\x18\x04 \x01(\x04R\x10prefixesReceived\x12#\n" +
+	"\rprefixes_sent\x18\x05 \x01(\x04R\fprefixesSent\x12/\n" +
+	"\x13established_seconds\x18\x06 \x01(\x04R\x12establishedSeconds\"\x84\x01\n" +
+	"\tVniStatus\x12\x10\n" +
+	"\x03vni\x18\x01 \x01(\rR\x03vni\x12\x10\n" +
+	"\x03vpc\x18\x02 \x01(\tR\x03vpc\x120\n" +
+	"\n" +
+	"oper_state\x18\x03 \x01(\x0e2\x11.config.OperStateR\toperState\x12!\n" +
+	"\fremote_vteps\x18\x04 \x03(\tR\vremoteVteps\"\x7f\n" +
+	"\n" +
+	"VtepStatus\x12\x18\n" +
+	"\aaddress\x18\x01 \x01(\tR\aaddress\x120\n" +
+	"\n" +
+	"oper_state\x18\x02 \x01(\x0e2\x11.config.OperStateR\toperState\x12%\n" +
+	"\x04vnis\x18\x03 \x03(\v2\x11.config.VniStatusR\x04vnis\"\xe2\x01\n" +
+	"\x11GetStatusResponse\x12\x1e\n" +
+	"\n" +
+	"generation\x18\x01 \x01(\x03R\n" +
+	"generation\x127\n" +
+	"\n" +
+	"interfaces\x18\x02 \x03(\v2\x17.config.InterfaceStatusR\n" +
+	"interfaces\x12>\n" +
+	"\rbgp_neighbors\x18\x03 \x03(\v2\x19.config.BgpNeighborStatusR\fbgpNeighbors\x12+\n" +
+	"\x04vtep\x18\x04 \x01(\v2\x12.config.VtepStatusH\x00R\x04vtep\x88\x01\x01B\a\n" +
+	"\x05_vtep\"\x14\n" +
+	"\x12GetCountersRequest\"A\n" +
+	"\x0fTrafficCounters\x12\x18\n" +
+	"\apackets\x18\x01 \x01(\x04R\apackets\x12\x14\n" +
+	"\x05bytes\x18\x02 \x01(\x04R\x05bytes\"\x94\x01\n" +
+	"\vVpcCounters\x12\x10\n" +
+	"\x03vpc\x18\x01 \x01(\tR\x03vpc\x12'\n" +
+	"\x02rx\x18\x02 \x01(\v2\x17.config.TrafficCountersR\x02rx\x12'\n" +
+	"\x02tx\x18\x03 \x01(\v2\x17.config.TrafficCountersR\x02tx\x12!\n" +
+	"\fnat_sessions\x18\x04 \x01(\x04R\vnatSessions\"\xad\x01\n" +
+	"\x0eExposeCounters\x12\x10\n" +
+	"\x03vpc\x18\x01 \x01(\tR\x03vpc\x12\x14\n" +
+	"\x05index\x18\x02 \x01(\rR\x05index\x12'\n" +
+	"\x02rx\x18\x03 \x01(\v2\x17.config.TrafficCountersR\x02rx\x12'\n" +
+	"\x02tx\x18\x04 \x01(\v2\x17.config.TrafficCountersR\x02tx\x12!\n" +
+	"\fnat_sessions\x18\x05 \x01(\x04R\vnatSessions\"W\n" +
+	"\x0fPeeringCounters\x12\x12\n" +
+	"\x04name\x18\x01 \x01(\tR\x04name\x120\n" +
+	"\aexposes\x18\x02 \x03(\v2\x16.config.ExposeCountersR\aexposes\"\xb6\x01\n" +
+	"\x13GetCountersResponse\x12\x1e\n" +
+	"\n" +
+	"generation\x18\x01 \x01(\x03R\n" +
+	"generation\x12!\n" +
+	"\ftimestamp_ms\x18\x02 \x01(\x04R\vtimestampMs\x12'\n" +
+	"\x04vpcs\x18\x03 \x03(\v2\x13.config.VpcCountersR\x04vpcs\x123\n" +
+	"\bpeerings\x18\x04 \x03(\v2\x17.config.PeeringCountersR\bpeerings\"o\n" +
+	"\x11ComponentLogLevel\x122\n" +
+	"\tcomponent\x18\x01 \x01(\x0e2\x14.config.LogComponentR\tcomponent\x12&\n" +
+	"\x05level\x18\x02 \x01(\x0e2\x10.config.LogLevelR\x05level\"\x86\x01\n" +
+	"\x12SetLogLevelRequest\x12+\n" +
+	"\x05level\x18\x01 \x01(\x0e2\x10.config.LogLevelH\x00R\x05level\x88\x01\x01\x129\n" +
+	"\n" +
+	"components\x18\x02 \x03(\v2\x19.config.ComponentLogLevelR\n" +
+	"componentsB\b\n" +
+	"\x06_level\"T\n" +
+	"\x13SetLogLevelResponse\x12#\n" +
+	"\x05error\x18\x01 \x01(\x0e2\r.config.ErrorR\x05error\x12\x18\n" +
+	"\amessage\x18\x02 \x01(\tR\amessage\"U\n" +
+	"\x18AcquireConfigLockRequest\x12\x14\n" +
+	"\x05owner\x18\x01 \x01(\tR\x05owner\x12#\n" +
+	"\rlease_seconds\x18\x02 \x01(\rR\fleaseSeconds\"\x94\x01\n" +
+	"\x19AcquireConfigLockResponse\x12#\n" +
+	"\x05error\x18\x01 \x01(\x0e2\r.config.ErrorR\x05error\x12\x18\n" +
+	"\amessage\x18\x02 \x01(\tR\amessage\x12\x14\n" +
+	"\x05owner\x18\x03 \x01(\tR\x05owner\x12\"\n" +
+	"\rexpires_at_ms\x18\x04 \x01(\x04R\vexpiresAtMs\"0\n" +
+	"\x18ReleaseConfigLockRequest\x12\x14\n" +
+	"\x05owner\x18\x01 \x01(\tR\x05owner\"Z\n" +
+	"\x19ReleaseConfigLockResponse\x12#\n" +
+	"\x05error\x18\x01 \x01(\x0e2\r.config.ErrorR\x05error\x12\x18\n" +
+	"\amessage\x18\x02 \x01(\tR\amessage\"6\n" +
+	"\x14ConfirmConfigRequest\x12\x1e\n" +
+	"\n" +
+	"generation\x18\x01 \x01(\x03R\n" +
+	"generation\"V\n" +
+	"\x15ConfirmConfigResponse\x12#\n" +
+	"\x05error\x18\x01 \x01(\x0e2\r.config.ErrorR\x05error\x12\x18\n" +
+	"\amessage\x18\x02 \x01(\tR\amessage\"h\n" +
+	"\rSectionResult\x12\x18\n" +
+	"\asection\x18\x01 \x01(\tR\asection\x12#\n" +
+	"\x05error\x18\x02 \x01(\x0e2\r.config.ErrorR\x05error\x12\x18\n" +
+	"\amessage\x18\x03 \x01(\tR\amessage\"\xbb\x02\n" +
+	"\x0eApplyOperation\x12\x0e\n" +
+	"\x02id\x18\x01 \x01(\tR\x02id\x12\x1e\n" +
+	"\n" +
+	"generation\x18\x02 \x01(\x03R\n" +
+	"generation\x12,\n" +
+	"\x05state\x18\x03 \x01(\x0e2\x16.config.OperationStateR\x05state\x12)\n" +
+	"\x10progress_percent\x18\x04 \x01(\rR\x0fprogressPercent\x121\n" +
+	"\bsections\x18\x05 \x03(\v2\x15.config.SectionResultR\bsections\x12#\n" +
+	"\x05error\x18\x06 \x01(\x0e2\r.config.ErrorR\x05error\x12\x18\n" +
+	"\amessage\x18\a \x01(\tR\amessage\x12.\n" +
+	"\aresults\x18\b \x03(\v2\x14.config.ObjectResultR\aresults\"%\n" +
+	"\x13GetOperationRequest\x12\x0e\n" +
+	"\x02id\x18\x01 \x01(\tR\x02id\"O\n" +
+	"\x14WaitOperationRequest\x12\x0e\n" +
+	"\x02id\x18\x01 \x01(\tR\x02id\x12'\n" +
+	"\x0ftimeout_seconds\x18\x02 \x01(\rR\x0etimeoutSeconds\"\xb1\x01\n" +
 	"\rOspfInterface\x12\x18\n" +
 	"\apassive\x18\x01 \x01(\bR\apassive\x12\x12\n" +
 	"\x04area\x18\x02 \x01(\tR\x04area\x12\x17\n" +
@@ -2154,10 +4897,33 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\tPeeringAs\x12\x14\n" +
 	"\x04cidr\x18\x01 \x01(\tH\x00R\x04cidr\x12\x12\n" +
 	"\x03not\x18\x02 \x01(\tH\x00R\x03notB\x06\n" +
-	"\x04rule\"Q\n" +
+	"\x04rule\"3\n" +
+	"\tPortRange\x12\x14\n" +
+	"\x05start\x18\x01 \x01(\rR\x05start\x12\x10\n" +
+	"\x03end\x18\x02 \x01(\rR\x03end\"W\n" +
+	"\aNatPool\x12\x14\n" +
+	"\x05cidrs\x18\x01 \x03(\tR\x05cidrs\x12,\n" +
+	"\x05ports\x18\x02 \x01(\v2\x11.config.PortRangeH\x00R\x05ports\x88\x01\x01B\b\n" +
+	"\x06_ports\"\x81\x02\n" +
+	"\vStatefulNat\x12#\n" +
+	"\x04pool\x18\x01 \x01(\v2\x0f.config.NatPoolR\x04pool\x12-\n" +
+	"\x10tcp_idle_timeout\x18\x02 \x01(\rH\x00R\x0etcpIdleTimeout\x88\x01\x01\x12-\n" +
+	"\x10udp_idle_timeout\x18\x03 \x01(\rH\x01R\x0eudpIdleTimeout\x88\x01\x01\x12/\n" +
+	"\x11icmp_idle_timeout\x18\x04 \x01(\rH\x02R\x0ficmpIdleTimeout\x88\x01\x01B\x13\n" +
+	"\x11_tcp_idle_timeoutB\x13\n" +
+	"\x11_udp_idle_timeoutB\x14\n" +
+	"\x12_icmp_idle_timeout\"\x82\x01\n" +
+	"\bL4Filter\x12.\n" +
+	"\bprotocol\x18\x01 \x01(\x0e2\x12.config.L4ProtocolR\bprotocol\x12'\n" +
+	"\x05ports\x18\x02 \x03(\v2\x11.config.PortRangeR\x05ports\x12\x1d\n" +
+	"\n" +
+	"icmp_types\x18\x03 \x03(\rR\ticmpTypes\"\xcb\x01\n" +
 	"\x06Expose\x12$\n" +
 	"\x03ips\x18\x01 \x03(\v2\x12.config.PeeringIPsR\x03ips\x12!\n" +
-	"\x02as\x18\x02 \x03(\v2\x11.config.PeeringAsR\x02as\"K\n" +
+	"\x02as\x18\x02 \x03(\v2\x11.config.PeeringAsR\x02as\x12;\n" +
+	"\fstateful_nat\x18\x03 \x01(\v2\x13.config.StatefulNatH\x00R\vstatefulNat\x88\x01\x01\x12*\n" +
+	"\afilters\x18\x04 \x03(\v2\x10.config.L4FilterR\afiltersB\x0f\n" +
+	"\r_stateful_nat\"K\n" +
 	"\x0fPeeringEntryFor\x12\x10\n" +
 	"\x03vpc\x18\x01 \x01(\tR\x03vpc\x12&\n" +
 	"\x06expose\x18\x02 \x03(\v2\x0e.config.ExposeR\x06expose\"K\n" +
@@ -2224,31 +4990,103 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\a_routerB\a\n" +
 	"\x05_ospf\"+\n" +
 	"\bUnderlay\x12\x1f\n" +
-	"\x04vrfs\x18\x01 \x03(\v2\v.config.VRFR\x04vrfs\"<\n" +
+	"\x04vrfs\x18\x01 \x03(\v2\v.config.VRFR\x04vrfs\"\xb3\x01\n" +
+	"\x12ExternalAttachment\x12\x12\n" +
+	"\x04name\x18\x01 \x01(\tR\x04name\x12\x10\n" +
+	"\x03vrf\x18\x02 \x01(\tR\x03vrf\x121\n" +
+	"\tupstreams\x18\x03 \x03(\v2\x13.config.BgpNeighborR\tupstreams\x12\x12\n" +
+	"\x04vpcs\x18\x04 \x03(\tR\x04vpcs\x120\n" +
+	"\x14inject_default_route\x18\x05 \x01(\bR\x12injectDefaultRoute\"H\n" +
+	"\bExternal\x12<\n" +
+	"\vattachments\x18\x01 \x03(\v2\x1a.config.ExternalAttachmentR\vattachments\"\xa9\x03\n" +
 	"\x05Ports\x12\x12\n" +
 	"\x04name\x18\x01 \x01(\tR\x04name\x12\x1f\n" +
 	"\vsystem_name\x18\x02 \x01(\tR\n" +
-	"systemName\"\x05\n" +
-	"\x03Eal\"\xc4\x01\n" +
+	"systemName\x12$\n" +
+	"\vpci_address\x18\x03 \x01(\tH\x00R\n" +
+	"pciAddress\x88\x01\x01\x12\x1b\n" +
+	"\x06driver\x18\x04 \x01(\tH\x01R\x06driver\x88\x01\x01\x12 \n" +
+	"\trx_queues\x18\x05 \x01(\rH\x02R\brxQueues\x88\x01\x01\x12 \n" +
+	"\ttx_queues\x18\x06 \x01(\rH\x03R\btxQueues\x88\x01\x01\x12*\n" +
+	"\x0erx_descriptors\x18\a \x01(\rH\x04R\rrxDescriptors\x88\x01\x01\x12*\n" +
+	"\x0etx_descriptors\x18\b \x01(\rH\x05R\rtxDescriptors\x88\x01\x01\x12!\n" +
+	"\tinterface\x18\t \x01(\tH\x06R\tinterface\x88\x01\x01B\x0e\n" +
+	"\f_pci_addressB\t\n" +
+	"\a_driverB\f\n" +
+	"\n" +
+	"_rx_queuesB\f\n" +
+	"\n" +
+	"_tx_queuesB\x11\n" +
+	"\x0f_rx_descriptorsB\x11\n" +
+	"\x0f_tx_descriptorsB\f\n" +
+	"\n" +
+	"_interface\"\xa7\x02\n" +
+	"\x03Eal\x12\x16\n" +
+	"\x06lcores\x18\x01 \x01(\tR\x06lcores\x12\"\n" +
+	"\n" +
+	"main_lcore\x18\x02 \x01(\rH\x00R\tmainLcore\x88\x01\x01\x12,\n" +
+	"\x0fmemory_channels\x18\x03 \x01(\rH\x01R\x0ememoryChannels\x88\x01\x01\x129\n" +
+	"\rhugepage_size\x18\x04 \x01(\x0e2\x14.config.HugePageSizeR\fhugepageSize\x12\x1c\n" +
+	"\thugepages\x18\x05 \x01(\rR\thugepages\x12\x1b\n" +
+	"\tpci_allow\x18\x06 \x03(\tR\bpciAllow\x12\x1d\n" +
+	"\n" +
+	"extra_args\x18\a \x03(\tR\textraArgsB\r\n" +
+	"\v_main_lcoreB\x12\n" +
+	"\x10_memory_channels\"\xc4\x01\n" +
 	"\x06Device\x12,\n" +
 	"\x06driver\x18\x01 \x01(\x0e2\x14.config.PacketDriverR\x06driver\x12\x1d\n" +
 	"\x03eal\x18\x02 \x01(\v2\v.config.EalR\x03eal\x12#\n" +
 	"\x05ports\x18\x03 \x03(\v2\r.config.PortsR\x05ports\x12\x1a\n" +
 	"\bhostname\x18\x04 \x01(\tR\bhostname\x12,\n" +
-	"\bloglevel\x18\x05 \x01(\x0e2\x10.config.LogLevelR\bloglevel\"\xb0\x01\n" +
+	"\bloglevel\x18\x05 \x01(\x0e2\x10.config.LogLevelR\bloglevel\"\xde\x01\n" +
 	"\rGatewayConfig\x12\x1e\n" +
 	"\n" +
 	"generation\x18\x01 \x01(\x03R\n" +
 	"generation\x12&\n" +
 	"\x06device\x18\x02 \x01(\v2\x0e.config.DeviceR\x06device\x12,\n" +
 	"\bunderlay\x18\x03 \x01(\v2\x10.config.UnderlayR\bunderlay\x12)\n" +
-	"\aoverlay\x18\x04 \x01(\v2\x0f.config.OverlayR\aoverlay*e\n" +
+	"\aoverlay\x18\x04 \x01(\v2\x0f.config.OverlayR\aoverlay\x12,\n" +
+	"\bexternal\x18\x05 \x01(\v2\x10.config.ExternalR\bexternal*\x80\x01\n" +
+	"\n" +
+	"ObjectKind\x12\x17\n" +
+	"\x13OBJECT_KIND_UNKNOWN\x10\x00\x12\x13\n" +
+	"\x0fOBJECT_KIND_VRF\x10\x01\x12\x13\n" +
+	"\x0fOBJECT_KIND_VPC\x10\x02\x12\x17\n" +
+	"\x13OBJECT_KIND_PEERING\x10\x03\x12\x16\n" +
+	"\x12OBJECT_KIND_DEVICE\x10\x04*o\n" +
+	"\n" +
+	"ApplyState\x12\x17\n" +
+	"\x13APPLY_STATE_UNKNOWN\x10\x00\x12\x17\n" +
+	"\x13APPLY_STATE_APPLIED\x10\x01\x12\x16\n" +
+	"\x12APPLY_STATE_FAILED\x10\x02\x12\x17\n" +
+	"\x13APPLY_STATE_SKIPPED\x10\x03*\x96\x01\n" +
 	"\x05Error\x12\x0e\n" +
 	"\n" +
 	"ERROR_NONE\x10\x00\x12\x1b\n" +
 	"\x17ERROR_VALIDATION_FAILED\x10\x01\x12\x16\n" +
 	"\x12ERROR_APPLY_FAILED\x10\x02\x12\x17\n" +
-	"\x13ERROR_UNKNOWN_ERROR\x10\x03*`\n" +
+	"\x13ERROR_UNKNOWN_ERROR\x10\x03\x12\x1d\n" +
+	"\x19ERROR_GENERATION_CONFLICT\x10\x04\x12\x10\n" +
+	"\fERROR_LOCKED\x10\x05*K\n" +
+	"\tOperState\x12\x16\n" +
+	"\x12OPER_STATE_UNKNOWN\x10\x00\x12\x11\n" +
+	"\rOPER_STATE_UP\x10\x01\x12\x13\n" +
+	"\x0fOPER_STATE_DOWN\x10\x02*\xd2\x01\n" +
+	"\x0fBgpSessionState\x12\x1a\n" +
+	"\x16BGP_SESSION_STATE_IDLE\x10\x00\x12\x1d\n" +
+	"\x19BGP_SESSION_STATE_CONNECT\x10\x01\x12\x1c\n" +
+	"\x18BGP_SESSION_STATE_ACTIVE\x10\x02\x12\x1f\n" +
+	"\x1bBGP_SESSION_STATE_OPEN_SENT\x10\x03\x12\"\n" +
+	"\x1eBGP_SESSION_STATE_OPEN_CONFIRM\x10\x04\x12!\n" +
+	"\x1dBGP_SESSION_STATE_ESTABLISHED\x10\x05*p\n" +
+	"\fLogComponent\x12\x16\n" +
+	"\x12LOG_COMPONENT_CORE\x10\x00\x12\x16\n" +
+	"\x12LOG_COMPONENT_DPDK\x10\x01\x12\x15\n" +
+	"\x11LOG_COMPONENT_NAT\x10\x02\x12\x19\n" +
+	"\x15LOG_COMPONENT_ROUTING\x10\x03*G\n" +
+	"\x0eOperationState\x12\x1b\n" +
+	"\x17OPERATION_STATE_RUNNING\x10\x00\x12\x18\n" +
+	"\x14OPERATION_STATE_DONE\x10\x01*`\n" +
 	"\x0fOspfNetworkType\x12\r\n" +
 	"\tBROADCAST\x10\x00\x12\x11\n" +
 	"\rNON_BROADCAST\x10\x01\x12\x12\n" +
@@ -2261,12 +5099,22 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\fIF_TYPE_VTEP\x10\x03*2\n" +
 	"\x06IfRole\x12\x12\n" +
 	"\x0eIF_ROLE_FABRIC\x10\x00\x12\x14\n" +
-	"\x10IF_ROLE_EXTERNAL\x10\x01*;\n" +
+	"\x10IF_ROLE_EXTERNAL\x10\x01*y\n" +
+	"\n" +
+	"L4Protocol\x12\x13\n" +
+	"\x0fL4_PROTOCOL_ANY\x10\x00\x12\x13\n" +
+	"\x0fL4_PROTOCOL_TCP\x10\x01\x12\x13\n" +
+	"\x0fL4_PROTOCOL_UDP\x10\x02\x12\x14\n" +
+	"\x10L4_PROTOCOL_ICMP\x10\x03\x12\x16\n" +
+	"\x12L4_PROTOCOL_ICMPV6\x10\x04*;\n" +
 	"\x05BgpAF\x12\x10\n" +
 	"\fIPV4_UNICAST\x10\x00\x12\x10\n" +
 	"\fIPV6_UNICAST\x10\x01\x12\x0e\n" +
 	"\n" +
-	"L2VPN_EVPN\x10\x02*B\n" +
+	"L2VPN_EVPN\x10\x02*0\n" +
+	"\fHugePageSize\x12\x0f\n" +
+	"\vHUGEPAGE_2M\x10\x00\x12\x0f\n" +
+	"\vHUGEPAGE_1G\x10\x01*B\n" +
 	"\bLogLevel\x12\t\n" +
 	"\x05ERROR\x10\x00\x12\v\n" +
 	"\aWARNING\x10\x01\x12\b\n" +
@@ -2276,11 +5124,19 @@ const file_proto_dataplane_proto_rawDesc = "" +
 	"\fPacketDriver\x12\n" +
 	"\n" +
 	"\x06KERNEL\x10\x00\x12\b\n" +
-	"\x04DPDK\x10\x012\xf8\x01\n" +
+	"\x04DPDK\x10\x012\xd8\x06\n" +
 	"\rConfigService\x12<\n" +
 	"\tGetConfig\x12\x18.config.GetConfigRequest\x1a\x15.config.GatewayConfig\x12^\n" +
 	"\x13GetConfigGeneration\x12\".config.GetConfigGenerationRequest\x1a#.config.GetConfigGenerationResponse\x12I\n" +
-	"\fUpdateConfig\x12\x1b.config.UpdateConfigRequest\x1a\x1c.config.UpdateConfigResponseB\x0fZ\rpkg/dataplaneb\x06proto3"
+	"\fUpdateConfig\x12\x1b.config.UpdateConfigRequest\x1a\x1c.config.UpdateConfigResponse\x12@\n" +
+	"\tGetStatus\x12\x18.config.GetStatusRequest\x1a\x19.config.GetStatusResponse\x12F\n" +
+	"\vGetCounters\x12\x1a.config.GetCountersRequest\x1a\x1b.config.GetCountersResponse\x12F\n" +
+	"\vSetLogLevel\x12\x1a.config.SetLogLevelRequest\x1a\x1b.config.SetLogLevelResponse\x12X\n" +
+	"\x11AcquireConfigLock\x12 .config.AcquireConfigLockRequest\x1a!.config.AcquireConfigLockResponse\x12X\n" +
+	"\x11ReleaseConfigLock\x12 .config.ReleaseConfigLockRequest\x1a!.config.ReleaseConfigLockResponse\x12L\n" +
+	"\rConfirmConfig\x12\x1c.config.ConfirmConfigRequest\x1a\x1d.config.ConfirmConfigResponse\x12C\n" +
+	"\fGetOperation\x12\x1b.config.GetOperationRequest\x1a\x16.config.ApplyOperation\x12E\n" +
+	"\rWaitOperation\x12\x1c.config.WaitOperationRequest\x1a\x16.config.ApplyOperationB\x0fZ\rpkg/dataplaneb\x06proto3"
 
 var (
 	file_proto_dataplane_proto_rawDescOnce sync.Once
@@ -2294,88 +5150,187 @@ func file_proto_dataplane_proto_rawDescGZIP() []byte {
 	return file_proto_dataplane_proto_rawDescData
 }
 
-var file_proto_dataplane_proto_enumTypes = make([]protoimpl.EnumInfo, 7)
-var file_proto_dataplane_proto_msgTypes = make([]protoimpl.MessageInfo, 28)
+var file_proto_dataplane_proto_enumTypes = make([]protoimpl.EnumInfo, 15)
+var file_proto_dataplane_proto_msgTypes = make([]protoimpl.MessageInfo, 61)
 var file_proto_dataplane_proto_goTypes = []any{
-	(Error)(0),                          // 0: config.Error
-	(OspfNetworkType)(0),                // 1: config.OspfNetworkType
-	(IfType)(0),                         // 2: config.IfType
-	(IfRole)(0),                         // 3: config.IfRole
-	(BgpAF)(0),                          // 4: config.BgpAF
-	(LogLevel)(0),                       // 5: config.LogLevel
-	(PacketDriver)(0),                   // 6: config.PacketDriver
-	(*GetConfigRequest)(nil),            // 7: config.GetConfigRequest
-	(*UpdateConfigRequest)(nil),         // 8: config.UpdateConfigRequest
-	(*UpdateConfigResponse)(nil),        // 9: config.UpdateConfigResponse
-	(*GetConfigGenerationRequest)(nil),  // 10: config.GetConfigGenerationRequest
-	(*GetConfigGenerationResponse)(nil), // 11: config.GetConfigGenerationResponse
-	(*OspfInterface)(nil),               // 12: config.OspfInterface
-	(*OspfConfig)(nil),                  // 13: config.OspfConfig
-	(*Interface)(nil),                   // 14: config.Interface
-	(*PeeringIPs)(nil),                  // 15: config.PeeringIPs
-	(*PeeringAs)(nil),                   // 16: config.PeeringAs
-	(*Expose)(nil),                      // 17: config.Expose
-	(*PeeringEntryFor)(nil),             // 18: config.PeeringEntryFor
-	(*VpcPeering)(nil),                  // 19: config.VpcPeering
-	(*VPC)(nil),                         // 20: config.VPC
-	(*Overlay)(nil),                     // 21: config.Overlay
-	(*BgpAddressFamilyIPv4)(nil),        // 22: config.BgpAddressFamilyIPv4
-	(*BgpAddressFamilyIPv6)(nil),        // 23: config.BgpAddressFamilyIPv6
-	(*BgpAddressFamilyL2VpnEvpn)(nil),   // 24: config.BgpAddressFamilyL2vpnEvpn
-	(*BgpNeighborUpdateSource)(nil),     // 25: config.BgpNeighborUpdateSource
-	(*BgpNeighbor)(nil),                 // 26: config.BgpNeighbor
-	(*RouteMap)(nil),                    // 27: config.RouteMap
-	(*RouterConfig)(nil),                // 28: config.RouterConfig
-	(*VRF)(nil),                         // 29: config.VRF
-	(*Underlay)(nil),                    // 30: config.Underlay
-	(*Ports)(nil),                       // 31: config.Ports
-	(*Eal)(nil),                         // 32: config.Eal
-	(*Device)(nil),                      // 33: config.Device
-	(*GatewayConfig)(nil),               // 34: config.GatewayConfig
+	(ObjectKind)(0),                     // 0: config.ObjectKind
+	(ApplyState)(0),                     // 1: config.ApplyState
+	(Error)(0),                          // 2: config.Error
+	(OperState)(0),                      // 3: config.OperState
+	(BgpSessionState)(0),                // 4: config.BgpSessionState
+	(LogComponent)(0),                   // 5: config.LogComponent
+	(OperationState)(0),                 // 6: config.OperationState
+	(OspfNetworkType)(0),                // 7: config.OspfNetworkType
+	(IfType)(0),                         // 8: config.IfType
+	(IfRole)(0),                         // 9: config.IfRole
+	(L4Protocol)(0),                     // 10: config.L4Protocol
+	(BgpAF)(0),                          // 11: config.BgpAF
+	(HugePageSize)(0),                   // 12: config.HugePageSize
+	(LogLevel)(0),                       // 13: config.LogLevel
+	(PacketDriver)(0),                   // 14: config.PacketDriver
+	(*GetConfigRequest)(nil),            // 15: config.GetConfigRequest
+	(*UpdateConfigRequest)(nil),         // 16: config.UpdateConfigRequest
+	(*UpdateConfigResponse)(nil),        // 17: config.UpdateConfigResponse
+	(*ObjectResult)(nil),                // 18: config.ObjectResult
+	(*GetConfigGenerationRequest)(nil),  // 19: config.GetConfigGenerationRequest
+	(*GetConfigGenerationResponse)(nil), // 20: config.GetConfigGenerationResponse
+	(*GetStatusRequest)(nil),            // 21: config.GetStatusRequest
+	(*InterfaceCounters)(nil),           // 22: config.InterfaceCounters
+	(*InterfaceStatus)(nil),             // 23: config.InterfaceStatus
+	(*BgpNeighborStatus)(nil),           // 24: config.BgpNeighborStatus
+	(*VniStatus)(nil),                   // 25: config.VniStatus
+	(*VtepStatus)(nil),                  // 26: config.VtepStatus
+	(*GetStatusResponse)(nil),           // 27: config.GetStatusResponse
+	(*GetCountersRequest)(nil),          // 28: config.GetCountersRequest
+	(*TrafficCounters)(nil),             // 29: config.TrafficCounters
+	(*VpcCounters)(nil),                 // 30: config.VpcCounters
+	(*ExposeCounters)(nil),              // 31: config.ExposeCounters
+	(*PeeringCounters)(nil),             // 32: config.PeeringCounters
+	(*GetCountersResponse)(nil),         // 33: config.GetCountersResponse
+	(*ComponentLogLevel)(nil),           // 34: config.ComponentLogLevel
+	(*SetLogLevelRequest)(nil),          // 35: config.SetLogLevelRequest
+	(*SetLogLevelResponse)(nil),         // 36: config.SetLogLevelResponse
+	(*AcquireConfigLockRequest)(nil),    // 37: config.AcquireConfigLockRequest
+	(*AcquireConfigLockResponse)(nil),   // 38: config.AcquireConfigLockResponse
+	(*ReleaseConfigLockRequest)(nil),    // 39: config.ReleaseConfigLockRequest
+	(*ReleaseConfigLockResponse)(nil),   // 40: config.ReleaseConfigLockResponse
+	(*ConfirmConfigRequest)(nil),        // 41: config.ConfirmConfigRequest
+	(*ConfirmConfigResponse)(nil),       // 42: config.ConfirmConfigResponse
+	(*SectionResult)(nil),               // 43: config.SectionResult
+	(*ApplyOperation)(nil),              // 44: config.ApplyOperation
+	(*GetOperationRequest)(nil),         // 45: config.GetOperationRequest
+	(*WaitOperationRequest)(nil),        // 46: config.WaitOperationRequest
+	(*OspfInterface)(nil),               // 47: config.OspfInterface
+	(*OspfConfig)(nil),                  // 48: config.OspfConfig
+	(*Interface)(nil),                   // 49: config.Interface
+	(*PeeringIPs)(nil),                  // 50: config.PeeringIPs
+	(*PeeringAs)(nil),                   // 51: config.PeeringAs
+	(*PortRange)(nil),                   // 52: config.PortRange
+	(*NatPool)(nil),                     // 53: config.NatPool
+	(*StatefulNat)(nil),                 // 54: config.StatefulNat
+	(*L4Filter)(nil),                    // 55: config.L4Filter
+	(*Expose)(nil),                      // 56: config.Expose
+	(*PeeringEntryFor)(nil),             // 57: config.PeeringEntryFor
+	(*VpcPeering)(nil),                  // 58: config.VpcPeering
+	(*VPC)(nil),                         // 59: config.VPC
+	(*Overlay)(nil),                     // 60: config.Overlay
+	(*BgpAddressFamilyIPv4)(nil),        // 61: config.BgpAddressFamilyIPv4
+	(*BgpAddressFamilyIPv6)(nil),        // 62: config.BgpAddressFamilyIPv6
+	(*BgpAddressFamilyL2VpnEvpn)(nil),   // 63: config.BgpAddressFamilyL2vpnEvpn
+	(*BgpNeighborUpdateSource)(nil),     // 64: config.BgpNeighborUpdateSource
+	(*BgpNeighbor)(nil),                 // 65: config.BgpNeighbor
+	(*RouteMap)(nil),                    // 66: config.RouteMap
+	(*RouterConfig)(nil),                // 67: config.RouterConfig
+	(*VRF)(nil),                         // 68: config.VRF
+	(*Underlay)(nil),                    // 69: config.Underlay
+	(*ExternalAttachment)(nil),          // 70: config.ExternalAttachment
+	(*External)(nil),                    // 71: config.External
+	(*Ports)(nil),                       // 72: config.Ports
+	(*Eal)(nil),                         // 73: config.Eal
+	(*Device)(nil),                      // 74: config.Device
+	(*GatewayConfig)(nil),               // 75: config.GatewayConfig
 }
 var file_proto_dataplane_proto_depIdxs = []int32{
-	34, // 0: config.UpdateConfigRequest.config:type_name -> config.GatewayConfig
-	0,  // 1: config.UpdateConfigResponse.error:type_name -> config.Error
-	1,  // 2: config.OspfInterface.network_type:type_name -> config.OspfNetworkType
-	2,  // 3: config.Interface.type:type_name -> config.IfType
-	3,  // 4: config.Interface.role:type_name -> config.IfRole
-	12, // 5: config.Interface.ospf:type_name -> config.OspfInterface
-	15, // 6: config.Expose.ips:type_name -> config.PeeringIPs
-	16, // 7: config.Expose.as:type_name -> config.PeeringAs
-	17, // 8: config.PeeringEntryFor.expose:type_name -> config.Expose
-	18, // 9: config.VpcPeering.for:type_name -> config.PeeringEntryFor
-	14, // 10: config.VPC.interfaces:type_name -> config.Interface
-	20, // 11: config.Overlay.vpcs:type_name -> config.VPC
-	19, // 12: config.Overlay.peerings:type_name -> config.VpcPeering
-	4,  // 13: config.BgpNeighbor.af_activate:type_name -> config.BgpAF
-	25, // 14: config.BgpNeighbor.update_source:type_name -> config.BgpNeighborUpdateSource
-	26, // 15: config.RouterConfig.neighbors:type_name -> config.BgpNeighbor
-	22, // 16: config.RouterConfig.ipv4_unicast:type_name -> config.BgpAddressFamilyIPv4
-	23, // 17: config.RouterConfig.ipv6_unicast:type_name -> config.BgpAddressFamilyIPv6
-	24, // 18: config.RouterConfig.l2vpn_evpn:type_name -> config.BgpAddressFamilyL2vpnEvpn
-	27, // 19: config.RouterConfig.route_maps:type_name -> config.RouteMap
-	14, // 20: config.VRF.interfaces:type_name -> config.Interface
-	28, // 21: config.VRF.router:type_name -> config.RouterConfig
-	13, // 22: config.VRF.ospf:type_name -> config.OspfConfig
-	29, // 23: config.Underlay.vrfs:type_name -> config.VRF
-	6,  // 24: config.Device.driver:type_name -> config.PacketDriver
-	32, // 25: config.Device.eal:type_name -> config.Eal
-	31, // 26: config.Device.ports:type_name -> config.Ports
-	5,  // 27: config.Device.loglevel:type_name -> config.LogLevel
-	33, // 28: config.GatewayConfig.device:type_name -> config.Device
-	30, // 29: config.GatewayConfig.underlay:type_name -> config.Underlay
-	21, // 30: config.GatewayConfig.overlay:type_name -> config.Overlay
-	7,  // 31: config.ConfigService.GetConfig:input_type -> config.GetConfigRequest
-	10, // 32: config.ConfigService.GetConfigGeneration:input_type -> config.GetConfigGenerationRequest
-	8,  // 33: config.ConfigService.UpdateConfig:input_type -> config.UpdateConfigRequest
-	34, // 34: config.ConfigService.GetConfig:output_type -> config.GatewayConfig
-	11, // 35: config.ConfigService.GetConfigGeneration:output_type -> config.GetConfigGenerationResponse
-	9,  // 36: config.ConfigService.UpdateConfig:output_type -> config.UpdateConfigResponse
-	34, // [34:37] is the sub-list for method output_type
-	31, // [31:34] is the sub-list for method input_type
-	31, // [31:31] is the sub-list for extension type_name
-	31, // [31:31] is the sub-list for extension extendee
-	0,  // [0:31] is the sub-list for field type_name
+	75, // 0: config.UpdateConfigRequest.config:type_name -> config.GatewayConfig
+	2,  // 1: config.UpdateConfigResponse.error:type_name -> config.Error
+	18, // 2: config.UpdateConfigResponse.results:type_name -> config.ObjectResult
+	0,  // 3: config.ObjectResult.kind:type_name -> config.ObjectKind
+	1,  // 4: config.ObjectResult.state:type_name -> config.ApplyState
+	3,  // 5: config.InterfaceStatus.oper_state:type_name -> config.OperState
+	22, // 6: config.InterfaceStatus.counters:type_name -> config.InterfaceCounters
+	4,  // 7: config.BgpNeighborStatus.state:type_name -> config.BgpSessionState
+	3,  // 8: config.VniStatus.oper_state:type_name -> config.OperState
+	3,  // 9: config.VtepStatus.oper_state:type_name -> config.OperState
+	25, // 10: config.VtepStatus.vnis:type_name -> config.VniStatus
+	23, // 11: config.GetStatusResponse.interfaces:type_name -> config.InterfaceStatus
+	24, // 12: config.GetStatusResponse.bgp_neighbors:type_name -> config.BgpNeighborStatus
+	26, // 13: config.GetStatusResponse.vtep:type_name -> config.VtepStatus
+	29, // 14: config.VpcCounters.rx:type_name -> config.TrafficCounters
+	29, // 15: config.VpcCounters.tx:type_name -> config.TrafficCounters
+	29, // 16: config.ExposeCounters.rx:type_name -> config.TrafficCounters
+	29, // 17: config.ExposeCounters.tx:type_name -> config.TrafficCounters
+	31, // 18: config.PeeringCounters.exposes:type_name -> config.ExposeCounters
+	30, // 19: config.GetCountersResponse.vpcs:type_name -> config.VpcCounters
+	32, // 20: config.GetCountersResponse.peerings:type_name -> config.PeeringCounters
+	5,  // 21: config.ComponentLogLevel.component:type_name -> config.LogComponent
+	13, // 22: config.ComponentLogLevel.level:type_name -> config.LogLevel
+	13, // 23: config.SetLogLevelRequest.level:type_name -> config.LogLevel
+	34, // 24: config.SetLogLevelRequest.components:type_name -> config.ComponentLogLevel
+	2,  // 25: config.SetLogLevelResponse.error:type_name -> config.Error
+	2,  // 26: config.AcquireConfigLockResponse.error:type_name -> config.Error
+	2,  // 27: config.ReleaseConfigLockResponse.error:type_name -> config.Error
+	2,  // 28: config.ConfirmConfigResponse.error:type_name -> config.Error
+	2,  // 29: config.SectionResult.error:type_name -> config.Error
+	6,  // 30: config.ApplyOperation.state:type_name -> config.OperationState
+	43, // 31: config.ApplyOperation.sections:type_name -> config.SectionResult
+	2,  // 32: config.ApplyOperation.error:type_name -> config.Error
+	18, // 33: config.ApplyOperation.results:type_name -> config.ObjectResult
+	7,  // 34: config.OspfInterface.network_type:type_name -> config.OspfNetworkType
+	8,  // 35: config.Interface.type:type_name -> config.IfType
+	9,  // 36: config.Interface.role:type_name -> config.IfRole
+	47, // 37: config.Interface.ospf:type_name -> config.OspfInterface
+	52, // 38: config.NatPool.ports:type_name -> config.PortRange
+	53, // 39: config.StatefulNat.pool:type_name -> config.NatPool
+	10, // 40: config.L4Filter.protocol:type_name -> config.L4Protocol
+	52, // 41: config.L4Filter.ports:type_name -> config.PortRange
+	50, // 42: config.Expose.ips:type_name -> config.PeeringIPs
+	51, // 43: config.Expose.as:type_name -> config.PeeringAs
+	54, // 44: config.Expose.stateful_nat:type_name -> config.StatefulNat
+	55, // 45: config.Expose.filters:type_name -> config.L4Filter
+	56, // 46: config.PeeringEntryFor.expose:type_name -> config.Expose
+	57, // 47: config.VpcPeering.for:type_name -> config.PeeringEntryFor
+	49, // 48: config.VPC.interfaces:type_name -> config.Interface
+	59, // 49: config.Overlay.vpcs:type_name -> config.VPC
+	58, // 50: config.Overlay.peerings:type_name -> config.VpcPeering
+	11, // 51: config.BgpNeighbor.af_activate:type_name -> config.BgpAF
+	64, // 52: config.BgpNeighbor.update_source:type_name -> config.BgpNeighborUpdateSource
+	65, // 53: config.RouterConfig.neighbors:type_name -> config.BgpNeighbor
+	61, // 54: config.RouterConfig.ipv4_unicast:type_name -> config.BgpAddressFamilyIPv4
+	62, // 55: config.RouterConfig.ipv6_unicast:type_name -> config.BgpAddressFamilyIPv6
+	63, // 56: config.RouterConfig.l2vpn_evpn:type_name -> config.BgpAddressFamilyL2vpnEvpn
+	66, // 57: config.RouterConfig.route_maps:type_name -> config.RouteMap
+	49, // 58: config.VRF.interfaces:type_name -> config.Interface
+	67, // 59: config.VRF.router:type_name -> config.RouterConfig
+	48, // 60: config.VRF.ospf:type_name -> config.OspfConfig
+	68, // 61: config.Underlay.vrfs:type_name -> config.VRF
+	65, // 62: config.ExternalAttachment.upstreams:type_name -> config.BgpNeighbor
+	70, // 63: config.External.attachments:type_name -> config.ExternalAttachment
+	12, // 64: config.Eal.hugepage_size:type_name -> config.HugePageSize
+	14, // 65: config.Device.driver:type_name -> config.PacketDriver
+	73, // 66: config.Device.eal:type_name -> config.Eal
+	72, // 67: config.Device.ports:type_name -> config.Ports
+	13, // 68: config.Device.loglevel:type_name -> config.LogLevel
+	74, // 69: config.GatewayConfig.device:type_name -> config.Device
+	69, // 70: config.GatewayConfig.underlay:type_name -> config.Underlay
+	60, // 71: config.GatewayConfig.overlay:type_name -> config.Overlay
+	71, // 72: config.GatewayConfig.external:type_name -> config.External
+	15, // 73: config.ConfigService.GetConfig:input_type -> config.GetConfigRequest
+	19, // 74: config.ConfigService.GetConfigGeneration:input_type -> config.GetConfigGenerationRequest
+	16, // 75: config.ConfigService.UpdateConfig:input_type -> config.UpdateConfigRequest
+	21, // 76: config.ConfigService.GetStatus:input_type -> config.GetStatusRequest
+	28, // 77: config.ConfigService.GetCounters:input_type -> config.GetCountersRequest
+	35, // 78: config.ConfigService.SetLogLevel:input_type -> config.SetLogLevelRequest
+	37, // 79: config.ConfigService.AcquireConfigLock:input_type -> config.AcquireConfigLockRequest
+	39, // 80: config.ConfigService.ReleaseConfigLock:input_type -> config.ReleaseConfigLockRequest
+	41, // 81: config.ConfigService.ConfirmConfig:input_type -> config.ConfirmConfigRequest
+	45, // 82: config.ConfigService.GetOperation:input_type -> config.GetOperationRequest
+	46, // 83: config.ConfigService.WaitOperation:input_type -> config.WaitOperationRequest
+	75, // 84: config.ConfigService.GetConfig:output_type -> config.GatewayConfig
+	20, // 85: config.ConfigService.GetConfigGeneration:output_type -> config.GetConfigGenerationResponse
+	17, // 86: config.ConfigService.UpdateConfig:output_type -> config.UpdateConfigResponse
+	27, // 87: config.ConfigService.GetStatus:output_type -> config.GetStatusResponse
+	33, // 88: config.ConfigService.GetCounters:output_type -> config.GetCountersResponse
+	36, // 89: config.ConfigService.SetLogLevel:output_type -> config.SetLogLevelResponse
+	38, // 90: config.ConfigService.AcquireConfigLock:output_type -> config.AcquireConfigLockResponse
+	40, // 91: config.ConfigService.ReleaseConfigLock:output_type -> config.ReleaseConfigLockResponse
+	42, // 92: config.ConfigService.ConfirmConfig:output_type -> config.ConfirmConfigResponse
+	44, // 93: config.ConfigService.GetOperation:output_type -> config.ApplyOperation
+	44, // 94: config.ConfigService.WaitOperation:output_type -> config.ApplyOperation
+	84, // [84:95] is the sub-list for method output_type
+	73, // [73:84] is the sub-list for method input_type
+	73, // [73:73] is the sub-list for extension type_name
+	73, // [73:73] is the sub-list for extension extendee
+	0,  // [0:73] is the sub-list for field type_name
 }
 
 func init() { file_proto_dataplane_proto_init() }
@@ -2383,30 +5338,38 @@ func file_proto_dataplane_proto_init() {
 	if File_proto_dataplane_proto != nil {
 		return
 	}
-	file_proto_dataplane_proto_msgTypes[5].OneofWrappers = []any{}
-	file_proto_dataplane_proto_msgTypes[6].OneofWrappers = []any{}
-	file_proto_dataplane_proto_msgTypes[7].OneofWrappers = []any{}
-	file_proto_dataplane_proto_msgTypes[8].OneofWrappers = []any{
+	file_proto_dataplane_proto_msgTypes[1].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[12].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[20].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[32].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[33].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[34].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[35].OneofWrappers = []any{
 		(*PeeringIPs_Cidr)(nil),
 		(*PeeringIPs_Not)(nil),
 	}
-	file_proto_dataplane_proto_msgTypes[9].OneofWrappers = []any{
+	file_proto_dataplane_proto_msgTypes[36].OneofWrappers = []any{
 		(*PeeringAs_Cidr)(nil),
 		(*PeeringAs_Not)(nil),
 	}
-	file_proto_dataplane_proto_msgTypes[18].OneofWrappers = []any{
+	file_proto_dataplane_proto_msgTypes[38].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[39].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[41].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[49].OneofWrappers = []any{
 		(*BgpNeighborUpdateSource_Address)(nil),
 		(*BgpNeighborUpdateSource_Interface)(nil),
 	}
-	file_proto_dataplane_proto_msgTypes[21].OneofWrappers = []any{}
-	file_proto_dataplane_proto_msgTypes[22].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[52].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[53].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[57].OneofWrappers = []any{}
+	file_proto_dataplane_proto_msgTypes[58].OneofWrappers = []any{}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_proto_dataplane_proto_rawDesc), len(file_proto_dataplane_proto_rawDesc)),
-			NumEnums:      7,
-			NumMessages:   28,
+			NumEnums:      15,
+			NumMessages:   61,
 			NumExtensions: 0,
 			NumServices:   1,
 		},
@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::fmt;
+
+use crate::config::{ApplyState, GatewayConfig, ObjectKind, ObjectResult, UpdateConfigResponse};
+
+impl fmt::Display for ObjectResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind() {
+            ObjectKind::Unknown => "object",
+            ObjectKind::Vrf => "VRF",
+            ObjectKind::Vpc => "VPC",
+            ObjectKind::Peering => "peering",
+            ObjectKind::Device => "device",
+        };
+        let state = match self.state() {
+            ApplyState::Unknown => "unknown",
+            ApplyState::Applied => "applied",
+            ApplyState::Failed => "failed",
+            ApplyState::Skipped => "skipped",
+        };
+        write!(f, "{kind} {} {state}", self.name)?;
+        if !self.reason.is_empty() {
+            write!(f, ": {}", self.reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// How many objects of an update were applied, failed or were skipped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApplySummary {
+    pub applied: usize,
+    pub failed: usize,
+    pub skipped: usize,
+}
+
+impl ApplySummary {
+    /// Whether every reported object was applied.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.failed == 0 && self.skipped == 0
+    }
+}
+
+impl fmt::Display for ApplySummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} applied, {} failed, {} skipped",
+            self.applied, self.failed, self.skipped
+        )
+    }
+}
+
+impl UpdateConfigResponse {
+    /// Result for the object of the given kind and name.
+    #[must_use]
+    pub fn result(&self, kind: ObjectKind, name: &str) -> Option<&ObjectResult> {
+        self.results
+            .iter()
+            .find(|result| result.kind() == kind && result.name == name)
+    }
+
+    /// Results of the objects in the given state.
+    pub fn results_in(&self, state: ApplyState) -> impl Iterator<Item = &ObjectResult> {
+        self.results
+            .iter()
+            .filter(move |result| result.state() == state)
+    }
+
+    /// Count of the results by state, objects in an unknown state are not counted.
+    #[must_use]
+    pub fn apply_summary(&self) -> ApplySummary {
+        self.results
+            .iter()
+            .fold(ApplySummary::default(), |mut summary, result| {
+                match result.state() {
+                    ApplyState::Applied => summary.applied += 1,
+                    ApplyState::Failed => summary.failed += 1,
+                    ApplyState::Skipped => summary.skipped += 1,
+                    ApplyState::Unknown => {}
+                }
+                summary
+            })
+    }
+}
+
+/// One result in `state` for the device and every VRF, VPC and peering of `config`, in
+/// that order, for servers reporting the same outcome for the whole config.
+#[must_use]
+pub fn object_results(
+    config: &GatewayConfig,
+    state: ApplyState,
+    reason: &str,
+) -> Vec<ObjectResult> {
+    let device = config
+        .device
+        .iter()
+        .map(|device| (ObjectKind::Device, &device.hostname));
+    let vrfs = config
+        .underlay
+        .iter()
+        .flat_map(|underlay| &underlay.vrfs)
+        .map(|vrf| (ObjectKind::Vrf, &vrf.name));
+    let vpcs = config
+        .overlay
+        .iter()
+        .flat_map(|overlay| &overlay.vpcs)
+        .map(|vpc| (ObjectKind::Vpc, &vpc.name));
+    let peerings = config
+        .overlay
+        .iter()
+        .flat_map(|overlay| &overlay.peerings)
+        .map(|peering| (ObjectKind::Peering, &peering.name));
+    device
+        .chain(vrfs)
+        .chain(vpcs)
+        .chain(peerings)
+        .map(|(kind, name)| ObjectResult {
+            kind: kind.into(),
+            name: name.clone(),
+            state: state.into(),
+            reason: reason.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builder::GatewayConfigBuilder;
+    use crate::config::Error;
+
+    #[test]
+    fn test_object_results() {
+        let config = GatewayConfigBuilder::new()
+            .hostname("gw-1")
+            .vrf("default")
+            .vpc("vpc-1", 100)
+            .vpc("vpc-2", 200)
+            .build()
+            .unwrap();
+        let results = object_results(&config, ApplyState::Applied, "");
+        let names: Vec<_> = results
+            .iter()
+            .map(|result| (result.kind(), result.name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                (ObjectKind::Device, "gw-1"),
+                (ObjectKind::Vrf, "default"),
+                (ObjectKind::Vpc, "vpc-1"),
+                (ObjectKind::Vpc, "vpc-2"),
+            ]
+        );
+        assert!(object_results(&GatewayConfig::default(), ApplyState::Skipped, "").is_empty());
+    }
+
+    #[test]
+    fn test_apply_summary() {
+        let result = |kind: ObjectKind, name: &str, state: ApplyState, reason: &str| ObjectResult {
+            kind: kind.into(),
+            name: name.to_string(),
+            state: state.into(),
+            reason: reason.to_string(),
+        };
+        let response = UpdateConfigResponse {
+            error: Error::ApplyFailed.into(),
+            message: "vpc-2 failed".to_string(),
+            operation_id: String::new(),
+            results: vec![
+                result(ObjectKind::Vrf, "default", ApplyState::Applied, ""),
+                result(ObjectKind::Vpc, "vpc-1", ApplyState::Applied, ""),
+                result(ObjectKind::Vpc, "vpc-2", ApplyState::Failed, "VNI in use"),
+                result(
+                    ObjectKind::Peering,
+                    "vpc-1--vpc-2",
+                    ApplyState::Skipped,
+                    "vpc-2 failed",
+                ),
+            ],
+        };
+        let summary = response.apply_summary();
+        assert_eq!(
+            summary,
+            ApplySummary {
+                applied: 2,
+                failed: 1,
+                skipped: 1
+            }
+        );
+        assert!(!summary.is_complete());
+        assert_eq!(summary.to_string(), "2 applied, 1 failed, 1 skipped");
+
+        let failed: Vec<_> = response
+            .results_in(ApplyState::Failed)
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(failed, ["VPC vpc-2 failed: VNI in use"]);
+        assert_eq!(
+            response
+                .result(ObjectKind::Peering, "vpc-1--vpc-2")
+                .unwrap()
+                .to_string(),
+            "peering vpc-1--vpc-2 skipped: vpc-2 failed"
+        );
+        assert!(response.result(ObjectKind::Vrf, "vpc-1").is_none());
+        assert!(
+            UpdateConfigResponse::default()
+                .apply_summary()
+                .is_complete()
+        );
+    }
+}
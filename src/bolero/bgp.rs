@@ -72,9 +72,9 @@ impl TypeGenerator for BgpNeighborUpdateSource {
 impl TypeGenerator for BgpNeighbor {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
         let naf = d.gen_usize(Bound::Included(&0), Bound::Included(&2))?;
-        let af_activate_set: std::collections::HashSet<_> = (0..naf)
+        let af_activate_set: std::collections::BTreeSet<_> = (0..naf)
             .map(|_| d.produce::<BgpAf>())
-            .collect::<Option<std::collections::HashSet<_>>>()?;
+            .collect::<Option<std::collections::BTreeSet<_>>>()?;
         Some(BgpNeighbor {
             address: d.produce::<IpAddrString>()?.0,
             remote_asn: d.produce::<u32>()?.to_string(),
@@ -1,18 +1,161 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright 2025 Hedgehog
 
-use crate::bolero::support::K8sObjectNameString;
-use crate::config::{Device, LogLevel, PacketDriver};
+use crate::bolero::support::{K8sObjectNameString, LinuxIfName, choose};
+use crate::config::{Device, Eal, HugePageSize, LogLevel, PacketDriver, Ports};
+use crate::device::{MAX_LCORE, PciAddress};
 use bolero::{Driver, TypeGenerator};
+use std::collections::BTreeSet;
+use std::ops::Bound;
+
+impl TypeGenerator for PciAddress {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        Some(PciAddress {
+            domain: d.produce()?,
+            bus: d.produce()?,
+            device: d.gen_u8(Bound::Included(&0), Bound::Included(&0x1f))?,
+            function: d.gen_u8(Bound::Included(&0), Bound::Included(&7))?,
+        })
+    }
+}
+
+impl TypeGenerator for Eal {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        // Build the CPU list from a few disjoint, ascending ranges
+        let nranges = d.gen_u32(Bound::Included(&1), Bound::Included(&4))?;
+        let mut next = d.gen_u32(Bound::Included(&0), Bound::Included(&16))?;
+        let mut ranges = vec![];
+        let mut lcores = vec![];
+        for _ in 0..nranges {
+            let len = d.gen_u32(Bound::Included(&1), Bound::Included(&8))?;
+            let last = (next + len - 1).min(MAX_LCORE);
+            ranges.push(if next == last {
+                format!("{next}")
+            } else {
+                format!("{next}-{last}")
+            });
+            lcores.extend(next..=last);
+            next = last + d.gen_u32(Bound::Included(&2), Bound::Included(&8))?;
+        }
+        let main_lcore = choose(d, &lcores)?;
+        let memory_channels = d.gen_u32(Bound::Included(&1), Bound::Included(&8))?;
+
+        let npci = d.gen_usize(Bound::Included(&0), Bound::Included(&4))?;
+        let pci_allow = (0..npci)
+            .map(|_| d.produce::<PciAddress>())
+            .collect::<Option<BTreeSet<_>>>()?
+            .into_iter()
+            .map(|addr| addr.to_string())
+            .collect();
+
+        Some(Eal {
+            lcores: ranges.join(","),
+            main_lcore: choose(d, &[Some(main_lcore), None])?,
+            memory_channels: choose(d, &[Some(memory_channels), None])?,
+            hugepage_size: i32::from(d.produce::<HugePageSize>()?),
+            hugepages: d.gen_u32(Bound::Included(&0), Bound::Included(&1024))?,
+            pci_allow,
+            extra_args: vec![],
+        })
+    }
+}
+
+/// Power of two descriptor ring size between 64 and 4096
+fn ring_size<D: Driver>(d: &mut D) -> Option<u32> {
+    Some(1 << d.gen_u32(Bound::Included(&6), Bound::Included(&12))?)
+}
+
+// The interface a port serves is only known with the rest of the config,
+// see the `GatewayConfig` generator
+impl TypeGenerator for Ports {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let queues = d.gen_u32(Bound::Included(&1), Bound::Included(&16))?;
+        let rx_ring = ring_size(d)?;
+        let tx_ring = ring_size(d)?;
+        Some(Ports {
+            name: d.produce::<LinuxIfName>()?.0,
+            system_name: d.produce::<LinuxIfName>()?.0,
+            pci_address: Some(d.produce::<PciAddress>()?.to_string()),
+            driver: choose(
+                d,
+                &[
+                    Some("vfio-pci".to_string()),
+                    Some("mlx5_core".to_string()),
+                    None,
+                ],
+            )?,
+            rx_queues: choose(d, &[Some(queues), None])?,
+            tx_queues: choose(d, &[Some(queues), None])?,
+            rx_descriptors: choose(d, &[Some(rx_ring), None])?,
+            tx_descriptors: choose(d, &[Some(tx_ring), None])?,
+            interface: None,
+        })
+    }
+}
 
 impl TypeGenerator for Device {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let driver = d.produce::<PacketDriver>()?;
+        let eal = match driver {
+            PacketDriver::Kernel => None,
+            PacketDriver::Dpdk => Some(d.produce::<Eal>()?),
+        };
         Some(Device {
-            driver: i32::from(d.produce::<PacketDriver>()?),
-            eal: None,     // TODO Add support for EAL when dataplane supports it
-            ports: vec![], // TODO Add support for ports when dataplane supports it
+            driver: i32::from(driver),
+            eal,
+            ports: vec![], // Generated with the interfaces they serve, see `GatewayConfig`
             hostname: d.produce::<K8sObjectNameString>()?.0,
             loglevel: i32::from(d.produce::<LogLevel>()?),
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::config::{Device, Eal, PacketDriver, Ports};
+    use crate::device::{PciAddress, eal_argv, validate_eal};
+
+    #[test]
+    fn test_eal() {
+        bolero::check!().with_type::<Eal>().for_each(|eal: &Eal| {
+            assert!(validate_eal(eal).is_ok(), "{eal:?}");
+            let argv = eal_argv("dataplane", eal).unwrap();
+            assert_eq!(argv[0], "dataplane");
+            assert_eq!(argv[1], "-l");
+        });
+    }
+
+    #[test]
+    fn test_ports() {
+        bolero::check!()
+            .with_type::<Ports>()
+            .for_each(|port: &Ports| {
+                assert!(!port.name.is_empty());
+                let pci = port.pci_address.as_ref().unwrap();
+                assert!(pci.parse::<PciAddress>().is_ok());
+                for ring in [port.rx_descriptors, port.tx_descriptors]
+                    .into_iter()
+                    .flatten()
+                {
+                    assert!(ring.is_power_of_two());
+                }
+                assert_ne!(port.rx_queues, Some(0));
+                assert_ne!(port.tx_queues, Some(0));
+            });
+    }
+
+    #[test]
+    fn test_device() {
+        let mut some_eal = false;
+        bolero::check!()
+            .with_type::<Device>()
+            .for_each(|device: &Device| {
+                assert_eq!(
+                    device.eal.is_some(),
+                    device.driver == i32::from(PacketDriver::Dpdk)
+                );
+                some_eal = some_eal || device.eal.is_some();
+            });
+        assert!(some_eal);
+    }
+}
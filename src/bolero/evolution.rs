@@ -0,0 +1,352 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use bolero::{Driver, TypeGenerator, ValueGenerator};
+use std::net::IpAddr;
+use std::ops::{Bound, RangeInclusive};
+
+use crate::bolero::expose::ExposeGenerator;
+use crate::bolero::gateway_config::sync_ports;
+use crate::bolero::support::choose;
+use crate::bolero::vpc::{VpcGenerator, VpcPeeringGenerator, vpc_subnets};
+use crate::config::bgp_neighbor_update_source::Source;
+use crate::config::{GatewayConfig, IfRole, IfType, Interface, Overlay, Underlay, Vrf};
+use crate::prefix::Prefix;
+
+/// A realistic change between two consecutive configs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edit {
+    /// A new VPC, sometimes peered with an existing one
+    AddVpc,
+    /// A VPC goes away, together with its peerings and external attachments
+    RemoveVpc,
+    /// The exposes of one side of a peering are replaced
+    ChangeExpose,
+    /// An interface moves from one underlay VRF to another existing one, unless the routing
+    /// of its VRF depends on it
+    MoveInterface,
+}
+
+/// Number of [`Edit`] kinds.
+const EDITS: usize = 4;
+
+impl Edit {
+    /// Edits that have something to work on in `config`.
+    fn applicable(config: &GatewayConfig) -> Vec<Edit> {
+        let default = Overlay::default();
+        let overlay = config.overlay.as_ref().unwrap_or(&default);
+        let mut edits = vec![Edit::AddVpc];
+        if !overlay.vpcs.is_empty() {
+            edits.push(Edit::RemoveVpc);
+        }
+        if exposing_entries(overlay).next().is_some() {
+            edits.push(Edit::ChangeExpose);
+        }
+        if config
+            .underlay
+            .as_ref()
+            .is_some_and(|underlay| !movable_sources(underlay).is_empty())
+        {
+            edits.push(Edit::MoveInterface);
+        }
+        edits
+    }
+
+    /// Apply the edit to a valid config, keeping it valid. The edit must be applicable.
+    fn apply<D: Driver>(self, d: &mut D, config: &mut GatewayConfig) -> Option<()> {
+        let overlay = config.overlay.get_or_insert_default();
+        match self {
+            Edit::AddVpc => {
+                let vni = overlay.vpcs.iter().map(|vpc| vpc.vni).max().unwrap_or(0) + 1;
+                let mut vpc = VpcGenerator { interfaces: 1..=10 }.generate(d)?;
+                vpc.vni = vni;
+                vpc.id = format!("{vni:05}");
+                vpc.name = format!("{vni:05}");
+                let peers = overlay
+                    .vpcs
+                    .iter()
+                    .filter(|peer| !vpc_subnets(peer).is_empty())
+                    .collect::<Vec<_>>();
+                if !vpc_subnets(&vpc).is_empty() && !peers.is_empty() && d.gen_bool(None)? {
+                    let peer = choose(d, &peers)?;
+                    let peering = VpcPeeringGenerator { vpcs: [peer, &vpc] }.generate(d)?;
+                    overlay.peerings.push(peering);
+                }
+                overlay.vpcs.push(vpc);
+            }
+            Edit::RemoveVpc => {
+                let index = pick(d, overlay.vpcs.len())?;
+                let vpc = overlay.vpcs.remove(index);
+                overlay
+                    .peerings
+                    .retain(|peering| peering.r#for.iter().all(|entry| entry.vpc != vpc.name));
+                for attachment in config
+                    .external
+                    .iter_mut()
+                    .flat_map(|external| &mut external.attachments)
+                {
+                    attachment.vpcs.retain(|name| *name != vpc.name);
+                }
+            }
+            Edit::ChangeExpose => {
+                let entries = exposing_entries(overlay).collect::<Vec<_>>();
+                let (peering, entry, prefixes) = choose(d, &entries)?;
+                let exposes = ExposeGenerator { prefixes };
+                let nexposes = d.gen_usize(Bound::Included(&1), Bound::Included(&3))?;
+                overlay.peerings[peering].r#for[entry].expose = (0..nexposes)
+                    .map(|_| exposes.generate(d))
+                    .collect::<Option<Vec<_>>>()?;
+            }
+            Edit::MoveInterface => {
+                let underlay = config.underlay.as_mut()?;
+                move_interface(d, underlay)?;
+            }
+        }
+        sync_ports(d, config)
+    }
+}
+
+/// Interfaces that stay in their VRF: external ones, whose VRF is designated external by an
+/// attachment, the VTEP, and those the VRF's BGP neighbors are reached through or sourced
+/// from.
+fn is_pinned(vrf: &Vrf, iface: &Interface) -> bool {
+    if iface.role == i32::from(IfRole::External) || iface.r#type == i32::from(IfType::Vtep) {
+        return true;
+    }
+    let prefixes = iface
+        .ipaddrs
+        .iter()
+        .filter_map(|addr| addr.parse::<Prefix>().ok())
+        .collect::<Vec<_>>();
+    let has_addr = |addr: &str| {
+        addr.parse::<IpAddr>()
+            .is_ok_and(|addr| prefixes.iter().any(|prefix| prefix.addr() == addr))
+    };
+    vrf.router
+        .iter()
+        .flat_map(|router| &router.neighbors)
+        .any(|neighbor| {
+            let reached = neighbor
+                .address
+                .parse::<IpAddr>()
+                .is_ok_and(|addr| prefixes.iter().any(|prefix| prefix.contains(addr)));
+            let sourced = match neighbor.update_source.as_ref().and_then(|s| s.source.as_ref()) {
+                Some(Source::Interface(name)) => *name == iface.name,
+                Some(Source::Address(addr)) => has_addr(addr),
+                None => false,
+            };
+            reached || sourced
+        })
+}
+
+/// Indices of the VRFs with an interface that can move to another VRF, none if there is a
+/// single VRF.
+fn movable_sources(underlay: &Underlay) -> Vec<usize> {
+    if underlay.vrfs.len() < 2 {
+        return vec![];
+    }
+    (0..underlay.vrfs.len())
+        .filter(|&i| {
+            let vrf = &underlay.vrfs[i];
+            vrf.interfaces.iter().any(|iface| !is_pinned(vrf, iface))
+        })
+        .collect()
+}
+
+/// Move an interface that is not pinned to another existing VRF.
+fn move_interface<D: Driver>(d: &mut D, underlay: &mut Underlay) -> Option<()> {
+    let from = choose(d, &movable_sources(underlay))?;
+    let to = (from + 1 + pick(d, underlay.vrfs.len() - 1)?) % underlay.vrfs.len();
+    let vrf = &underlay.vrfs[from];
+    let movable = (0..vrf.interfaces.len())
+        .filter(|&i| !is_pinned(vrf, &vrf.interfaces[i]))
+        .collect::<Vec<_>>();
+    let index = choose(d, &movable)?;
+    let iface = underlay.vrfs[from].interfaces.remove(index);
+    underlay.vrfs[to].interfaces.push(iface);
+    Some(())
+}
+
+/// Index below `len`, which must not be zero.
+fn pick<D: Driver>(d: &mut D, len: usize) -> Option<usize> {
+    d.gen_usize(Bound::Included(&0), Bound::Excluded(&len))
+}
+
+/// Peering and entry indices of the peering sides whose VPC has subnets to expose, with
+/// those subnets.
+fn exposing_entries(overlay: &Overlay) -> impl Iterator<Item = (usize, usize, Vec<Prefix>)> + '_ {
+    overlay
+        .peerings
+        .iter()
+        .enumerate()
+        .flat_map(|(i, peering)| {
+            peering
+                .r#for
+                .iter()
+                .enumerate()
+                .map(move |(j, entry)| (i, j, &entry.vpc))
+        })
+        .filter_map(|(i, j, name)| {
+            let vpc = overlay.vpcs.iter().find(|vpc| vpc.name == *name)?;
+            let subnets = vpc_subnets(vpc);
+            (!subnets.is_empty()).then_some((i, j, subnets))
+        })
+}
+
+/// One config of an evolution and the edit that led to it.
+#[derive(Debug, Clone)]
+pub struct EvolutionStep {
+    pub edit: Edit,
+    pub config: GatewayConfig,
+}
+
+/// A valid initial config followed by valid configs, each one edit away from the previous
+/// one and with the next generation.
+#[derive(Debug, Clone)]
+pub struct ConfigEvolution {
+    pub initial: GatewayConfig,
+    pub steps: Vec<EvolutionStep>,
+}
+
+impl ConfigEvolution {
+    /// All configs in the order they are meant to be applied.
+    pub fn configs(&self) -> impl Iterator<Item = &GatewayConfig> {
+        std::iter::once(&self.initial).chain(self.steps.iter().map(|step| &step.config))
+    }
+}
+
+/// [`ConfigEvolution`] with a number of steps in the given range.
+pub struct ConfigEvolutionGenerator {
+    pub steps: RangeInclusive<usize>,
+}
+
+impl ValueGenerator for ConfigEvolutionGenerator {
+    type Output = ConfigEvolution;
+
+    fn generate<D: Driver>(&self, d: &mut D) -> Option<Self::Output> {
+        let nsteps = d.gen_usize(
+            Bound::Included(self.steps.start()),
+            Bound::Included(self.steps.end()),
+        )?;
+        // Picked before the initial config uses up most of the driver's input, the edits
+        // would otherwise mostly be the first applicable one
+        let picks = (0..nsteps)
+            .map(|_| d.gen_usize(Bound::Included(&0), Bound::Excluded(&EDITS)))
+            .collect::<Option<Vec<_>>>()?;
+        let initial = d.produce::<GatewayConfig>()?;
+        let mut config = initial.clone();
+        let mut steps = Vec::with_capacity(nsteps);
+        for pick in picks {
+            let edits = Edit::applicable(&config);
+            let edit = edits[pick % edits.len()];
+            edit.apply(d, &mut config)?;
+            config.generation += 1;
+            steps.push(EvolutionStep {
+                edit,
+                config: config.clone(),
+            });
+        }
+        Some(ConfigEvolution { initial, steps })
+    }
+}
+
+impl TypeGenerator for ConfigEvolution {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        ConfigEvolutionGenerator { steps: 1..=10 }.generate(d)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bolero::test_support::get_peering_ip;
+    use crate::config::config_service_server::ConfigService;
+    use crate::config::{Error, GetConfigRequest, UpdateConfigRequest};
+    use crate::server::FakeConfigService;
+    use crate::validation::validate_config;
+    use std::collections::HashSet;
+    use tonic::Request;
+
+    /// Every peering side only exposes subnets of its own VPC.
+    fn assert_own_subnets(config: &GatewayConfig) {
+        let Some(overlay) = &config.overlay else {
+            return;
+        };
+        for entry in overlay.peerings.iter().flat_map(|peering| &peering.r#for) {
+            let vpc = overlay.vpcs.iter().find(|vpc| vpc.name == entry.vpc);
+            let subnets = vpc_subnets(vpc.unwrap());
+            for ips in entry.expose.iter().flat_map(|expose| &expose.ips) {
+                let cidr = get_peering_ip(ips).unwrap().parse::<Prefix>().unwrap();
+                assert!(subnets.contains(&cidr), "{} exposes {cidr}", entry.vpc);
+            }
+        }
+    }
+
+    /// Interfaces pinned to their VRF, as VRF and interface names.
+    fn pinned(config: &GatewayConfig) -> Vec<(&str, &str)> {
+        config
+            .underlay
+            .iter()
+            .flat_map(|underlay| &underlay.vrfs)
+            .flat_map(|vrf| {
+                vrf.interfaces
+                    .iter()
+                    .filter(|iface| is_pinned(vrf, iface))
+                    .map(|iface| (vrf.name.as_str(), iface.name.as_str()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_config_evolution() {
+        let mut edits = HashSet::new();
+        bolero::check!()
+            .with_type::<ConfigEvolution>()
+            .for_each(|evolution: &ConfigEvolution| {
+                let mut generation = evolution.initial.generation - 1;
+                let mut previous: Option<&GatewayConfig> = None;
+                for config in evolution.configs() {
+                    assert_eq!(validate_config(config), Ok(()));
+                    assert_own_subnets(config);
+                    assert_eq!(config.generation, generation + 1);
+                    if let Some(previous) = previous {
+                        let kept = pinned(config);
+                        assert!(pinned(previous).iter().all(|iface| kept.contains(iface)));
+                    }
+                    generation = config.generation;
+                    previous = Some(config);
+                }
+                edits.extend(evolution.steps.iter().map(|step| step.edit));
+            });
+        assert_eq!(edits.len(), EDITS);
+    }
+
+    #[test]
+    fn test_fake_server_follows_evolution() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        bolero::check!()
+            .with_type::<ConfigEvolution>()
+            .with_iterations(100)
+            .for_each(|evolution: &ConfigEvolution| {
+                let service = FakeConfigService::new();
+                for config in evolution.configs() {
+                    let response = runtime
+                        .block_on(service.update_config(Request::new(UpdateConfigRequest {
+                            config: Some(config.clone()),
+                            expected_generation: None,
+                            owner: String::new(),
+                            confirm_timeout_seconds: 0,
+                            async_apply: false,
+                        })))
+                        .unwrap()
+                        .into_inner();
+                    assert_eq!(response.error(), Error::None);
+                    let running = runtime
+                        .block_on(service.get_config(Request::new(GetConfigRequest {})))
+                        .unwrap()
+                        .into_inner();
+                    assert_eq!(&running, config);
+                }
+            });
+    }
+}
@@ -4,8 +4,12 @@
 use bolero::{Driver, TypeGenerator, ValueGenerator};
 use std::ops::Bound;
 
-use crate::bolero::support::{UniqueV4CidrGenerator, UniqueV6CidrGenerator};
-use crate::config::{Expose, PeeringAs, PeeringIPs, peering_as, peering_i_ps};
+use crate::bolero::support::{UniqueV4CidrGenerator, UniqueV6CidrGenerator, choose};
+use crate::config::{
+    Expose, L4Filter, L4Protocol, NatPool, PeeringAs, PeeringIPs, PortRange, StatefulNat,
+    peering_as, peering_i_ps,
+};
+use crate::prefix::Prefix;
 
 struct UniquePeeringAs<T: ValueGenerator<Output = Vec<String>>> {
     cidr_producer: T,
@@ -75,6 +79,116 @@ where
     }
 }
 
+impl TypeGenerator for PortRange {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let start = d.gen_u32(Bound::Included(&1), Bound::Included(&65535))?;
+        let end = d.gen_u32(Bound::Included(&start), Bound::Included(&65535))?;
+        Some(PortRange { start, end })
+    }
+}
+
+/// L4 filter using the ICMP version of the given address family
+struct L4FilterGenerator {
+    v4: bool,
+}
+
+impl ValueGenerator for L4FilterGenerator {
+    type Output = L4Filter;
+
+    fn generate<D: Driver>(&self, d: &mut D) -> Option<Self::Output> {
+        let icmp = if self.v4 {
+            L4Protocol::Icmp
+        } else {
+            L4Protocol::Icmpv6
+        };
+        let protocol = choose(
+            d,
+            &[L4Protocol::Any, L4Protocol::Tcp, L4Protocol::Udp, icmp],
+        )?;
+        let nports = d.gen_usize(Bound::Included(&0), Bound::Included(&3))?;
+        let ntypes = d.gen_usize(Bound::Included(&0), Bound::Included(&3))?;
+        let (ports, icmp_types) = match protocol {
+            L4Protocol::Tcp | L4Protocol::Udp => (
+                (0..nports)
+                    .map(|_| d.produce::<PortRange>())
+                    .collect::<Option<Vec<_>>>()?,
+                vec![],
+            ),
+            L4Protocol::Icmp | L4Protocol::Icmpv6 => (
+                vec![],
+                (0..ntypes)
+                    .map(|_| d.gen_u32(Bound::Included(&0), Bound::Included(&255)))
+                    .collect::<Option<Vec<_>>>()?,
+            ),
+            L4Protocol::Any => (vec![], vec![]),
+        };
+        Some(L4Filter {
+            protocol: protocol.into(),
+            ports,
+            icmp_types,
+        })
+    }
+}
+
+/// Stateful NAT hiding an expose behind a single address of the given family
+struct StatefulNatGenerator {
+    v4: bool,
+}
+
+impl ValueGenerator for StatefulNatGenerator {
+    type Output = StatefulNat;
+
+    fn generate<D: Driver>(&self, d: &mut D) -> Option<Self::Output> {
+        let cidrs = if self.v4 {
+            UniqueV4CidrGenerator::new(1, 32).generate(d)?
+        } else {
+            UniqueV6CidrGenerator::new(1, 128).generate(d)?
+        };
+        let ports = d.produce::<PortRange>()?;
+        let mut timeout = || {
+            let seconds = d.gen_u32(Bound::Included(&1), Bound::Included(&86400))?;
+            choose(d, &[Some(seconds), None])
+        };
+        Some(StatefulNat {
+            tcp_idle_timeout: timeout()?,
+            udp_idle_timeout: timeout()?,
+            icmp_idle_timeout: timeout()?,
+            pool: Some(NatPool {
+                cidrs,
+                ports: choose(d, &[Some(ports), None])?,
+            }),
+        })
+    }
+}
+
+/// Stateful NAT and filters for an expose of the given address family, which already has
+/// its `ips` and `as` rules
+fn finish_expose<D: Driver>(
+    d: &mut D,
+    v4: bool,
+    ips: Vec<PeeringIPs>,
+    r#as: Vec<PeeringAs>,
+) -> Option<Expose> {
+    // Static and stateful NAT are mutually exclusive
+    let stateful_nat = if r#as.is_empty() && d.gen_bool(None)? {
+        Some(StatefulNatGenerator { v4 }.generate(d)?)
+    } else {
+        None
+    };
+
+    let nfilters = d.gen_usize(Bound::Included(&0), Bound::Included(&3))?;
+    let filters = (0..nfilters)
+        .map(|_| L4FilterGenerator { v4 }.generate(d))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Expose {
+        ips,
+        r#as,
+        stateful_nat,
+        filters,
+    })
+}
+
 // FIXME(manishv): We should make sure that the number of peering ips and ases are
 // consistent.
 // FIXME(manishv): We should also make sure that the cidrs use not
@@ -110,10 +224,63 @@ impl TypeGenerator for Expose {
             vec![]
         };
 
-        Some(Expose {
-            ips: peering_ips,
-            r#as,
-        })
+        finish_expose(d, v4, peering_ips, r#as)
+    }
+}
+
+/// Expose of a non-empty subset of `prefixes`, e.g. the subnets of a VPC.
+///
+/// The exposed prefixes are all of one address family, static NAT translates each of them
+/// into a random prefix of the same length.
+pub(crate) struct ExposeGenerator {
+    pub prefixes: Vec<Prefix>,
+}
+
+impl ValueGenerator for ExposeGenerator {
+    type Output = Expose;
+
+    fn generate<D: Driver>(&self, d: &mut D) -> Option<Self::Output> {
+        let v4 = choose(d, &self.prefixes)?.is_ipv4();
+        let family = self
+            .prefixes
+            .iter()
+            .filter(|prefix| prefix.is_ipv4() == v4)
+            .collect::<Vec<_>>();
+        let mut exposed = vec![];
+        for &prefix in &family {
+            if d.gen_bool(None)? {
+                exposed.push(*prefix);
+            }
+        }
+        if exposed.is_empty() {
+            exposed.push(*choose(d, &family)?);
+        }
+
+        let r#as = if d.gen_bool(None)? {
+            exposed
+                .iter()
+                .map(|prefix| {
+                    let cidr = if v4 {
+                        UniqueV4CidrGenerator::new(1, prefix.mask_len()).generate(d)?
+                    } else {
+                        UniqueV6CidrGenerator::new(1, prefix.mask_len()).generate(d)?
+                    };
+                    Some(PeeringAs {
+                        rule: Some(peering_as::Rule::Cidr(cidr.into_iter().next()?)),
+                    })
+                })
+                .collect::<Option<Vec<_>>>()?
+        } else {
+            vec![]
+        };
+        let ips = exposed
+            .iter()
+            .map(|prefix| PeeringIPs {
+                rule: Some(peering_i_ps::Rule::Cidr(prefix.to_string())),
+            })
+            .collect();
+
+        finish_expose(d, v4, ips, r#as)
     }
 }
 
@@ -122,6 +289,7 @@ mod test {
     use super::*;
     use crate::bolero::test_support::parse_cidr;
     use crate::bolero::test_support::{get_peering_as_ip, get_peering_ip};
+    use crate::overlay::{check_filters, check_stateful_nat};
     use std::net::IpAddr;
 
     enum IpAddrType {
@@ -148,10 +316,25 @@ mod test {
     #[test]
     fn test_expose() {
         let mut more_than_one = false;
+        let mut some_stateful = false;
+        let mut some_filters = false;
         bolero::check!()
             .with_type::<Expose>()
             .for_each(|expose: &Expose| {
                 assert!(!expose.ips.is_empty());
+                if !expose.filters.is_empty() {
+                    some_filters = true;
+                    let mut errors = vec![];
+                    check_filters("peering", expose, &mut errors);
+                    assert_eq!(errors, vec![]);
+                }
+                if expose.stateful_nat.is_some() {
+                    some_stateful = true;
+                    assert!(expose.r#as.is_empty());
+                    let mut errors = vec![];
+                    check_stateful_nat("peering", expose, &mut errors);
+                    assert_eq!(errors, vec![]);
+                }
                 if expose.ips.len() > 1 {
                     more_than_one = true;
                 }
@@ -173,5 +356,7 @@ mod test {
                 ));
             });
         assert!(more_than_one);
+        assert!(some_stateful);
+        assert!(some_filters);
     }
 }
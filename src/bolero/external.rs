@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use crate::bolero::support::{K8sObjectNameString, LinuxIfName};
+use crate::config::{BgpNeighbor, ExternalAttachment};
+use bolero::{Driver, TypeGenerator};
+use std::ops::Bound;
+
+// The VRF and VPCs referenced here are random, the `GatewayConfig` generator
+// replaces them with ones that exist in the generated config
+impl TypeGenerator for ExternalAttachment {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let nupstreams = d.gen_usize(Bound::Included(&1), Bound::Included(&4))?;
+        let nvpcs = d.gen_usize(Bound::Included(&0), Bound::Included(&4))?;
+        Some(ExternalAttachment {
+            name: d.produce::<K8sObjectNameString>()?.0,
+            vrf: d.produce::<LinuxIfName>()?.0,
+            upstreams: (0..nupstreams)
+                .map(|_| d.produce::<BgpNeighbor>())
+                .collect::<Option<Vec<_>>>()?,
+            vpcs: (0..nvpcs)
+                .map(|_| Some(d.produce::<LinuxIfName>()?.0))
+                .collect::<Option<Vec<_>>>()?,
+            inject_default_route: d.produce()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config::ExternalAttachment;
+    use std::net::IpAddr;
+
+    #[test]
+    fn test_external_attachment() {
+        bolero::check!().with_type::<ExternalAttachment>().for_each(
+            |attachment: &ExternalAttachment| {
+                assert!(!attachment.name.is_empty());
+                assert!(!attachment.upstreams.is_empty());
+                assert!(
+                    attachment
+                        .upstreams
+                        .iter()
+                        .all(|upstream| upstream.address.parse::<IpAddr>().is_ok())
+                );
+            },
+        );
+    }
+}
@@ -1,44 +1,95 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright 2025 Hedgehog
 
-use crate::config::{Device, GatewayConfig, Overlay, Underlay, Vpc, VpcPeering, Vrf};
-use bolero::{Driver, TypeGenerator};
-use std::ops::Bound;
+use crate::bolero::vpc::{VpcGenerator, VpcPeeringGenerator, vpc_subnets};
+use crate::config::{
+    Device, External, ExternalAttachment, GatewayConfig, IfRole, IfType, Overlay, PacketDriver,
+    Ports, Underlay, Vrf,
+};
+use crate::device::PciAddress;
+use crate::validation::all_interfaces;
+use bolero::{Driver, TypeGenerator, ValueGenerator};
+use std::collections::HashSet;
+use std::ops::{Bound, RangeInclusive};
+
+/// Overlay with VPC, peering and per VPC interface counts in the given ranges.
+///
+/// VPCs get consecutive VNIs starting at 1. Peerings are between distinct pairs of VPCs with
+/// subnets to expose, fewer than asked for are generated if there are not enough such pairs.
+pub(crate) struct OverlayGenerator {
+    pub vpcs: RangeInclusive<usize>,
+    pub peerings: RangeInclusive<usize>,
+    pub interfaces: RangeInclusive<usize>,
+}
+
+impl ValueGenerator for OverlayGenerator {
+    type Output = Overlay;
+
+    fn generate<D: Driver>(&self, d: &mut D) -> Option<Self::Output> {
+        let nvpcs = d.gen_usize(
+            Bound::Included(self.vpcs.start()),
+            Bound::Included(self.vpcs.end()),
+        )?;
+        let npeerings = d.gen_usize(
+            Bound::Included(self.peerings.start()),
+            Bound::Included(self.peerings.end()),
+        )?;
+        let vpc_generator = VpcGenerator {
+            interfaces: self.interfaces.clone(),
+        };
+        let vpcs = (1..=nvpcs)
+            .map(|vni| {
+                let mut vpc = vpc_generator.generate(d)?;
+                vpc.vni = u32::try_from(vni).ok()?;
+                vpc.id = format!("{vni:05}");
+                vpc.name = format!("{vni:05}");
+                Some(vpc)
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        // At most one peering per pair of VPCs, and only between VPCs with subnets to expose
+        let peerable = vpcs
+            .iter()
+            .filter(|vpc| !vpc_subnets(vpc).is_empty())
+            .collect::<Vec<_>>();
+        let npairs = peerable.len() * peerable.len().saturating_sub(1) / 2;
+        let mut pairs = HashSet::new();
+        let mut peerings = vec![];
+        // Random pairs rather than all of them, there are too many with a lot of VPCs. Bound
+        // the attempts, a driver running out of input keeps picking the same pair.
+        let wanted = npeerings.min(npairs);
+        for _ in 0..wanted * 4 {
+            if peerings.len() == wanted {
+                break;
+            }
+            let last = peerable.len() - 1;
+            let a = d.gen_usize(Bound::Included(&0), Bound::Included(&last))?;
+            let b = d.gen_usize(Bound::Included(&0), Bound::Included(&last))?;
+            if a != b && pairs.insert((a.min(b), a.max(b))) {
+                let vpcs = [peerable[a.min(b)], peerable[a.max(b)]];
+                peerings.push(VpcPeeringGenerator { vpcs }.generate(d)?);
+            }
+        }
+        Some(Overlay { vpcs, peerings })
+    }
+}
 
-// FIXME: Only generate peerings between vpcs named in vpc list
 impl TypeGenerator for Overlay {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
-        let nvpcs = d.gen_usize(Bound::Included(&0), Bound::Included(&10))?;
-        let npeerings = d.gen_usize(Bound::Included(&0), Bound::Included(&10))?;
-        let mut next_vni = 1;
-        let mut peering_num = 0;
-        Some(Overlay {
-            vpcs: (0..nvpcs)
-                .map(|_| {
-                    let mut vpc = d.produce::<Vpc>()?;
-                    vpc.vni = next_vni;
-                    vpc.id = format!("{next_vni:05}");
-                    vpc.name = format!("{next_vni:05}");
-                    next_vni += 1;
-                    Some(vpc)
-                })
-                .collect::<Option<Vec<_>>>()?,
-            peerings: (0..npeerings)
-                .map(|_| {
-                    let mut peering = d.produce::<VpcPeering>()?;
-                    peering.name = format!("peering{peering_num}");
-                    peering_num += 1;
-                    Some(peering)
-                })
-                .collect::<Option<Vec<_>>>()?,
-        })
+        OverlayGenerator {
+            vpcs: 0..=10,
+            peerings: 0..=10,
+            interfaces: 1..=10,
+        }
+        .generate(d)
     }
 }
 
 impl TypeGenerator for Underlay {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
-        // Dataplane currently only supports a single vrf
-        const MAX_UNDERLAY_VRFS: usize = 1;
+        // The dataplane currently runs a single VRF, more are valid configs and let
+        // interfaces move between VRFs in config evolutions
+        const MAX_UNDERLAY_VRFS: usize = 3;
         let nvrfs = d.gen_usize(Bound::Included(&1), Bound::Included(&MAX_UNDERLAY_VRFS))?;
         Some(Underlay {
             vrfs: (0..nvrfs)
@@ -48,27 +99,239 @@ impl TypeGenerator for Underlay {
     }
 }
 
+/// Turn some ethernet and VLAN interfaces of the first underlay VRF into external ones
+/// and attach a random subset of the VPCs to that VRF.
+fn generate_external<D: Driver>(d: &mut D, config: &mut GatewayConfig) -> Option<()> {
+    let vpc_names = config
+        .overlay
+        .iter()
+        .flat_map(|overlay| &overlay.vpcs)
+        .map(|vpc| vpc.name.clone())
+        .collect::<Vec<_>>();
+    let Some(vrf) = config.underlay.as_mut()?.vrfs.first_mut() else {
+        return Some(());
+    };
+    let mut some_external = false;
+    for iface in &mut vrf.interfaces {
+        let eligible =
+            iface.r#type == i32::from(IfType::Ethernet) || iface.r#type == i32::from(IfType::Vlan);
+        // Make sure at least one interface is external so the VRF can reach upstream
+        if eligible && (!some_external || d.gen_bool(None)?) {
+            iface.role = IfRole::External.into();
+            some_external = true;
+        }
+    }
+    if !some_external {
+        return Some(());
+    }
+
+    let mut attachment = d.produce::<ExternalAttachment>()?;
+    attachment.vrf.clone_from(&vrf.name);
+    attachment.vpcs = vpc_names
+        .into_iter()
+        .map(|name| Some(d.gen_bool(None)?.then_some(name)))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect();
+    config.external = Some(External {
+        attachments: vec![attachment],
+    });
+    Some(())
+}
+
+/// With the DPDK driver every ethernet interface needs a port, add one per interface
+/// and restrict the EAL PCI allow list (if any) to exactly those ports.
+fn generate_ports<D: Driver>(d: &mut D, config: &mut GatewayConfig) -> Option<()> {
+    let mut names = HashSet::new();
+    let ifnames = all_interfaces(config)
+        .filter(|iface| iface.r#type == i32::from(IfType::Ethernet))
+        .filter(|iface| names.insert(&iface.name))
+        .map(|iface| iface.name.clone())
+        .collect::<Vec<_>>();
+    let domain = d.produce::<u16>()?;
+    let ports = ifnames
+        .into_iter()
+        .enumerate()
+        .map(|(i, ifname)| {
+            let mut port = d.produce::<Ports>()?;
+            port.name = format!("port{i}");
+            // Unique address per port, 32 devices per bus and 256 buses per domain
+            #[allow(clippy::cast_possible_truncation)]
+            let pci = PciAddress {
+                domain: domain.wrapping_add((i / (32 * 256)) as u16),
+                bus: (i / 32 % 256) as u8,
+                device: (i % 32) as u8,
+                function: 0,
+            };
+            port.pci_address = Some(pci.to_string());
+            port.interface = Some(ifname);
+            Some(port)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let device = config.device.as_mut()?;
+    if let Some(eal) = device.eal.as_mut() {
+        if !eal.pci_allow.is_empty() {
+            eal.pci_allow = ports.iter().filter_map(|p| p.pci_address.clone()).collect();
+        }
+    }
+    device.ports = ports;
+    Some(())
+}
+
+/// Keep the ports of a DPDK config in line with its interfaces after an edit: drop the ports
+/// of interfaces that are gone and add one for each ethernet interface without a port, with
+/// the EAL PCI allow list (if any) following along.
+pub(crate) fn sync_ports<D: Driver>(d: &mut D, config: &mut GatewayConfig) -> Option<()> {
+    if config.device.as_ref()?.driver != i32::from(PacketDriver::Dpdk) {
+        return Some(());
+    }
+    let interfaces = all_interfaces(config)
+        .map(|iface| iface.name.clone())
+        .collect::<HashSet<_>>();
+    let mut unbound = Vec::new();
+    let mut seen = HashSet::new();
+    for iface in all_interfaces(config) {
+        if iface.r#type == i32::from(IfType::Ethernet) && seen.insert(&iface.name) {
+            unbound.push(iface.name.clone());
+        }
+    }
+
+    let device = config.device.as_mut()?;
+    let (kept, removed) = std::mem::take(&mut device.ports)
+        .into_iter()
+        .partition::<Vec<_>, _>(|port| {
+            port.interface
+                .as_ref()
+                .is_none_or(|iface| interfaces.contains(iface))
+        });
+    device.ports = kept;
+    unbound.retain(|iface| {
+        !device
+            .ports
+            .iter()
+            .any(|port| port.interface.as_ref() == Some(iface))
+    });
+
+    let mut names = device
+        .ports
+        .iter()
+        .map(|port| port.name.clone())
+        .collect::<HashSet<_>>();
+    let mut addrs = device
+        .ports
+        .iter()
+        .filter_map(|port| port.pci_address.as_ref()?.parse::<PciAddress>().ok())
+        .collect::<HashSet<_>>();
+    let domain = match addrs.iter().map(|addr| addr.domain).min() {
+        Some(domain) => domain,
+        None => d.produce::<u16>()?,
+    };
+    let mut added = vec![];
+    let (mut n, mut i) = (0_usize, 0_usize);
+    for ifname in unbound {
+        while !names.insert(format!("port{n}")) {
+            n += 1;
+        }
+        // Same layout as `generate_ports`, skipping addresses already in use
+        #[allow(clippy::cast_possible_truncation)]
+        let pci = loop {
+            let pci = PciAddress {
+                domain: domain.wrapping_add((i / (32 * 256)) as u16),
+                bus: (i / 32 % 256) as u8,
+                device: (i % 32) as u8,
+                function: 0,
+            };
+            i += 1;
+            if addrs.insert(pci) {
+                break pci;
+            }
+        };
+        let mut port = d.produce::<Ports>()?;
+        port.name = format!("port{n}");
+        port.pci_address = Some(pci.to_string());
+        port.interface = Some(ifname);
+        added.push(port);
+    }
+
+    if let Some(eal) = device.eal.as_mut() {
+        if !eal.pci_allow.is_empty() {
+            eal.pci_allow.retain(|pci| {
+                !removed
+                    .iter()
+                    .any(|port| port.pci_address.as_ref() == Some(pci))
+            });
+            eal.pci_allow
+                .extend(added.iter().filter_map(|port| port.pci_address.clone()));
+        }
+    }
+    device.ports.extend(added);
+    Some(())
+}
+
+/// Config around the given overlay, valid as long as the overlay is.
+pub(crate) fn generate_config<D: Driver>(d: &mut D, overlay: Overlay) -> Option<GatewayConfig> {
+    let mut config = GatewayConfig {
+        generation: d.gen_i64(Bound::Included(&0), Bound::Included(&1000))?,
+        device: Some(d.produce::<Device>()?),
+        overlay: Some(overlay),
+        underlay: Some(d.produce::<Underlay>()?),
+        external: None,
+    };
+    if d.gen_bool(None)? {
+        generate_external(d, &mut config)?;
+    }
+    if config.device.as_ref()?.driver == i32::from(PacketDriver::Dpdk) {
+        generate_ports(d, &mut config)?;
+    }
+    Some(config)
+}
+
 impl TypeGenerator for GatewayConfig {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
-        Some(GatewayConfig {
-            generation: d.gen_i64(Bound::Included(&0), Bound::Included(&1000))?,
-            device: Some(d.produce::<Device>()?),
-            overlay: Some(d.produce::<Overlay>()?),
-            underlay: Some(d.produce::<Underlay>()?),
-        })
+        let overlay = d.produce::<Overlay>()?;
+        generate_config(d, overlay)
     }
 }
+
 #[cfg(test)]
 mod test {
-    use crate::config::{Overlay, Underlay};
+    use crate::bolero::test_support::get_peering_ip;
+    use crate::bolero::vpc::vpc_subnets;
+    use crate::config::{GatewayConfig, Overlay, Underlay};
+    use crate::normalize::{normalized, semantic_eq};
+    use crate::prefix::Prefix;
+    use crate::validation::validate_config;
+    use std::collections::HashSet;
 
     #[test]
     fn test_overlay() {
+        let mut some_peerings = false;
         bolero::check!()
             .with_type::<Overlay>()
-            .for_each(|_overlay| {
-                // Other tests cover the interesting stuff, this just makes sure the generator doesn't panic
+            .for_each(|overlay: &Overlay| {
+                let mut pairs = HashSet::new();
+                for peering in &overlay.peerings {
+                    some_peerings = true;
+                    let [a, b] = peering.r#for.as_slice() else {
+                        panic!("peering {} has not exactly two entries", peering.name);
+                    };
+                    assert_ne!(a.vpc, b.vpc);
+                    assert!(pairs.insert((a.vpc.clone(), b.vpc.clone())));
+                    assert!(!pairs.contains(&(b.vpc.clone(), a.vpc.clone())));
+                    for entry in &peering.r#for {
+                        let vpc = overlay.vpcs.iter().find(|vpc| vpc.name == entry.vpc);
+                        let subnets = vpc_subnets(vpc.unwrap());
+                        assert!(!entry.expose.is_empty());
+                        for ips in entry.expose.iter().flat_map(|expose| &expose.ips) {
+                            let cidr = get_peering_ip(ips).unwrap().parse::<Prefix>().unwrap();
+                            assert!(subnets.contains(&cidr));
+                        }
+                    }
+                }
             });
+        assert!(some_peerings);
     }
 
     #[test]
@@ -79,4 +342,23 @@ mod test {
                 // Other tests cover the interesting stuff, this just makes sure the generator doesn't panic
             });
     }
+
+    #[test]
+    fn test_gateway_config() {
+        let mut some_ports = false;
+        let mut some_external = false;
+        bolero::check!()
+            .with_type::<GatewayConfig>()
+            .for_each(|config: &GatewayConfig| {
+                assert_eq!(validate_config(config), Ok(()));
+                let normal = normalized(config);
+                assert_eq!(validate_config(&normal), Ok(()));
+                assert_eq!(normalized(&normal), normal);
+                assert!(semantic_eq(config, &normal));
+                some_ports = some_ports || !config.device.as_ref().unwrap().ports.is_empty();
+                some_external = some_external || config.external.is_some();
+            });
+        assert!(some_ports);
+        assert!(some_external);
+    }
 }
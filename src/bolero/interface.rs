@@ -85,7 +85,9 @@ impl TypeGenerator for Interface {
             name: d.produce::<LinuxIfName>()?.0,
             ipaddrs,
             r#type: r#type.into(),
-            role: IfRole::Fabric.into(), // Dataplane only supports Fabric for now - d.produce::<IfRole>()?.into(),
+            // External interfaces are only valid in external VRFs, the `GatewayConfig`
+            // generator turns some of the underlay interfaces into external ones
+            role: IfRole::Fabric.into(),
             vlan,
             macaddr,
             ospf,
@@ -3,11 +3,16 @@
 
 mod bgp;
 mod device;
+mod evolution;
 mod expose;
+mod external;
 mod gateway_config;
 mod impl_peering_as;
 mod impl_peering_i_ps;
 mod interface;
+mod model;
+mod mutation;
+mod scale;
 pub mod support;
 #[cfg(test)]
 pub mod test_support;
@@ -18,14 +23,20 @@ mod vrf;
 pub use bgp::*;
 #[allow(unused)] // Currently only implements traits, remove if we export anything
 pub use device::*;
+pub use evolution::*;
 #[allow(unused)] // Currently only implements traits, remove if we export anything
 pub use expose::*;
 #[allow(unused)] // Currently only implements traits, remove if we export anything
+pub use external::*;
+#[allow(unused)] // Currently only implements traits, remove if we export anything
 pub use gateway_config::*;
 pub use impl_peering_as::*;
 pub use impl_peering_i_ps::*;
 #[allow(unused)] // Currently only implements traits, remove if we export anything
 pub use interface::*;
+pub use model::*;
+pub use mutation::*;
+pub use scale::*;
 #[allow(unused)] // Currently only implements traits, remove if we export anything
 pub use vpc::*;
 #[allow(unused)] // Currently only implements traits, remove if we export anything
@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use bolero::{Driver, TypeGenerator, ValueGenerator};
+use std::ops::{Bound, RangeInclusive};
+use std::panic::RefUnwindSafe;
+use std::sync::Arc;
+use tonic::Status;
+
+use crate::bolero::mutation::InvalidConfig;
+use crate::config::{Error, GatewayConfig, config_service_server::ConfigService};
+use crate::conformance::ConformanceTarget;
+use crate::normalize::semantic_eq;
+use crate::validation::validate_config;
+
+/// A single `ConfigService` call of a model-based test.
+///
+/// Configs sent by updates get the generation following the model's, so that accepted
+/// updates always move the generation forward.
+#[derive(Debug, Clone, TypeGenerator)]
+pub enum Operation {
+    GetConfig,
+    GetConfigGeneration,
+    UpdateConfig(GatewayConfig),
+    UpdateInvalidConfig(InvalidConfig),
+}
+
+/// Why a service diverged from [`ConfigModel`], with the index of the failing operation.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ModelError {
+    #[error("Operation {0}: RPC failed: {1}")]
+    Rpc(usize, Status),
+    #[error("Operation {0}: expected update result {1:?}, got {2:?}")]
+    UpdateResult(usize, Error, Error),
+    #[error("Operation {0}: generation went back from {1} to {2}")]
+    GenerationDecreased(usize, i64, i64),
+    #[error("Operation {0}: expected generation {1}, got {2}")]
+    Generation(usize, i64, i64),
+    #[error("Operation {0}: GetConfig does not return the config of generation {1}")]
+    StaleConfig(usize, i64),
+}
+
+/// Reference behavior of a `ConfigService`: a config is accepted if and only if it
+/// passes [`validate_config`], and is then what the service runs.
+#[derive(Debug, Clone, Default)]
+pub struct ConfigModel {
+    config: GatewayConfig,
+}
+
+impl ConfigModel {
+    #[must_use]
+    pub fn new(config: GatewayConfig) -> Self {
+        Self { config }
+    }
+
+    /// The config the service is expected to run.
+    #[must_use]
+    pub fn config(&self) -> &GatewayConfig {
+        &self.config
+    }
+
+    /// Apply an update and return the result the service is expected to report.
+    pub fn update(&mut self, config: GatewayConfig) -> Error {
+        if validate_config(&config).is_err() {
+            return Error::ValidationFailed;
+        }
+        self.config = config;
+        Error::None
+    }
+}
+
+/// Run `operations` against `target` and a [`ConfigModel`] starting from the target's
+/// current config, checking that generations never go back and that every read returns
+/// the last accepted write.
+///
+/// # Errors
+///
+/// Returns the first divergence between the target and the model.
+pub async fn check_model<T: ConformanceTarget>(
+    target: &mut T,
+    operations: &[Operation],
+) -> Result<(), ModelError> {
+    let mut model = ConfigModel::new(
+        target
+            .get_config()
+            .await
+            .map_err(|s| ModelError::Rpc(0, s))?,
+    );
+    let mut seen = model.config().generation;
+    for (i, operation) in operations.iter().enumerate() {
+        let rpc = |status| ModelError::Rpc(i, status);
+        let expected = model.config().generation;
+        match operation {
+            Operation::GetConfig => {
+                let running = target.get_config().await.map_err(rpc)?;
+                if running.generation != expected || !semantic_eq(&running, model.config()) {
+                    return Err(ModelError::StaleConfig(i, expected));
+                }
+            }
+            Operation::GetConfigGeneration => {
+                let generation = target.get_generation().await.map_err(rpc)?;
+                if generation < seen {
+                    return Err(ModelError::GenerationDecreased(i, seen, generation));
+                }
+                if generation != expected {
+                    return Err(ModelError::Generation(i, expected, generation));
+                }
+                seen = generation;
+            }
+            Operation::UpdateConfig(config)
+            | Operation::UpdateInvalidConfig(InvalidConfig { config, .. }) => {
+                let config = GatewayConfig {
+                    generation: expected + 1,
+                    ..config.clone()
+                };
+                let response = target.update_config(config.clone()).await.map_err(rpc)?;
+                let wanted = model.update(config);
+                if response.error() != wanted {
+                    return Err(ModelError::UpdateResult(i, wanted, response.error()));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Sequences of [`Operation`]s with a length in the given range.
+pub struct OperationsGenerator {
+    pub operations: RangeInclusive<usize>,
+}
+
+impl ValueGenerator for OperationsGenerator {
+    type Output = Vec<Operation>;
+
+    fn generate<D: Driver>(&self, d: &mut D) -> Option<Self::Output> {
+        let len = d.gen_usize(
+            Bound::Included(self.operations.start()),
+            Bound::Included(self.operations.end()),
+        )?;
+        (0..len).map(|_| d.produce::<Operation>()).collect()
+    }
+}
+
+/// Check a `ConfigService` implementation against [`ConfigModel`] with bolero generated
+/// operation sequences, each run on a fresh service from `new_service`. Meant to be called
+/// from a `#[test]`.
+///
+/// # Panics
+///
+/// Panics on the first divergence from the model, or if no tokio runtime can be created.
+pub fn check_config_service<S: ConfigService>(new_service: impl Fn() -> S + RefUnwindSafe) {
+    let runtime = tokio::runtime::Runtime::new().expect("tokio runtime");
+    bolero::check!()
+        .with_generator(OperationsGenerator { operations: 1..=10 })
+        .with_iterations(100)
+        .with_max_len(1 << 20)
+        .for_each(|operations: &Vec<Operation>| {
+            let mut target = Arc::new(new_service());
+            if let Err(e) = runtime.block_on(check_model(&mut target, operations)) {
+                panic!("{e}");
+            }
+        });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::conformance::test_support::Forgetful;
+    use crate::server::FakeConfigService;
+
+    #[test]
+    fn test_fake_server_follows_model() {
+        check_config_service(FakeConfigService::new);
+    }
+
+    #[tokio::test]
+    async fn test_check_model_divergence() {
+        let mut target = Forgetful(Arc::new(FakeConfigService::new()));
+        let operations = [
+            Operation::GetConfigGeneration,
+            Operation::UpdateConfig(GatewayConfig::default()),
+            Operation::GetConfigGeneration,
+        ];
+        let result = check_model(&mut target, &operations).await;
+        assert!(
+            matches!(result, Err(ModelError::Generation(2, 1, 0))),
+            "{result:?}"
+        );
+    }
+}
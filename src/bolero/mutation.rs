@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use bolero::{Driver, TypeGenerator};
+use std::collections::HashSet;
+use std::ops::Bound;
+
+use crate::bolero::support::choose;
+use crate::config::{GatewayConfig, IfType, Interface, PeeringEntryFor, Vpc, VpcPeering};
+use crate::prefix::Prefix;
+use crate::validation::ValidationError;
+
+/// A single targeted corruption of a valid config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, TypeGenerator)]
+pub enum Mutation {
+    /// A second VPC reusing the VNI of an existing one
+    DuplicateVni,
+    /// A malformed interface address
+    BadCidr,
+    /// A VLAN ID on an ethernet interface
+    VlanOnEthernet,
+    /// A peering entry for a VPC that does not exist
+    MissingPeeringVpc,
+    /// An interface address with a mask longer than its address family allows
+    MaskTooLong,
+}
+
+/// A config made invalid by a [`Mutation`], with the only violation validation must report.
+#[derive(Debug, Clone)]
+pub struct InvalidConfig {
+    pub config: GatewayConfig,
+    pub mutation: Mutation,
+    pub expected: ValidationError,
+}
+
+fn interfaces_mut(config: &mut GatewayConfig) -> Vec<&mut Interface> {
+    let underlay = config
+        .underlay
+        .iter_mut()
+        .flat_map(|underlay| &mut underlay.vrfs)
+        .flat_map(|vrf| &mut vrf.interfaces);
+    let overlay = config
+        .overlay
+        .iter_mut()
+        .flat_map(|overlay| &mut overlay.vpcs)
+        .flat_map(|vpc| &mut vpc.interfaces);
+    underlay.chain(overlay).collect()
+}
+
+/// Pick one of the interfaces matching `eligible`, `None` if there is none.
+fn choose_interface<'a, D: Driver>(
+    d: &mut D,
+    config: &'a mut GatewayConfig,
+    eligible: impl Fn(&Interface) -> bool,
+) -> Option<&'a mut Interface> {
+    let mut candidates = interfaces_mut(config)
+        .into_iter()
+        .filter(|iface| eligible(iface))
+        .collect::<Vec<_>>();
+    if candidates.is_empty() {
+        return None;
+    }
+    let index = d.gen_usize(Bound::Included(&0), Bound::Excluded(&candidates.len()))?;
+    Some(candidates.swap_remove(index))
+}
+
+impl Mutation {
+    /// Corrupt `config`, which must be valid, and return the violation it now has.
+    ///
+    /// VPCs and peerings are added when there are none to corrupt. Returns `None` if the
+    /// config has no interface this mutation could target.
+    pub fn apply<D: Driver>(
+        self,
+        d: &mut D,
+        config: &mut GatewayConfig,
+    ) -> Option<ValidationError> {
+        match self {
+            Mutation::DuplicateVni => {
+                let overlay = config.overlay.get_or_insert_default();
+                let vni = match overlay.vpcs.as_slice() {
+                    [] => {
+                        overlay.vpcs.push(Vpc {
+                            id: "orig".to_string(),
+                            name: "orig".to_string(),
+                            vni: 1,
+                            interfaces: vec![],
+                        });
+                        1
+                    }
+                    vpcs => choose(d, vpcs)?.vni,
+                };
+                // Generated VPC names and IDs are digits only
+                overlay.vpcs.push(Vpc {
+                    id: "duplicate-vni-vpc".to_string(),
+                    name: "duplicate-vni-vpc".to_string(),
+                    vni,
+                    interfaces: vec![],
+                });
+                Some(ValidationError::DuplicateVni(vni))
+            }
+            Mutation::BadCidr => {
+                let bad = choose(
+                    d,
+                    &[
+                        "10.0.0.1",
+                        "10.0.0.256/24",
+                        "10.0.0.1/x",
+                        "fe80::1::2/64",
+                        "",
+                    ],
+                )?;
+                let iface = choose_interface(d, config, |iface| !iface.ipaddrs.is_empty())?;
+                let index =
+                    d.gen_usize(Bound::Included(&0), Bound::Excluded(&iface.ipaddrs.len()))?;
+                iface.ipaddrs[index] = bad.to_string();
+                // A duplicate of the replaced address would be reported twice
+                let mut seen = HashSet::new();
+                iface.ipaddrs.retain(|addr| seen.insert(addr.clone()));
+                Some(ValidationError::InvalidCidr(bad.to_string()))
+            }
+            Mutation::VlanOnEthernet => {
+                let vlan = d.gen_u32(Bound::Included(&1), Bound::Included(&4094))?;
+                let iface = choose_interface(d, config, |iface| {
+                    iface.r#type == i32::from(IfType::Ethernet)
+                })?;
+                iface.vlan = Some(vlan);
+                Some(ValidationError::VlanOnNonVlanInterface(iface.name.clone()))
+            }
+            Mutation::MissingPeeringVpc => {
+                let overlay = config.overlay.get_or_insert_default();
+                let vpc = "missing-vpc".to_string();
+                if overlay.peerings.is_empty() {
+                    // Peer with an existing VPC, adding one if needed
+                    if overlay.vpcs.is_empty() {
+                        overlay.vpcs.push(Vpc {
+                            id: "orig".to_string(),
+                            name: "orig".to_string(),
+                            vni: 1,
+                            interfaces: vec![],
+                        });
+                    }
+                    let peer = choose(d, &overlay.vpcs)?.name;
+                    let entry = |vpc: &str| PeeringEntryFor {
+                        vpc: vpc.to_string(),
+                        expose: vec![],
+                    };
+                    overlay.peerings.push(VpcPeering {
+                        name: format!("{peer}--{vpc}"),
+                        r#for: vec![entry(&peer), entry(&vpc)],
+                    });
+                    let name = overlay.peerings[0].name.clone();
+                    return Some(ValidationError::UnknownVpc(name, vpc));
+                }
+                let index = d.gen_usize(
+                    Bound::Included(&0),
+                    Bound::Excluded(&overlay.peerings.len()),
+                )?;
+                let peering = &mut overlay.peerings[index];
+                let side =
+                    d.gen_usize(Bound::Included(&0), Bound::Excluded(&peering.r#for.len()))?;
+                peering.r#for[side].vpc.clone_from(&vpc);
+                Some(ValidationError::UnknownVpc(peering.name.clone(), vpc))
+            }
+            Mutation::MaskTooLong => {
+                let is_valid = |addr: &String| addr.parse::<Prefix>().is_ok();
+                let iface =
+                    choose_interface(d, config, |iface| iface.ipaddrs.iter().any(is_valid))?;
+                let addr = iface.ipaddrs.iter_mut().find(|addr| is_valid(addr))?;
+                let max = if addr.parse::<Prefix>().ok()?.is_ipv4() {
+                    32
+                } else {
+                    128
+                };
+                let mask = d.gen_u8(Bound::Excluded(&max), Bound::Included(&u8::MAX))?;
+                let (ip, _) = addr.split_once('/')?;
+                *addr = format!("{ip}/{mask}");
+                Some(ValidationError::InvalidCidr(addr.clone()))
+            }
+        }
+    }
+}
+
+impl TypeGenerator for InvalidConfig {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        let mut config = d.produce::<GatewayConfig>()?;
+        let mutation = d.produce::<Mutation>()?;
+        let expected = mutation.apply(d, &mut config)?;
+        Some(InvalidConfig {
+            config,
+            mutation,
+            expected,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builder::{GatewayConfigBuilder, InterfaceBuilder};
+    use crate::config::config_service_server::ConfigService;
+    use crate::config::{Error, UpdateConfigRequest};
+    use crate::server::FakeConfigService;
+    use crate::validation::validate_config;
+    use bolero::ValueGenerator;
+    use tonic::Request;
+
+    /// A config whose only address is an IPv4-mapped IPv6 one, with its mask made too long.
+    struct MappedMaskTooLong;
+
+    impl ValueGenerator for MappedMaskTooLong {
+        type Output = (GatewayConfig, ValidationError);
+
+        fn generate<D: Driver>(&self, d: &mut D) -> Option<Self::Output> {
+            let mut config = GatewayConfigBuilder::new()
+                .vrf("default")
+                .interface(InterfaceBuilder::ethernet("eth0").address("::ffff:10.0.0.1/96"))
+                .build()
+                .ok()?;
+            let expected = Mutation::MaskTooLong.apply(d, &mut config)?;
+            Some((config, expected))
+        }
+    }
+
+    #[test]
+    fn test_mask_too_long_mapped_ipv6() {
+        bolero::check!()
+            .with_generator(MappedMaskTooLong)
+            .for_each(|(config, expected): &(GatewayConfig, ValidationError)| {
+                let ValidationError::InvalidCidr(addr) = expected else {
+                    panic!("{expected:?}");
+                };
+                let (_, mask) = addr.split_once('/').unwrap();
+                assert!(mask.parse::<u8>().unwrap() > 128, "{addr}");
+                assert_eq!(validate_config(config), Err(vec![expected.clone()]));
+            });
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let mut mutations = HashSet::new();
+        bolero::check!()
+            .with_type::<InvalidConfig>()
+            .for_each(|invalid: &InvalidConfig| {
+                mutations.insert(invalid.mutation);
+                assert_eq!(
+                    validate_config(&invalid.config),
+                    Err(vec![invalid.expected.clone()]),
+                    "{:?}",
+                    invalid.mutation
+                );
+            });
+        assert_eq!(mutations.len(), 5, "{mutations:?}");
+    }
+
+    #[test]
+    fn test_fake_server_rejects_invalid_config() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        bolero::check!()
+            .with_type::<InvalidConfig>()
+            .with_iterations(100)
+            .for_each(|invalid: &InvalidConfig| {
+                let service = FakeConfigService::new();
+                let response = runtime
+                    .block_on(service.update_config(Request::new(UpdateConfigRequest {
+                        config: Some(invalid.config.clone()),
+                        expected_generation: None,
+                        owner: String::new(),
+                        confirm_timeout_seconds: 0,
+                        async_apply: false,
+                    })))
+                    .unwrap()
+                    .into_inner();
+                assert_eq!(response.error(), Error::ValidationFailed);
+                assert!(response.message.contains(&invalid.expected.to_string()));
+                assert_eq!(runtime.block_on(service.config()), GatewayConfig::default());
+            });
+    }
+}
@@ -0,0 +1,315 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::ops::Bound;
+
+use bolero::{Driver, ValueGenerator};
+use rand_xoshiro::Xoshiro256PlusPlus;
+use rand_xoshiro::rand_core::{RngCore, SeedableRng};
+
+use crate::bolero::gateway_config::{OverlayGenerator, generate_config};
+use crate::config::{GatewayConfig, RouterConfig};
+
+/// Sizes of a generated config, for load and performance testing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScaleLimits {
+    pub vpcs: usize,
+    /// Upper bound, only VPCs with subnets are peered and each pair at most once
+    pub peerings: usize,
+    pub interfaces_per_vpc: usize,
+    /// BGP neighbors of the first underlay VRF
+    pub neighbors: usize,
+}
+
+/// Predefined [`ScaleLimits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleProfile {
+    /// About what the regular generators produce
+    Small,
+    /// A busy production gateway
+    Medium,
+    /// Beyond what we expect in production
+    Huge,
+}
+
+impl ScaleProfile {
+    #[must_use]
+    pub fn limits(self) -> ScaleLimits {
+        match self {
+            ScaleProfile::Small => ScaleLimits {
+                vpcs: 10,
+                peerings: 10,
+                interfaces_per_vpc: 2,
+                neighbors: 2,
+            },
+            ScaleProfile::Medium => ScaleLimits {
+                vpcs: 200,
+                peerings: 500,
+                interfaces_per_vpc: 4,
+                neighbors: 16,
+            },
+            ScaleProfile::Huge => ScaleLimits {
+                vpcs: 5000,
+                peerings: 10000,
+                interfaces_per_vpc: 8,
+                neighbors: 128,
+            },
+        }
+    }
+
+    /// Shorthand for generating with the [`limits`](Self::limits) of the profile.
+    #[must_use]
+    pub fn generate(self, seed: u64) -> GatewayConfig {
+        self.limits().generate(seed)
+    }
+}
+
+impl ScaleLimits {
+    /// Generate a valid config of this size. The same seed always gives the same config.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generators fail to produce a config, which would be a bug in them.
+    #[must_use]
+    pub fn generate(&self, seed: u64) -> GatewayConfig {
+        self.generate_with(&mut SeededDriver::new(seed))
+            .expect("generators should always produce a scaled config")
+    }
+
+    fn generate_with<D: bolero::Driver>(&self, d: &mut D) -> Option<GatewayConfig> {
+        let overlay = OverlayGenerator {
+            vpcs: self.vpcs..=self.vpcs,
+            peerings: self.peerings..=self.peerings,
+            interfaces: self.interfaces_per_vpc..=self.interfaces_per_vpc,
+        }
+        .generate(d)?;
+        let mut config = generate_config(d, overlay)?;
+        if let Some(vrf) = config
+            .underlay
+            .as_mut()
+            .and_then(|underlay| underlay.vrfs.first_mut())
+        {
+            let router = match vrf.router.take() {
+                Some(router) => router,
+                None => d.produce::<RouterConfig>()?,
+            };
+            let neighbors = (0..self.neighbors)
+                .map(|_| d.produce())
+                .collect::<Option<Vec<_>>>()?;
+            vrf.router = Some(RouterConfig {
+                neighbors,
+                ..router
+            });
+        }
+        Some(config)
+    }
+}
+
+/// [`Driver`] drawing every value from an RNG seeded by the caller. Unlike the drivers of
+/// bolero's test engine it never runs dry, so configs of any size are fully random, and the
+/// same seed always gives the same values.
+struct SeededDriver {
+    rng: Xoshiro256PlusPlus,
+    depth: usize,
+}
+
+/// Same as bolero's default.
+const MAX_DEPTH: usize = 5;
+
+/// Largest byte slice handed to `gen_from_bytes` producers without an upper bound, bolero's
+/// default input length.
+const MAX_BYTES: usize = 4096;
+
+impl SeededDriver {
+    fn new(seed: u64) -> Self {
+        Self {
+            rng: Xoshiro256PlusPlus::seed_from_u64(seed),
+            depth: 0,
+        }
+    }
+
+    /// Value in `low..=high`.
+    fn gen_range(&mut self, low: u128, high: u128) -> u128 {
+        let value = u128::from(self.rng.next_u64()) << 64 | u128::from(self.rng.next_u64());
+        match (high - low).checked_add(1) {
+            Some(span) => low + value % span,
+            None => value,
+        }
+    }
+
+    /// Value in `[0, 1)`.
+    #[allow(clippy::cast_precision_loss)]
+    fn gen_unit(&mut self) -> f64 {
+        (self.rng.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+/// Integers are drawn in the order preserving mapping of their type to `u128`.
+macro_rules! gen_int {
+    ($name:ident, $ty:ty, $to:expr, $from:expr) => {
+        fn $name(&mut self, min: Bound<&$ty>, max: Bound<&$ty>) -> Option<$ty> {
+            let low = match min {
+                Bound::Included(min) => *min,
+                Bound::Excluded(min) => min.checked_add(1)?,
+                Bound::Unbounded => <$ty>::MIN,
+            };
+            let high = match max {
+                Bound::Included(max) => *max,
+                Bound::Excluded(max) => max.checked_sub(1)?,
+                Bound::Unbounded => <$ty>::MAX,
+            };
+            if low > high {
+                return None;
+            }
+            Some($from(self.gen_range($to(low), $to(high))))
+        }
+    };
+    ($name:ident, $ty:ty, unsigned) => {
+        gen_int!($name, $ty, |v: $ty| v as u128, |v: u128| v as $ty);
+    };
+    ($name:ident, $ty:ty, signed) => {
+        gen_int!(
+            $name,
+            $ty,
+            |v: $ty| (v as i128 as u128) ^ (1 << 127),
+            |v: u128| (v ^ (1 << 127)) as i128 as $ty
+        );
+    };
+}
+
+/// Floats are drawn uniformly between their bounds, excluded bounds are treated as included.
+macro_rules! gen_float {
+    ($name:ident, $ty:ty) => {
+        fn $name(&mut self, min: Bound<&$ty>, max: Bound<&$ty>) -> Option<$ty> {
+            let low = match min {
+                Bound::Included(min) | Bound::Excluded(min) => *min,
+                Bound::Unbounded => <$ty>::MIN,
+            };
+            let high = match max {
+                Bound::Included(max) | Bound::Excluded(max) => *max,
+                Bound::Unbounded => <$ty>::MAX,
+            };
+            let unit = self.gen_unit() as $ty;
+            Some(low * (1.0 - unit) + high * unit)
+        }
+    };
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    clippy::cast_lossless
+)]
+impl Driver for SeededDriver {
+    gen_int!(gen_u8, u8, unsigned);
+    gen_int!(gen_i8, i8, signed);
+    gen_int!(gen_u16, u16, unsigned);
+    gen_int!(gen_i16, i16, signed);
+    gen_int!(gen_u32, u32, unsigned);
+    gen_int!(gen_i32, i32, signed);
+    gen_int!(gen_u64, u64, unsigned);
+    gen_int!(gen_i64, i64, signed);
+    gen_int!(gen_u128, u128, unsigned);
+    gen_int!(gen_i128, i128, signed);
+    gen_int!(gen_usize, usize, unsigned);
+    gen_int!(gen_isize, isize, signed);
+    gen_float!(gen_f32, f32);
+    gen_float!(gen_f64, f64);
+
+    fn gen_char(&mut self, min: Bound<&char>, max: Bound<&char>) -> Option<char> {
+        let min = min.map(|min| u32::from(*min));
+        let max = max.map(|max| u32::from(*max));
+        // Retry on surrogates, which are not chars
+        (0..100).find_map(|_| {
+            let code = self.gen_u32(min.as_ref(), max.as_ref())?;
+            char::from_u32(code)
+        })
+    }
+
+    fn gen_bool(&mut self, probability: Option<f32>) -> Option<bool> {
+        Some(self.gen_unit() < f64::from(probability.unwrap_or(0.5)))
+    }
+
+    fn gen_variant(&mut self, variants: usize, base_case: usize) -> Option<usize> {
+        if self.depth == MAX_DEPTH {
+            return Some(base_case);
+        }
+        self.gen_usize(Bound::Included(&0), Bound::Excluded(&variants))
+    }
+
+    fn gen_from_bytes<Hint, Gen, T>(&mut self, hint: Hint, mut produce: Gen) -> Option<T>
+    where
+        Hint: FnOnce() -> (usize, Option<usize>),
+        Gen: FnMut(&[u8]) -> Option<(usize, T)>,
+    {
+        let (min, max) = hint();
+        let max = max.unwrap_or(MAX_BYTES).max(min);
+        let len = self.gen_usize(Bound::Included(&min), Bound::Included(&max))?;
+        let mut bytes = vec![0; len];
+        self.rng.fill_bytes(&mut bytes);
+        produce(&bytes).map(|(_, value)| value)
+    }
+
+    fn depth(&self) -> usize {
+        self.depth
+    }
+
+    fn set_depth(&mut self, depth: usize) {
+        self.depth = depth;
+    }
+
+    fn max_depth(&self) -> usize {
+        MAX_DEPTH
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::validation::validate_config;
+
+    #[test]
+    fn test_scale_profiles() {
+        for profile in [ScaleProfile::Small, ScaleProfile::Medium] {
+            let limits = profile.limits();
+            let config = profile.generate(42);
+            assert_eq!(config, profile.generate(42));
+            assert_ne!(config, profile.generate(43));
+            assert_eq!(validate_config(&config), Ok(()));
+
+            let overlay = config.overlay.unwrap();
+            assert_eq!(overlay.vpcs.len(), limits.vpcs);
+            assert!(overlay.peerings.len() <= limits.peerings);
+            assert!(!overlay.peerings.is_empty());
+            assert!(
+                overlay
+                    .vpcs
+                    .iter()
+                    .all(|vpc| vpc.interfaces.len() == limits.interfaces_per_vpc)
+            );
+            let vrf = &config.underlay.unwrap().vrfs[0];
+            assert_eq!(
+                vrf.router.as_ref().unwrap().neighbors.len(),
+                limits.neighbors
+            );
+        }
+
+        // Huge is only generated by the ignored test_huge_profile
+        let (medium, huge) = (ScaleProfile::Medium.limits(), ScaleProfile::Huge.limits());
+        assert!(huge.vpcs > medium.vpcs && huge.peerings > medium.peerings);
+    }
+
+    #[test]
+    #[ignore = "takes seconds, run with --ignored when changing the generators"]
+    fn test_huge_profile() {
+        let limits = ScaleProfile::Huge.limits();
+        let config = ScaleProfile::Huge.generate(42);
+        assert_eq!(validate_config(&config), Ok(()));
+
+        let overlay = config.overlay.unwrap();
+        assert_eq!(overlay.vpcs.len(), limits.vpcs);
+        assert!(overlay.peerings.len() <= limits.peerings);
+        assert!(!overlay.peerings.is_empty());
+    }
+}
@@ -1,10 +1,14 @@
 // SPDX-License-Identifier: Apache-2.0
 // Copyright 2025 Hedgehog
 
+use crate::bolero::expose::ExposeGenerator;
 use crate::bolero::support::{ALPHA_NUMERIC_CHARS, LinuxIfName, gen_from_chars};
 use crate::config::{Expose, Interface, PeeringEntryFor, Vpc, VpcPeering};
-use bolero::{Driver, TypeGenerator};
-use std::ops::Bound;
+use crate::prefix::Prefix;
+use bolero::{Driver, TypeGenerator, ValueGenerator};
+use std::collections::BTreeSet;
+use std::ops::{Bound, RangeInclusive};
+
 impl TypeGenerator for PeeringEntryFor {
     fn generate<D: Driver>(d: &mut D) -> Option<Self> {
         Some(PeeringEntryFor {
@@ -25,9 +29,19 @@ impl TypeGenerator for VpcPeering {
     }
 }
 
-impl TypeGenerator for Vpc {
-    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
-        let nintf = d.gen_usize(Bound::Included(&1), Bound::Included(&10))?;
+/// VPC with a number of interfaces in the given range.
+pub(crate) struct VpcGenerator {
+    pub interfaces: RangeInclusive<usize>,
+}
+
+impl ValueGenerator for VpcGenerator {
+    type Output = Vpc;
+
+    fn generate<D: Driver>(&self, d: &mut D) -> Option<Self::Output> {
+        let nintf = d.gen_usize(
+            Bound::Included(self.interfaces.start()),
+            Bound::Included(self.interfaces.end()),
+        )?;
         let mut iface_num = 0;
         Some(Vpc {
             name: d.produce::<LinuxIfName>()?.0,
@@ -54,6 +68,58 @@ impl TypeGenerator for Vpc {
     }
 }
 
+impl TypeGenerator for Vpc {
+    fn generate<D: Driver>(d: &mut D) -> Option<Self> {
+        VpcGenerator { interfaces: 1..=10 }.generate(d)
+    }
+}
+
+/// Subnets of the addresses of a VPC's interfaces, without duplicates.
+pub(crate) fn vpc_subnets(vpc: &Vpc) -> Vec<Prefix> {
+    vpc.interfaces
+        .iter()
+        .flat_map(|iface| &iface.ipaddrs)
+        .filter_map(|addr| addr.parse::<Prefix>().ok())
+        .map(|prefix| prefix.network())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+/// Peering between two existing VPCs, named after them, where each side exposes some of
+/// its own subnets. Both VPCs must have at least one subnet.
+pub(crate) struct VpcPeeringGenerator<'a> {
+    pub vpcs: [&'a Vpc; 2],
+}
+
+impl ValueGenerator for VpcPeeringGenerator<'_> {
+    type Output = VpcPeering;
+
+    fn generate<D: Driver>(&self, d: &mut D) -> Option<Self::Output> {
+        let [a, b] = self.vpcs;
+        let entries = self
+            .vpcs
+            .iter()
+            .map(|vpc| {
+                let exposes = ExposeGenerator {
+                    prefixes: vpc_subnets(vpc),
+                };
+                let nexposes = d.gen_usize(Bound::Included(&1), Bound::Included(&3))?;
+                Some(PeeringEntryFor {
+                    vpc: vpc.name.clone(),
+                    expose: (0..nexposes)
+                        .map(|_| exposes.generate(d))
+                        .collect::<Option<Vec<_>>>()?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(VpcPeering {
+            name: format!("{}--{}", a.name, b.name),
+            r#for: entries,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::config::{PeeringEntryFor, VpcPeering};
@@ -0,0 +1,501 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use thiserror::Error;
+
+use crate::config::{
+    BgpNeighbor, Device, Expose, GatewayConfig, IfRole, IfType, Interface, L4Filter, L4Protocol,
+    NatPool, Overlay, PacketDriver, PeeringAs, PeeringEntryFor, PeeringIPs, PortRange,
+    RouterConfig, StatefulNat, Underlay, Vpc, VpcPeering, Vrf, peering_as, peering_i_ps,
+};
+use crate::validation::{ValidationError, validate_config};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum BuildError {
+    #[error("Builder method {0}() must follow {1}")]
+    OutOfScope(&'static str, &'static str),
+    #[error("VPC {1} is not part of peering {0}")]
+    VpcNotInPeering(String, String),
+    #[error("Config failed validation: {0:?}")]
+    Invalid(Vec<ValidationError>),
+}
+
+/// Builder for an [`Interface`], defaulting to a fabric facing ethernet interface.
+#[derive(Debug, Clone)]
+pub struct InterfaceBuilder {
+    iface: Interface,
+}
+
+impl InterfaceBuilder {
+    fn new(name: &str, r#type: IfType) -> Self {
+        Self {
+            iface: Interface {
+                name: name.to_string(),
+                r#type: r#type.into(),
+                role: IfRole::Fabric.into(),
+                ..Interface::default()
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn ethernet(name: &str) -> Self {
+        Self::new(name, IfType::Ethernet)
+    }
+
+    /// VLAN `vlan` on top of the system interface `parent`.
+    #[must_use]
+    pub fn vlan(name: &str, vlan: u32, parent: &str) -> Self {
+        let mut builder = Self::new(name, IfType::Vlan);
+        builder.iface.vlan = Some(vlan);
+        builder.iface.system_name = Some(parent.to_string());
+        builder
+    }
+
+    #[must_use]
+    pub fn loopback(name: &str) -> Self {
+        Self::new(name, IfType::Loopback)
+    }
+
+    #[must_use]
+    pub fn vtep(name: &str) -> Self {
+        Self::new(name, IfType::Vtep)
+    }
+
+    /// Add an address in CIDR notation, e.g. `10.0.0.1/31`.
+    #[must_use]
+    pub fn address(mut self, addr: &str) -> Self {
+        self.iface.ipaddrs.push(addr.to_string());
+        self
+    }
+
+    /// Mark the interface as facing networks outside the fabric.
+    #[must_use]
+    pub fn external(mut self) -> Self {
+        self.iface.role = IfRole::External.into();
+        self
+    }
+
+    #[must_use]
+    pub fn mac(mut self, mac: &str) -> Self {
+        self.iface.macaddr = Some(mac.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn mtu(mut self, mtu: u32) -> Self {
+        self.iface.mtu = Some(mtu);
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> Interface {
+        self.iface
+    }
+}
+
+impl From<InterfaceBuilder> for Interface {
+    fn from(builder: InterfaceBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// Builder for an [`Expose`] entry of a peering.
+#[derive(Debug, Clone, Default)]
+pub struct ExposeBuilder {
+    expose: Expose,
+}
+
+impl ExposeBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expose a prefix.
+    #[must_use]
+    pub fn ip(mut self, cidr: &str) -> Self {
+        self.expose.ips.push(PeeringIPs {
+            rule: Some(peering_i_ps::Rule::Cidr(cidr.to_string())),
+        });
+        self
+    }
+
+    /// Exclude a prefix from the exposed ones.
+    #[must_use]
+    pub fn not(mut self, cidr: &str) -> Self {
+        self.expose.ips.push(PeeringIPs {
+            rule: Some(peering_i_ps::Rule::Not(cidr.to_string())),
+        });
+        self
+    }
+
+    /// Statically translate the exposed prefixes into `cidr`.
+    #[must_use]
+    pub fn as_cidr(mut self, cidr: &str) -> Self {
+        self.expose.r#as.push(PeeringAs {
+            rule: Some(peering_as::Rule::Cidr(cidr.to_string())),
+        });
+        self
+    }
+
+    /// Exclude a prefix from the static translation.
+    #[must_use]
+    pub fn as_not(mut self, cidr: &str) -> Self {
+        self.expose.r#as.push(PeeringAs {
+            rule: Some(peering_as::Rule::Not(cidr.to_string())),
+        });
+        self
+    }
+
+    /// Masquerade behind the addresses of `pool`, with the dataplane's default ports and timeouts.
+    #[must_use]
+    pub fn stateful_nat(mut self, pool: &[&str]) -> Self {
+        self.expose.stateful_nat = Some(StatefulNat {
+            pool: Some(NatPool {
+                cidrs: pool.iter().map(ToString::to_string).collect(),
+                ports: None,
+            }),
+            ..StatefulNat::default()
+        });
+        self
+    }
+
+    /// Allow `protocol` to the given inclusive destination port ranges, all ports if empty.
+    #[must_use]
+    pub fn allow(mut self, protocol: L4Protocol, ports: &[(u32, u32)]) -> Self {
+        self.expose.filters.push(L4Filter {
+            protocol: protocol.into(),
+            ports: ports
+                .iter()
+                .map(|&(start, end)| PortRange { start, end })
+                .collect(),
+            icmp_types: vec![],
+        });
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> Expose {
+        self.expose
+    }
+}
+
+impl From<ExposeBuilder> for Expose {
+    fn from(builder: ExposeBuilder) -> Self {
+        builder.build()
+    }
+}
+
+/// What the scoped builder methods apply to: the last VRF, VPC or peering added.
+#[derive(Debug, Clone, Copy)]
+enum Scope {
+    None,
+    Vrf(usize),
+    Vpc(usize),
+    Peering(usize),
+}
+
+/// Fluent builder for a [`GatewayConfig`].
+///
+/// [`vrf`](Self::vrf), [`vpc`](Self::vpc) and [`peering`](Self::peering) open a scope the
+/// following scoped methods apply to, e.g. interfaces are added to the last VRF or VPC:
+///
+/// ```
+/// use gateway_config::builder::{ExposeBuilder, GatewayConfigBuilder, InterfaceBuilder};
+///
+/// let config = GatewayConfigBuilder::new()
+///     .generation(1)
+///     .vrf("default")
+///     .interface(InterfaceBuilder::ethernet("eth0").address("10.0.0.2/31"))
+///     .bgp("65000", "10.1.0.1")
+///     .neighbor("10.0.0.3", "65001")
+///     .vpc("vpc-1", 100)
+///     .vpc("vpc-2", 200)
+///     .peering("vpc-1", "vpc-2")
+///     .expose("vpc-1", ExposeBuilder::new().ip("10.10.0.0/24"))
+///     .expose("vpc-2", ExposeBuilder::new().ip("10.20.0.0/24"))
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.overlay.unwrap().peerings[0].name, "vpc-1--vpc-2");
+/// ```
+///
+/// Misuse, like an interface before any VRF or VPC, is reported by [`build`](Self::build).
+#[derive(Debug, Clone)]
+pub struct GatewayConfigBuilder {
+    config: GatewayConfig,
+    scope: Scope,
+    error: Option<BuildError>,
+}
+
+impl Default for GatewayConfigBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GatewayConfigBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            config: GatewayConfig::default(),
+            scope: Scope::None,
+            error: None,
+        }
+    }
+
+    /// Remember the first misuse, it is returned by `build()`.
+    fn fail(&mut self, error: BuildError) {
+        self.error.get_or_insert(error);
+    }
+
+    fn device_mut(&mut self) -> &mut Device {
+        self.config.device.get_or_insert_with(Device::default)
+    }
+
+    fn underlay_mut(&mut self) -> &mut Underlay {
+        self.config.underlay.get_or_insert_with(Underlay::default)
+    }
+
+    fn overlay_mut(&mut self) -> &mut Overlay {
+        self.config.overlay.get_or_insert_with(Overlay::default)
+    }
+
+    /// The VRF in scope, if any.
+    fn vrf_mut(&mut self, method: &'static str) -> Option<&mut Vrf> {
+        if let Scope::Vrf(index) = self.scope {
+            return Some(&mut self.underlay_mut().vrfs[index]);
+        }
+        self.fail(BuildError::OutOfScope(method, "vrf()"));
+        None
+    }
+
+    #[must_use]
+    pub fn generation(mut self, generation: i64) -> Self {
+        self.config.generation = generation;
+        self
+    }
+
+    /// Replace the device settings as a whole.
+    #[must_use]
+    pub fn device(mut self, device: Device) -> Self {
+        self.config.device = Some(device);
+        self
+    }
+
+    #[must_use]
+    pub fn hostname(mut self, hostname: &str) -> Self {
+        self.device_mut().hostname = hostname.to_string();
+        self
+    }
+
+    #[must_use]
+    pub fn driver(mut self, driver: PacketDriver) -> Self {
+        self.device_mut().driver = driver.into();
+        self
+    }
+
+    /// Add an underlay VRF and make it the scope.
+    #[must_use]
+    pub fn vrf(mut self, name: &str) -> Self {
+        let vrfs = &mut self.underlay_mut().vrfs;
+        vrfs.push(Vrf {
+            name: name.to_string(),
+            ..Vrf::default()
+        });
+        self.scope = Scope::Vrf(vrfs.len() - 1);
+        self
+    }
+
+    /// Run BGP in the VRF in scope.
+    #[must_use]
+    pub fn bgp(mut self, asn: &str, router_id: &str) -> Self {
+        if let Some(vrf) = self.vrf_mut("bgp") {
+            let router = vrf.router.get_or_insert_with(RouterConfig::default);
+            router.asn = asn.to_string();
+            router.router_id = router_id.to_string();
+        }
+        self
+    }
+
+    /// Add a BGP neighbor to the VRF in scope, which must run BGP.
+    #[must_use]
+    pub fn neighbor(mut self, address: &str, remote_asn: &str) -> Self {
+        let neighbor = BgpNeighbor {
+            address: address.to_string(),
+            remote_asn: remote_asn.to_string(),
+            ..BgpNeighbor::default()
+        };
+        match self.vrf_mut("neighbor").map(|vrf| vrf.router.as_mut()) {
+            Some(Some(router)) => router.neighbors.push(neighbor),
+            Some(None) => self.fail(BuildError::OutOfScope("neighbor", "bgp()")),
+            None => {}
+        }
+        self
+    }
+
+    /// Add a VPC, named and identified by `name`, and make it the scope.
+    #[must_use]
+    pub fn vpc(mut self, name: &str, vni: u32) -> Self {
+        let vpcs = &mut self.overlay_mut().vpcs;
+        vpcs.push(Vpc {
+            id: name.to_string(),
+            name: name.to_string(),
+            vni,
+            interfaces: vec![],
+        });
+        self.scope = Scope::Vpc(vpcs.len() - 1);
+        self
+    }
+
+    /// Add an interface to the VRF or VPC in scope.
+    #[must_use]
+    pub fn interface(mut self, iface: impl Into<Interface>) -> Self {
+        let iface = iface.into();
+        match self.scope {
+            Scope::Vrf(index) => self.underlay_mut().vrfs[index].interfaces.push(iface),
+            Scope::Vpc(index) => self.overlay_mut().vpcs[index].interfaces.push(iface),
+            Scope::None | Scope::Peering(_) => {
+                self.fail(BuildError::OutOfScope("interface", "vrf() or vpc()"));
+            }
+        }
+        self
+    }
+
+    /// Add a peering between VPCs `a` and `b`, named `a--b`, and make it the scope.
+    #[must_use]
+    pub fn peering(mut self, a: &str, b: &str) -> Self {
+        let entry = |vpc: &str| PeeringEntryFor {
+            vpc: vpc.to_string(),
+            expose: vec![],
+        };
+        let peerings = &mut self.overlay_mut().peerings;
+        peerings.push(VpcPeering {
+            name: format!("{a}--{b}"),
+            r#for: vec![entry(a), entry(b)],
+        });
+        self.scope = Scope::Peering(peerings.len() - 1);
+        self
+    }
+
+    /// Add an expose to the side of `vpc` of the peering in scope.
+    #[must_use]
+    pub fn expose(mut self, vpc: &str, expose: impl Into<Expose>) -> Self {
+        let Scope::Peering(index) = self.scope else {
+            self.fail(BuildError::OutOfScope("expose", "peering()"));
+            return self;
+        };
+        let peering = &mut self.overlay_mut().peerings[index];
+        if let Some(entry) = peering.r#for.iter_mut().find(|entry| entry.vpc == vpc) {
+            entry.expose.push(expose.into());
+        } else {
+            let error = BuildError::VpcNotInPeering(peering.name.clone(), vpc.to_string());
+            self.fail(error);
+        }
+        self
+    }
+
+    /// Finish the config and validate it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first misuse of the builder, or the problems found by [`validate_config`].
+    pub fn build(self) -> Result<GatewayConfig, BuildError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        validate_config(&self.config).map_err(BuildError::Invalid)?;
+        Ok(self.config)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        let config = GatewayConfigBuilder::new()
+            .generation(4)
+            .hostname("gw1")
+            .vrf("default")
+            .interface(InterfaceBuilder::ethernet("eth0").address("10.0.0.2/31"))
+            .interface(InterfaceBuilder::vtep("vtep").address("10.1.0.1/32"))
+            .bgp("65000", "10.1.0.1")
+            .neighbor("10.0.0.3", "65001")
+            .vpc("vpc-1", 100)
+            .interface(InterfaceBuilder::vlan("vlan10", 10, "eth1").address("10.10.0.1/24"))
+            .vpc("vpc-2", 200)
+            .peering("vpc-1", "vpc-2")
+            .expose(
+                "vpc-1",
+                ExposeBuilder::new()
+                    .ip("10.10.0.0/24")
+                    .stateful_nat(&["192.168.0.1/32"])
+                    .allow(L4Protocol::Tcp, &[(443, 443)]),
+            )
+            .expose("vpc-2", ExposeBuilder::new().ip("10.20.0.0/24"))
+            .build()
+            .unwrap();
+
+        assert_eq!(config.generation, 4);
+        assert_eq!(config.device.unwrap().hostname, "gw1");
+        let vrf = &config.underlay.unwrap().vrfs[0];
+        assert_eq!(vrf.interfaces[1].r#type(), IfType::Vtep);
+        assert_eq!(
+            vrf.router.as_ref().unwrap().neighbors[0].remote_asn,
+            "65001"
+        );
+        let overlay = config.overlay.unwrap();
+        let vlan = &overlay.vpcs[0].interfaces[0];
+        assert_eq!(vlan.r#type(), IfType::Vlan);
+        assert_eq!(vlan.vlan, Some(10));
+        let peering = &overlay.peerings[0];
+        assert_eq!(peering.r#for[1].vpc, "vpc-2");
+        let expose = &peering.r#for[0].expose[0];
+        assert!(expose.stateful_nat.is_some());
+        assert_eq!(expose.filters[0].protocol(), L4Protocol::Tcp);
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        assert_eq!(
+            GatewayConfigBuilder::new()
+                .interface(InterfaceBuilder::ethernet("eth0"))
+                .vrf("default")
+                .neighbor("10.0.0.1", "65001")
+                .build(),
+            Err(BuildError::OutOfScope("interface", "vrf() or vpc()"))
+        );
+        assert_eq!(
+            GatewayConfigBuilder::new()
+                .vrf("default")
+                .neighbor("10.0.0.1", "65001")
+                .build(),
+            Err(BuildError::OutOfScope("neighbor", "bgp()"))
+        );
+        assert_eq!(
+            GatewayConfigBuilder::new()
+                .peering("vpc-1", "vpc-2")
+                .expose("vpc-3", ExposeBuilder::new())
+                .build(),
+            Err(BuildError::VpcNotInPeering(
+                "vpc-1--vpc-2".to_string(),
+                "vpc-3".to_string()
+            ))
+        );
+        assert!(matches!(
+            GatewayConfigBuilder::new()
+                .peering("vpc-1", "vpc-2")
+                .expose(
+                    "vpc-1",
+                    ExposeBuilder::new()
+                        .ip("10.0.0.0/24")
+                        .as_cidr("10.1.0.0/24")
+                        .stateful_nat(&["192.168.0.1/32"]),
+                )
+                .build(),
+            Err(BuildError::Invalid(_))
+        ));
+    }
+}
@@ -0,0 +1,373 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+use tonic::Status;
+use tonic::codegen::StdError;
+use tonic::transport::{Channel, Endpoint};
+
+use crate::config::{
+    AcquireConfigLockRequest, AcquireConfigLockResponse, ApplyOperation, ConfirmConfigRequest,
+    ConfirmConfigResponse, Error, GatewayConfig, GetConfigGenerationRequest, GetConfigRequest,
+    GetCountersRequest, GetCountersResponse, GetOperationRequest, GetStatusRequest,
+    GetStatusResponse, OperationState, ReleaseConfigLockRequest, ReleaseConfigLockResponse,
+    SetLogLevelRequest, SetLogLevelResponse, UpdateConfigRequest, UpdateConfigResponse,
+    WaitOperationRequest, config_service_client::ConfigServiceClient,
+};
+use crate::normalize::content_hash;
+
+/// Convenience wrapper around [`ConfigServiceClient`] that hides the request and response plumbing.
+///
+/// Updates and config lock requests are sent on behalf of the client's owner identity,
+/// empty unless set with [`with_owner`](Self::with_owner).
+#[derive(Debug, Clone)]
+pub struct GatewayClient {
+    client: ConfigServiceClient<Channel>,
+    owner: String,
+}
+
+impl GatewayClient {
+    #[must_use]
+    pub fn new(channel: Channel) -> Self {
+        Self {
+            client: ConfigServiceClient::new(channel),
+            owner: String::new(),
+        }
+    }
+
+    /// Act as `owner`, the identity holding or asking for the config lock.
+    #[must_use]
+    pub fn with_owner(mut self, owner: impl Into<String>) -> Self {
+        self.owner = owner.into();
+        self
+    }
+
+    /// Connect to the dataplane at `dst`, e.g. `http://[::1]:50051`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the endpoint is invalid or cannot be connected to.
+    pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+    where
+        D: TryInto<Endpoint>,
+        D::Error: Into<StdError>,
+    {
+        Ok(Self {
+            client: ConfigServiceClient::connect(dst).await?,
+            owner: String::new(),
+        })
+    }
+
+    /// The underlying generated client, for calls not covered by the wrapper.
+    pub fn inner(&mut self) -> &mut ConfigServiceClient<Channel> {
+        &mut self.client
+    }
+
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn get_config(&mut self) -> Result<GatewayConfig, Status> {
+        Ok(self
+            .client
+            .get_config(GetConfigRequest {})
+            .await?
+            .into_inner())
+    }
+
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn get_generation(&mut self) -> Result<i64, Status> {
+        Ok(self
+            .client
+            .get_config_generation(GetConfigGenerationRequest {})
+            .await?
+            .into_inner()
+            .generation)
+    }
+
+    /// Content hash of the running config, see [`crate::normalize::content_hash`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn get_content_hash(&mut self) -> Result<String, Status> {
+        Ok(self
+            .client
+            .get_config_generation(GetConfigGenerationRequest {})
+            .await?
+            .into_inner()
+            .content_hash)
+    }
+
+    /// Whether the dataplane runs a config meaning the same as `config`, by content hash.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn is_running(&mut self, config: &GatewayConfig) -> Result<bool, Status> {
+        Ok(self.get_content_hash().await? == content_hash(config))
+    }
+
+    /// Send a config, the dataplane's verdict is in the returned response.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn update_config(
+        &mut self,
+        config: GatewayConfig,
+    ) -> Result<UpdateConfigResponse, Status> {
+        Ok(self
+            .client
+            .update_config(UpdateConfigRequest {
+                config: Some(config),
+                expected_generation: None,
+                owner: self.owner.clone(),
+                confirm_timeout_seconds: 0,
+                async_apply: false,
+            })
+            .await?
+            .into_inner())
+    }
+
+    /// Send a config that is only applied if the running generation is still
+    /// `expected_generation`, otherwise the response is a `GenerationConflict` error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn update_config_if(
+        &mut self,
+        config: GatewayConfig,
+        expected_generation: i64,
+    ) -> Result<UpdateConfigResponse, Status> {
+        Ok(self
+            .client
+            .update_config(UpdateConfigRequest {
+                config: Some(config),
+                expected_generation: Some(expected_generation),
+                owner: self.owner.clone(),
+                confirm_timeout_seconds: 0,
+                async_apply: false,
+            })
+            .await?
+            .into_inner())
+    }
+
+    /// Send a config that the dataplane reverts after `timeout_seconds` unless it is
+    /// kept with [`confirm_config`](Self::confirm_config) before.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn update_config_confirmed(
+        &mut self,
+        config: GatewayConfig,
+        timeout_seconds: u32,
+    ) -> Result<UpdateConfigResponse, Status> {
+        Ok(self
+            .client
+            .update_config(UpdateConfigRequest {
+                config: Some(config),
+                expected_generation: None,
+                owner: self.owner.clone(),
+                confirm_timeout_seconds: timeout_seconds,
+                async_apply: false,
+            })
+            .await?
+            .into_inner())
+    }
+
+    /// Keep the config of `generation` sent with a confirm timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn confirm_config(
+        &mut self,
+        generation: i64,
+    ) -> Result<ConfirmConfigResponse, Status> {
+        Ok(self
+            .client
+            .confirm_config(ConfirmConfigRequest { generation })
+            .await?
+            .into_inner())
+    }
+
+    /// Send a config to be applied in the background, the operation to follow with
+    /// [`get_operation`](Self::get_operation) is the response's `operation_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn update_config_async(
+        &mut self,
+        config: GatewayConfig,
+    ) -> Result<UpdateConfigResponse, Status> {
+        Ok(self
+            .client
+            .update_config(UpdateConfigRequest {
+                config: Some(config),
+                expected_generation: None,
+                owner: self.owner.clone(),
+                confirm_timeout_seconds: 0,
+                async_apply: true,
+            })
+            .await?
+            .into_inner())
+    }
+
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails, `NotFound` for an unknown operation.
+    pub async fn get_operation(&mut self, id: &str) -> Result<ApplyOperation, Status> {
+        Ok(self
+            .client
+            .get_operation(GetOperationRequest { id: id.to_string() })
+            .await?
+            .into_inner())
+    }
+
+    /// Wait on the server side until the operation is done or `timeout_seconds` expire.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails, `NotFound` for an unknown operation.
+    pub async fn wait_operation(
+        &mut self,
+        id: &str,
+        timeout_seconds: u32,
+    ) -> Result<ApplyOperation, Status> {
+        Ok(self
+            .client
+            .wait_operation(WaitOperationRequest {
+                id: id.to_string(),
+                timeout_seconds,
+            })
+            .await?
+            .into_inner())
+    }
+
+    /// Poll the operation every `interval` until it is done or `timeout` expires, for
+    /// servers where long `WaitOperation` calls would be cut off.
+    ///
+    /// Returns the last state seen, still running if the timeout expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if a call fails.
+    pub async fn poll_operation(
+        &mut self,
+        id: &str,
+        interval: Duration,
+        timeout: Duration,
+    ) -> Result<ApplyOperation, Status> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let operation = self.get_operation(id).await?;
+            if operation.state() == OperationState::Done || Instant::now() >= deadline {
+                return Ok(operation);
+            }
+            tokio::time::sleep(interval.min(deadline.saturating_duration_since(Instant::now())))
+                .await;
+        }
+    }
+
+    /// Change the running config with `modify` and send it back with the next generation,
+    /// starting over when another writer got in between, at most `attempts` times.
+    ///
+    /// Returns the response to the last attempt, a `GenerationConflict` error if all of
+    /// them conflicted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if a call fails.
+    pub async fn read_modify_write(
+        &mut self,
+        attempts: usize,
+        mut modify: impl FnMut(&mut GatewayConfig),
+    ) -> Result<UpdateConfigResponse, Status> {
+        let mut attempt = 1;
+        loop {
+            let mut config = self.get_config().await?;
+            let expected = config.generation;
+            modify(&mut config);
+            config.generation = expected + 1;
+            let response = self.update_config_if(config, expected).await?;
+            if response.error() != Error::GenerationConflict || attempt >= attempts {
+                return Ok(response);
+            }
+            attempt += 1;
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn get_status(&mut self) -> Result<GetStatusResponse, Status> {
+        Ok(self
+            .client
+            .get_status(GetStatusRequest {})
+            .await?
+            .into_inner())
+    }
+
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn get_counters(&mut self) -> Result<GetCountersResponse, Status> {
+        Ok(self
+            .client
+            .get_counters(GetCountersRequest {})
+            .await?
+            .into_inner())
+    }
+
+    /// Change log levels without touching the config, the verdict is in the returned response.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn set_log_level(
+        &mut self,
+        request: SetLogLevelRequest,
+    ) -> Result<SetLogLevelResponse, Status> {
+        Ok(self.client.set_log_level(request).await?.into_inner())
+    }
+
+    /// Take or renew the config lock for `lease_seconds`, the verdict and current holder
+    /// are in the returned response.
+    ///
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn acquire_config_lock(
+        &mut self,
+        lease_seconds: u32,
+    ) -> Result<AcquireConfigLockResponse, Status> {
+        Ok(self
+            .client
+            .acquire_config_lock(AcquireConfigLockRequest {
+                owner: self.owner.clone(),
+                lease_seconds,
+            })
+            .await?
+            .into_inner())
+    }
+
+    /// # Errors
+    ///
+    /// Returns the gRPC status if the call fails.
+    pub async fn release_config_lock(&mut self) -> Result<ReleaseConfigLockResponse, Status> {
+        Ok(self
+            .client
+            .release_config_lock(ReleaseConfigLockRequest {
+                owner: self.owner.clone(),
+            })
+            .await?
+            .into_inner())
+    }
+}
@@ -0,0 +1,388 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::fmt;
+use std::sync::Arc;
+
+use futures::future::join_all;
+use tonic::{Request, Status};
+
+use crate::builder::{ExposeBuilder, GatewayConfigBuilder, InterfaceBuilder};
+use crate::client::GatewayClient;
+use crate::config::{
+    Error, GatewayConfig, GetConfigGenerationRequest, GetConfigRequest, UpdateConfigRequest,
+    UpdateConfigResponse, config_service_server::ConfigService,
+};
+use crate::normalize::semantic_eq;
+
+/// Number of updates sent at once by the concurrent update case.
+const CONCURRENT_UPDATES: u32 = 8;
+
+/// Why a conformance case failed.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ConformanceError {
+    #[error("RPC failed: {0}")]
+    Rpc(#[from] Status),
+    #[error("Valid config of generation {0} rejected with {1:?}: {2}")]
+    Rejected(i64, Error, String),
+    #[error("Invalid config accepted, expected {0:?}")]
+    Accepted(Error),
+    #[error("Expected generation {0}, got {1}")]
+    GenerationMismatch(i64, i64),
+    #[error("GetConfig does not return the config of generation {0}")]
+    ConfigMismatch(i64),
+    #[error("Running config changed by a rejected update")]
+    ConfigChanged,
+    #[error("No concurrent update was accepted")]
+    NoneAccepted,
+    #[error("Running config after concurrent updates is none of the accepted ones")]
+    TornUpdate,
+}
+
+/// Something the conformance suite can talk to, either a connected client or an
+/// in-process [`ConfigService`]. Clones must reach the same service.
+#[tonic::async_trait]
+pub trait ConformanceTarget: Clone + Send + Sync {
+    async fn get_config(&mut self) -> Result<GatewayConfig, Status>;
+    async fn get_generation(&mut self) -> Result<i64, Status>;
+    async fn update_config(
+        &mut self,
+        config: GatewayConfig,
+    ) -> Result<UpdateConfigResponse, Status>;
+}
+
+#[tonic::async_trait]
+impl ConformanceTarget for GatewayClient {
+    async fn get_config(&mut self) -> Result<GatewayConfig, Status> {
+        GatewayClient::get_config(self).await
+    }
+
+    async fn get_generation(&mut self) -> Result<i64, Status> {
+        GatewayClient::get_generation(self).await
+    }
+
+    async fn update_config(
+        &mut self,
+        config: GatewayConfig,
+    ) -> Result<UpdateConfigResponse, Status> {
+        GatewayClient::update_config(self, config).await
+    }
+}
+
+#[tonic::async_trait]
+impl<S: ConfigService> ConformanceTarget for Arc<S> {
+    async fn get_config(&mut self) -> Result<GatewayConfig, Status> {
+        Ok(
+            ConfigService::get_config(&**self, Request::new(GetConfigRequest {}))
+                .await?
+                .into_inner(),
+        )
+    }
+
+    async fn get_generation(&mut self) -> Result<i64, Status> {
+        Ok(self
+            .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+            .await?
+            .into_inner()
+            .generation)
+    }
+
+    async fn update_config(
+        &mut self,
+        config: GatewayConfig,
+    ) -> Result<UpdateConfigResponse, Status> {
+        Ok(ConfigService::update_config(
+            &**self,
+            Request::new(UpdateConfigRequest {
+                config: Some(config),
+                expected_generation: None,
+                owner: String::new(),
+                confirm_timeout_seconds: 0,
+                async_apply: false,
+            }),
+        )
+        .await?
+        .into_inner())
+    }
+}
+
+/// Outcome of one conformance case.
+#[derive(Debug, Clone)]
+pub struct CaseResult {
+    pub case: &'static str,
+    pub result: Result<(), ConformanceError>,
+}
+
+/// Outcome of every case of [`run_conformance`], in the order they ran.
+#[derive(Debug, Clone)]
+pub struct ConformanceReport {
+    pub cases: Vec<CaseResult>,
+}
+
+impl ConformanceReport {
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.cases.iter().all(|case| case.result.is_ok())
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &CaseResult> {
+        self.cases.iter().filter(|case| case.result.is_err())
+    }
+}
+
+impl fmt::Display for ConformanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for case in &self.cases {
+            match &case.result {
+                Ok(()) => writeln!(f, "PASS {}", case.case)?,
+                Err(e) => writeln!(f, "FAIL {}: {e}", case.case)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A small valid config, `vni` and `vni + 1` are used by its two VPCs.
+fn valid_config(generation: i64, vni: u32) -> GatewayConfig {
+    GatewayConfigBuilder::new()
+        .generation(generation)
+        .vrf("default")
+        .interface(InterfaceBuilder::ethernet("eth0").address("10.0.0.2/31"))
+        .interface(InterfaceBuilder::vtep("vtep").address("10.1.0.1/32"))
+        .bgp("65000", "10.1.0.1")
+        .neighbor("10.0.0.3", "65001")
+        .vpc("vpc-1", vni)
+        .vpc("vpc-2", vni + 1)
+        .peering("vpc-1", "vpc-2")
+        .expose("vpc-1", ExposeBuilder::new().ip("10.10.0.0/24"))
+        .expose("vpc-2", ExposeBuilder::new().ip("10.20.0.0/24"))
+        .build()
+        .expect("conformance config is valid")
+}
+
+/// A config that every implementation must reject, two VPCs share a VNI.
+fn invalid_config(generation: i64) -> GatewayConfig {
+    let mut config = valid_config(generation, 100);
+    if let Some(overlay) = config.overlay.as_mut() {
+        overlay.vpcs[1].vni = overlay.vpcs[0].vni;
+    }
+    config
+}
+
+async fn apply<T: ConformanceTarget>(
+    target: &mut T,
+    config: GatewayConfig,
+) -> Result<(), ConformanceError> {
+    let generation = config.generation;
+    let response = target.update_config(config).await?;
+    match response.error() {
+        Error::None => Ok(()),
+        error => Err(ConformanceError::Rejected(
+            generation,
+            error,
+            response.message,
+        )),
+    }
+}
+
+async fn check_running<T: ConformanceTarget>(
+    target: &mut T,
+    config: &GatewayConfig,
+) -> Result<(), ConformanceError> {
+    let generation = target.get_generation().await?;
+    if generation != config.generation {
+        return Err(ConformanceError::GenerationMismatch(
+            config.generation,
+            generation,
+        ));
+    }
+    let running = target.get_config().await?;
+    if running.generation != config.generation || !semantic_eq(&running, config) {
+        return Err(ConformanceError::ConfigMismatch(config.generation));
+    }
+    Ok(())
+}
+
+/// An accepted config is returned by `GetConfig`.
+async fn update_round_trip<T: ConformanceTarget>(
+    target: &mut T,
+    generation: i64,
+) -> Result<(), ConformanceError> {
+    let config = valid_config(generation, 100);
+    apply(target, config.clone()).await?;
+    check_running(target, &config).await
+}
+
+/// `GetConfigGeneration` follows every accepted update.
+async fn generation_follows_updates<T: ConformanceTarget>(
+    target: &mut T,
+    generation: i64,
+) -> Result<(), ConformanceError> {
+    for (generation, vni) in [(generation, 200), (generation + 1, 300)] {
+        apply(target, valid_config(generation, vni)).await?;
+        let running = target.get_generation().await?;
+        if running != generation {
+            return Err(ConformanceError::GenerationMismatch(generation, running));
+        }
+    }
+    Ok(())
+}
+
+/// An invalid config is rejected with `ValidationFailed` and changes nothing.
+async fn invalid_config_rejected<T: ConformanceTarget>(
+    target: &mut T,
+    generation: i64,
+) -> Result<(), ConformanceError> {
+    let before = target.get_config().await?;
+    let response = target.update_config(invalid_config(generation)).await?;
+    if response.error() != Error::ValidationFailed {
+        return Err(ConformanceError::Accepted(Error::ValidationFailed));
+    }
+    let running = target.get_generation().await?;
+    if running != before.generation {
+        return Err(ConformanceError::GenerationMismatch(
+            before.generation,
+            running,
+        ));
+    }
+    if target.get_config().await? != before {
+        return Err(ConformanceError::ConfigChanged);
+    }
+    Ok(())
+}
+
+/// Updates sent at once are applied atomically, one of them wins as a whole.
+async fn concurrent_updates<T: ConformanceTarget>(
+    target: &mut T,
+    generation: i64,
+) -> Result<(), ConformanceError> {
+    let configs = (0..CONCURRENT_UPDATES)
+        .map(|i| valid_config(generation + i64::from(i), 1000 + i * 10))
+        .collect::<Vec<_>>();
+    let responses = join_all(configs.iter().map(|config| {
+        let mut target = target.clone();
+        let config = config.clone();
+        async move { target.update_config(config).await }
+    }))
+    .await;
+    let mut accepted = vec![];
+    for (config, response) in configs.iter().zip(responses) {
+        if response?.error() == Error::None {
+            accepted.push(config);
+        }
+    }
+    if accepted.is_empty() {
+        return Err(ConformanceError::NoneAccepted);
+    }
+    let running = target.get_config().await?;
+    let Some(winner) = accepted
+        .into_iter()
+        .find(|config| config.generation == running.generation && semantic_eq(config, &running))
+    else {
+        return Err(ConformanceError::TornUpdate);
+    };
+    check_running(target, winner).await
+}
+
+/// Run every conformance case against `target`, which is left running one of the suite's
+/// configs. Generations used are above the one running when the suite starts.
+pub async fn run_conformance<T: ConformanceTarget>(mut target: T) -> ConformanceReport {
+    let base = match target.get_generation().await {
+        Ok(generation) => generation,
+        Err(status) => {
+            return ConformanceReport {
+                cases: vec![CaseResult {
+                    case: "get_generation",
+                    result: Err(status.into()),
+                }],
+            };
+        }
+    };
+    let cases = vec![
+        CaseResult {
+            case: "update_round_trip",
+            result: update_round_trip(&mut target, base + 1).await,
+        },
+        CaseResult {
+            case: "generation_follows_updates",
+            result: generation_follows_updates(&mut target, base + 10).await,
+        },
+        CaseResult {
+            case: "invalid_config_rejected",
+            result: invalid_config_rejected(&mut target, base + 20).await,
+        },
+        CaseResult {
+            case: "concurrent_updates",
+            result: concurrent_updates(&mut target, base + 30).await,
+        },
+    ];
+    ConformanceReport { cases }
+}
+
+/// Conformance targets misbehaving on purpose, for tests of the checks themselves.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::sync::Arc;
+
+    use tonic::Status;
+
+    use super::ConformanceTarget;
+    use crate::config::{Error, GatewayConfig, UpdateConfigResponse};
+    use crate::server::{FakeConfigService, update_response};
+
+    /// Acknowledges every update without applying it.
+    #[derive(Clone)]
+    pub(crate) struct Forgetful(pub Arc<FakeConfigService>);
+
+    #[tonic::async_trait]
+    impl ConformanceTarget for Forgetful {
+        async fn get_config(&mut self) -> Result<GatewayConfig, Status> {
+            self.0.get_config().await
+        }
+
+        async fn get_generation(&mut self) -> Result<i64, Status> {
+            self.0.get_generation().await
+        }
+
+        async fn update_config(
+            &mut self,
+            _config: GatewayConfig,
+        ) -> Result<UpdateConfigResponse, Status> {
+            Ok(update_response(Error::None, ""))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::test_support::Forgetful;
+    use super::*;
+    use crate::server::FakeConfigService;
+
+    #[tokio::test]
+    async fn test_fake_server_conforms() {
+        let report = run_conformance(Arc::new(FakeConfigService::new())).await;
+        assert!(report.passed(), "{report}");
+        assert_eq!(report.cases.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_report_failures() {
+        let report = run_conformance(Forgetful(Arc::new(FakeConfigService::new()))).await;
+        let failures = report.failures().map(|case| case.case).collect::<Vec<_>>();
+        assert_eq!(
+            failures,
+            [
+                "update_round_trip",
+                "generation_follows_updates",
+                "invalid_config_rejected",
+                "concurrent_updates",
+            ]
+        );
+        assert!(
+            report
+                .to_string()
+                .contains("FAIL update_round_trip: Expected generation 1, got 0")
+        );
+    }
+}
@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::collections::BTreeMap;
+use std::ops::{Add, AddAssign};
+use std::time::Duration;
+
+use thiserror::Error;
+
+use crate::config::{
+    ExposeCounters, GetCountersResponse, PeeringCounters, TrafficCounters, VpcCounters,
+};
+
+impl Add for TrafficCounters {
+    type Output = TrafficCounters;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        TrafficCounters {
+            packets: self.packets.saturating_add(rhs.packets),
+            bytes: self.bytes.saturating_add(rhs.bytes),
+        }
+    }
+}
+
+impl AddAssign for TrafficCounters {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl PeeringCounters {
+    /// Sum of the received traffic over all exposes of the peering.
+    #[must_use]
+    pub fn rx_total(&self) -> TrafficCounters {
+        self.exposes
+            .iter()
+            .filter_map(|expose| expose.rx)
+            .fold(TrafficCounters::default(), Add::add)
+    }
+
+    /// Sum of the transmitted traffic over all exposes of the peering.
+    #[must_use]
+    pub fn tx_total(&self) -> TrafficCounters {
+        self.exposes
+            .iter()
+            .filter_map(|expose| expose.tx)
+            .fold(TrafficCounters::default(), Add::add)
+    }
+
+    /// Sum of the active stateful NAT sessions over all exposes of the peering.
+    #[must_use]
+    pub fn nat_sessions_total(&self) -> u64 {
+        self.exposes.iter().map(|expose| expose.nat_sessions).sum()
+    }
+
+    /// Counters of the `index`th expose of `vpc`'s side of the peering.
+    #[must_use]
+    pub fn expose(&self, vpc: &str, index: u32) -> Option<&ExposeCounters> {
+        self.exposes
+            .iter()
+            .find(|expose| expose.vpc == vpc && expose.index == index)
+    }
+}
+
+impl GetCountersResponse {
+    #[must_use]
+    pub fn vpc(&self, name: &str) -> Option<&VpcCounters> {
+        self.vpcs.iter().find(|vpc| vpc.vpc == name)
+    }
+
+    #[must_use]
+    pub fn peering(&self, name: &str) -> Option<&PeeringCounters> {
+        self.peerings.iter().find(|peering| peering.name == name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CounterRateError {
+    #[error("Counter samples are not in chronological order: {0} ms, then {1} ms")]
+    NotChronological(u64, u64),
+}
+
+/// Average rates for one direction between two samples.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TrafficRates {
+    pub packets_per_second: f64,
+    pub bytes_per_second: f64,
+}
+
+/// Average rates in both directions between two samples.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct DirectionalRates {
+    pub rx: TrafficRates,
+    pub tx: TrafficRates,
+}
+
+/// Rates of every VPC and peering present in both samples, keyed by name.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CounterRates {
+    pub interval: Duration,
+    pub vpcs: BTreeMap<String, DirectionalRates>,
+    pub peerings: BTreeMap<String, DirectionalRates>,
+}
+
+/// Counter increase between samples, a decrease means the counter was reset in between.
+fn delta(prev: u64, cur: u64) -> u64 {
+    if cur >= prev { cur - prev } else { cur }
+}
+
+/// Average rates between two samples of the same counters.
+#[must_use]
+pub fn traffic_rates(
+    prev: TrafficCounters,
+    cur: TrafficCounters,
+    interval: Duration,
+) -> TrafficRates {
+    let seconds = interval.as_secs_f64();
+    if seconds == 0.0 {
+        return TrafficRates::default();
+    }
+    // Precision loss only matters beyond 2^53 packets or bytes per interval
+    #[allow(clippy::cast_precision_loss)]
+    TrafficRates {
+        packets_per_second: delta(prev.packets, cur.packets) as f64 / seconds,
+        bytes_per_second: delta(prev.bytes, cur.bytes) as f64 / seconds,
+    }
+}
+
+fn directional_rates(
+    (prev_rx, prev_tx): (TrafficCounters, TrafficCounters),
+    (cur_rx, cur_tx): (TrafficCounters, TrafficCounters),
+    interval: Duration,
+) -> DirectionalRates {
+    DirectionalRates {
+        rx: traffic_rates(prev_rx, cur_rx, interval),
+        tx: traffic_rates(prev_tx, cur_tx, interval),
+    }
+}
+
+/// Average rates per VPC and per peering between two counter samples.
+///
+/// VPCs and peerings that appear in only one of the samples are left out.
+///
+/// # Errors
+///
+/// Returns an error if `cur` was not sampled after `prev`.
+pub fn counter_rates(
+    prev: &GetCountersResponse,
+    cur: &GetCountersResponse,
+) -> Result<CounterRates, CounterRateError> {
+    if cur.timestamp_ms <= prev.timestamp_ms {
+        return Err(CounterRateError::NotChronological(
+            prev.timestamp_ms,
+            cur.timestamp_ms,
+        ));
+    }
+    let interval = Duration::from_millis(cur.timestamp_ms - prev.timestamp_ms);
+    let vpc_counters = |vpc: &VpcCounters| (vpc.rx.unwrap_or_default(), vpc.tx.unwrap_or_default());
+    let peering_counters = |peering: &PeeringCounters| (peering.rx_total(), peering.tx_total());
+
+    Ok(CounterRates {
+        interval,
+        vpcs: cur
+            .vpcs
+            .iter()
+            .filter_map(|vpc| {
+                let prev = prev.vpc(&vpc.vpc)?;
+                Some((
+                    vpc.vpc.clone(),
+                    directional_rates(vpc_counters(prev), vpc_counters(vpc), interval),
+                ))
+            })
+            .collect(),
+        peerings: cur
+            .peerings
+            .iter()
+            .filter_map(|peering| {
+                let prev = prev.peering(&peering.name)?;
+                Some((
+                    peering.name.clone(),
+                    directional_rates(peering_counters(prev), peering_counters(peering), interval),
+                ))
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn traffic(packets: u64, bytes: u64) -> TrafficCounters {
+        TrafficCounters { packets, bytes }
+    }
+
+    fn sample(timestamp_ms: u64, scale: u64) -> GetCountersResponse {
+        GetCountersResponse {
+            generation: 1,
+            timestamp_ms,
+            vpcs: vec![VpcCounters {
+                vpc: "vpc-1".to_string(),
+                rx: Some(traffic(10 * scale, 1000 * scale)),
+                tx: Some(traffic(20 * scale, 2000 * scale)),
+                nat_sessions: scale,
+            }],
+            peerings: vec![PeeringCounters {
+                name: "vpc-1--vpc-2".to_string(),
+                exposes: vec![
+                    ExposeCounters {
+                        vpc: "vpc-1".to_string(),
+                        index: 0,
+                        rx: Some(traffic(scale, 100 * scale)),
+                        tx: Some(traffic(scale, 100 * scale)),
+                        nat_sessions: 2,
+                    },
+                    ExposeCounters {
+                        vpc: "vpc-2".to_string(),
+                        index: 0,
+                        rx: Some(traffic(3 * scale, 300 * scale)),
+                        tx: None,
+                        nat_sessions: 1,
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_peering_totals() {
+        let counters = sample(0, 1);
+        let peering = counters.peering("vpc-1--vpc-2").unwrap();
+        assert_eq!(
+            peering.rx_total(),
+            TrafficCounters {
+                packets: 4,
+                bytes: 400
+            }
+        );
+        assert_eq!(
+            peering.tx_total(),
+            TrafficCounters {
+                packets: 1,
+                bytes: 100
+            }
+        );
+        assert_eq!(peering.nat_sessions_total(), 3);
+        assert!(peering.expose("vpc-2", 0).is_some());
+        assert!(peering.expose("vpc-2", 1).is_none());
+    }
+
+    #[test]
+    fn test_counter_rates() {
+        let rates = counter_rates(&sample(1_000, 1), &sample(3_000, 3)).unwrap();
+        assert_eq!(rates.interval, Duration::from_secs(2));
+        let vpc = rates.vpcs["vpc-1"];
+        assert!((vpc.rx.packets_per_second - 10.0).abs() < f64::EPSILON);
+        assert!((vpc.tx.bytes_per_second - 2000.0).abs() < f64::EPSILON);
+        let peering = rates.peerings["vpc-1--vpc-2"];
+        assert!((peering.rx.packets_per_second - 4.0).abs() < f64::EPSILON);
+
+        // A counter reset counts everything since the reset
+        let rates = counter_rates(&sample(1_000, 5), &sample(2_000, 1)).unwrap();
+        assert!((rates.vpcs["vpc-1"].rx.packets_per_second - 10.0).abs() < f64::EPSILON);
+
+        let mut other = sample(2_000, 1);
+        other.vpcs[0].vpc = "vpc-3".to_string();
+        assert!(
+            counter_rates(&sample(1_000, 1), &other)
+                .unwrap()
+                .vpcs
+                .is_empty()
+        );
+
+        assert_eq!(
+            counter_rates(&sample(2_000, 1), &sample(2_000, 1)),
+            Err(CounterRateError::NotChronological(2_000, 2_000))
+        );
+    }
+}
@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::collections::{BTreeSet, HashSet};
+
+use crate::config::{Eal, HugePageSize};
+use crate::device::PciAddress;
+use crate::validation::ValidationError;
+
+/// Largest lcore id accepted in a CPU list, matches the largest `RTE_MAX_LCORE` DPDK builds with.
+pub const MAX_LCORE: u32 = 1023;
+
+/// Parse a DPDK CPU list such as `0-3,8,10-11` into the set of lcore ids.
+///
+/// # Errors
+///
+/// Returns an error if the list is empty, contains something other than comma separated
+/// ids and inclusive ranges, has a descending range or an id above [`MAX_LCORE`].
+pub fn parse_cpu_list(list: &str) -> Result<BTreeSet<u32>, ValidationError> {
+    let err = |reason: &str| ValidationError::InvalidCpuList(list.to_string(), reason.to_string());
+    let parse_id = |id: &str| {
+        let id = id
+            .parse::<u32>()
+            .map_err(|_| err(&format!("invalid lcore id {id:?}")))?;
+        if id > MAX_LCORE {
+            return Err(err(&format!("lcore id {id} above {MAX_LCORE}")));
+        }
+        Ok(id)
+    };
+
+    if list.is_empty() {
+        return Err(err("empty list"));
+    }
+    let mut lcores = BTreeSet::new();
+    for item in list.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_id(start)?, parse_id(end)?);
+                if start > end {
+                    return Err(err(&format!("descending range {item}")));
+                }
+                lcores.extend(start..=end);
+            }
+            None => {
+                lcores.insert(parse_id(item)?);
+            }
+        }
+    }
+    Ok(lcores)
+}
+
+/// Size in megabytes of a single huge page.
+#[must_use]
+pub fn hugepage_size_mb(size: HugePageSize) -> u64 {
+    match size {
+        HugePageSize::Hugepage2m => 2,
+        HugePageSize::Hugepage1g => 1024,
+    }
+}
+
+pub(crate) fn check_eal(eal: &Eal, errors: &mut Vec<ValidationError>) {
+    let lcores = if eal.lcores.is_empty() {
+        None
+    } else {
+        match parse_cpu_list(&eal.lcores) {
+            Ok(lcores) => Some(lcores),
+            Err(e) => {
+                errors.push(e);
+                None
+            }
+        }
+    };
+    match (eal.main_lcore, &lcores) {
+        (Some(main), Some(lcores)) if !lcores.contains(&main) => {
+            errors.push(ValidationError::MainLcoreNotInList(
+                main,
+                eal.lcores.clone(),
+            ));
+        }
+        // EAL only takes a main lcore out of an explicit lcore list
+        (Some(main), None) if eal.lcores.is_empty() => {
+            errors.push(ValidationError::MainLcoreWithoutLcores(main));
+        }
+        _ => {}
+    }
+    if eal.memory_channels == Some(0) {
+        errors.push(ValidationError::ZeroMemoryChannels);
+    }
+    if HugePageSize::try_from(eal.hugepage_size).is_err() {
+        errors.push(ValidationError::UnknownEnumValue(
+            "HugePageSize",
+            eal.hugepage_size,
+        ));
+    }
+    let mut seen = HashSet::new();
+    for pci in &eal.pci_allow {
+        match pci.parse::<PciAddress>() {
+            Ok(addr) => {
+                if !seen.insert(addr) {
+                    errors.push(ValidationError::DuplicatePciAddress(pci.clone()));
+                }
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+}
+
+/// Check the EAL options for consistency.
+///
+/// # Errors
+///
+/// Returns every violation found: a malformed lcore list, a main lcore outside the lcore
+/// list or without one, zero memory channels, an unknown huge page size or a malformed or repeated PCI address.
+pub fn validate_eal(eal: &Eal) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    check_eal(eal, &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Render the EAL options as the argument vector for `rte_eal_init`.
+///
+/// `program` is used as `argv[0]`. Options that are not set are left out so that
+/// EAL falls back to its defaults, `extra_args` are appended last and unchanged.
+///
+/// # Errors
+///
+/// Returns the validation errors if the options do not pass [`validate_eal`].
+pub fn eal_argv(program: &str, eal: &Eal) -> Result<Vec<String>, Vec<ValidationError>> {
+    validate_eal(eal)?;
+
+    let mut argv = vec![program.to_string()];
+    if !eal.lcores.is_empty() {
+        argv.extend(["-l".to_string(), eal.lcores.clone()]);
+    }
+    if let Some(main) = eal.main_lcore {
+        argv.extend(["--main-lcore".to_string(), main.to_string()]);
+    }
+    if let Some(channels) = eal.memory_channels {
+        argv.extend(["-n".to_string(), channels.to_string()]);
+    }
+    if eal.hugepages > 0 {
+        let memory_mb = u64::from(eal.hugepages) * hugepage_size_mb(eal.hugepage_size());
+        argv.extend(["-m".to_string(), memory_mb.to_string()]);
+    }
+    for pci in &eal.pci_allow {
+        // Validated above, so parsing cannot fail
+        if let Ok(addr) = pci.parse::<PciAddress>() {
+            argv.extend(["-a".to_string(), addr.to_string()]);
+        }
+    }
+    argv.extend(eal.extra_args.iter().cloned());
+    Ok(argv)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(
+            parse_cpu_list("0-3,8,10-11")
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 8, 10, 11]
+        );
+        assert_eq!(parse_cpu_list("2,2,1-2").unwrap().len(), 2);
+        for bad in [
+            "", ",", "1,", "3-1", "a", "1-", "-1", "0 1", "1-2-3", "1024",
+        ] {
+            assert!(parse_cpu_list(bad).is_err(), "{bad:?} parsed");
+        }
+    }
+
+    #[test]
+    fn test_validate_eal() {
+        let eal = Eal {
+            lcores: "0-3".to_string(),
+            main_lcore: Some(4),
+            memory_channels: Some(0),
+            hugepage_size: 7,
+            hugepages: 0,
+            pci_allow: vec!["01:00.0".to_string(), "0000:01:00.0".to_string()],
+            extra_args: vec![],
+        };
+        assert_eq!(
+            validate_eal(&eal).unwrap_err(),
+            vec![
+                ValidationError::MainLcoreNotInList(4, "0-3".to_string()),
+                ValidationError::ZeroMemoryChannels,
+                ValidationError::UnknownEnumValue("HugePageSize", 7),
+                ValidationError::DuplicatePciAddress("0000:01:00.0".to_string()),
+            ]
+        );
+        assert!(validate_eal(&Eal::default()).is_ok());
+
+        let eal = Eal {
+            main_lcore: Some(0),
+            ..Eal::default()
+        };
+        assert_eq!(
+            validate_eal(&eal).unwrap_err(),
+            vec![ValidationError::MainLcoreWithoutLcores(0)]
+        );
+        assert!(eal_argv("dataplane", &eal).is_err());
+    }
+
+    #[test]
+    fn test_eal_argv() {
+        let eal = Eal {
+            lcores: "2-5".to_string(),
+            main_lcore: Some(2),
+            memory_channels: Some(4),
+            hugepage_size: HugePageSize::Hugepage1g.into(),
+            hugepages: 2,
+            pci_allow: vec!["3b:00.0".to_string()],
+            extra_args: vec!["--in-memory".to_string()],
+        };
+        assert_eq!(
+            eal_argv("dataplane", &eal).unwrap(),
+            vec![
+                "dataplane",
+                "-l",
+                "2-5",
+                "--main-lcore",
+                "2",
+                "-n",
+                "4",
+                "-m",
+                "2048",
+                "-a",
+                "0000:3b:00.0",
+                "--in-memory",
+            ]
+        );
+        assert_eq!(eal_argv("dp", &Eal::default()).unwrap(), vec!["dp"]);
+        assert!(
+            eal_argv(
+                "dp",
+                &Eal {
+                    lcores: "x".to_string(),
+                    ..Eal::default()
+                }
+            )
+            .is_err()
+        );
+    }
+}
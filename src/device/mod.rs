@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+mod eal;
+mod pci;
+mod ports;
+
+pub use eal::*;
+pub use pci::*;
+pub(crate) use ports::check_ports;
+
+use crate::config::Device;
+use crate::validation::ValidationError;
+
+pub(crate) fn check_device(device: &Device, errors: &mut Vec<ValidationError>) {
+    if let Some(eal) = &device.eal {
+        check_eal(eal, errors);
+    }
+}
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::validation::ValidationError;
+
+/// A PCI address in domain:bus:device.function (BDF) notation.
+///
+/// Parsing accepts both the full form (`0000:01:00.0`) and the short form
+/// without a domain (`01:00.0`), `Display` always renders the full lower-case form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PciAddress {
+    pub domain: u16,
+    pub bus: u8,
+    pub device: u8,
+    pub function: u8,
+}
+
+impl FromStr for PciAddress {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ValidationError::InvalidPciAddress(s.to_string());
+        let hex_u8 = |part: &str| {
+            if part.len() == 2 {
+                u8::from_str_radix(part, 16).map_err(|_| err())
+            } else {
+                Err(err())
+            }
+        };
+
+        let (bdf, function) = s.rsplit_once('.').ok_or_else(err)?;
+        let parts: Vec<&str> = bdf.split(':').collect();
+        let (domain, bus, device) = match parts.as_slice() {
+            [domain, bus, device] if domain.len() == 4 => (
+                u16::from_str_radix(domain, 16).map_err(|_| err())?,
+                hex_u8(bus)?,
+                hex_u8(device)?,
+            ),
+            [bus, device] => (0, hex_u8(bus)?, hex_u8(device)?),
+            _ => return Err(err()),
+        };
+        if device > 0x1f || function.len() != 1 {
+            return Err(err());
+        }
+        let function = u8::from_str_radix(function, 8).map_err(|_| err())?;
+
+        Ok(PciAddress {
+            domain,
+            bus,
+            device,
+            function,
+        })
+    }
+}
+
+impl fmt::Display for PciAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04x}:{:02x}:{:02x}.{}",
+            self.domain, self.bus, self.device, self.function
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PciAddress;
+
+    #[test]
+    fn test_parse_pci_address() {
+        let addr = "0000:3b:1f.7".parse::<PciAddress>().unwrap();
+        assert_eq!(
+            addr,
+            PciAddress {
+                domain: 0,
+                bus: 0x3b,
+                device: 0x1f,
+                function: 7
+            }
+        );
+        assert_eq!(addr.to_string(), "0000:3b:1f.7");
+        assert_eq!(
+            "01:00.1".parse::<PciAddress>().unwrap().to_string(),
+            "0000:01:00.1"
+        );
+        assert_eq!(
+            "00AB:CD:0A.0".parse::<PciAddress>().unwrap().to_string(),
+            "00ab:cd:0a.0"
+        );
+
+        for bad in [
+            "",
+            "0000:01:00",
+            "0000:01:20.0",
+            "0000:01:00.8",
+            "000:01:00.0",
+            "0000:1:00.0",
+            "0000:01:00:00.0",
+            "zz:00.0",
+        ] {
+            assert!(bad.parse::<PciAddress>().is_err(), "{bad} parsed");
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::collections::HashSet;
+
+use crate::config::{GatewayConfig, IfType, PacketDriver, Ports};
+use crate::device::PciAddress;
+use crate::validation::{ValidationError, all_interfaces};
+
+fn check_ring_size(port: &Ports, size: Option<u32>, errors: &mut Vec<ValidationError>) {
+    if let Some(size) = size {
+        if !size.is_power_of_two() {
+            errors.push(ValidationError::InvalidRingSize(port.name.clone(), size));
+        }
+    }
+}
+
+pub(crate) fn check_ports(config: &GatewayConfig, errors: &mut Vec<ValidationError>) {
+    let Some(device) = &config.device else {
+        return;
+    };
+    let dpdk = device.driver == i32::from(PacketDriver::Dpdk);
+    let allowed = device
+        .eal
+        .iter()
+        .flat_map(|eal| &eal.pci_allow)
+        .filter_map(|pci| pci.parse::<PciAddress>().ok())
+        .collect::<HashSet<_>>();
+    let interfaces = all_interfaces(config)
+        .map(|iface| iface.name.as_str())
+        .collect::<HashSet<_>>();
+
+    let mut names = HashSet::new();
+    let mut addrs = HashSet::new();
+    let mut bound = HashSet::new();
+    for port in &device.ports {
+        if !names.insert(&port.name) {
+            errors.push(ValidationError::DuplicatePortName(port.name.clone()));
+        }
+        match &port.pci_address {
+            Some(pci) => match pci.parse::<PciAddress>() {
+                Ok(addr) => {
+                    if !addrs.insert(addr) {
+                        errors.push(ValidationError::DuplicatePciAddress(pci.clone()));
+                    }
+                    if dpdk && !allowed.is_empty() && !allowed.contains(&addr) {
+                        errors.push(ValidationError::PortNotInPciAllowList(
+                            port.name.clone(),
+                            pci.clone(),
+                        ));
+                    }
+                }
+                Err(e) => errors.push(e),
+            },
+            None if dpdk => errors.push(ValidationError::MissingPciAddress(port.name.clone())),
+            None => {}
+        }
+        if port.rx_queues == Some(0) || port.tx_queues == Some(0) {
+            errors.push(ValidationError::ZeroQueues(port.name.clone()));
+        }
+        check_ring_size(port, port.rx_descriptors, errors);
+        check_ring_size(port, port.tx_descriptors, errors);
+        if let Some(iface) = &port.interface {
+            if !interfaces.contains(iface.as_str()) {
+                errors.push(ValidationError::PortInterfaceNotFound(
+                    port.name.clone(),
+                    iface.clone(),
+                ));
+            }
+            if !bound.insert(iface) {
+                errors.push(ValidationError::InterfaceOnMultiplePorts(iface.clone()));
+            }
+        }
+    }
+
+    if dpdk {
+        for iface in all_interfaces(config) {
+            if iface.r#type == i32::from(IfType::Ethernet) && bound.insert(&iface.name) {
+                errors.push(ValidationError::InterfaceWithoutPort(iface.name.clone()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::{Device, Eal, Interface, Underlay, Vrf};
+
+    fn config(driver: PacketDriver, ports: Vec<Ports>) -> GatewayConfig {
+        let iface = |name: &str, r#type: IfType| Interface {
+            name: name.to_string(),
+            r#type: r#type.into(),
+            ..Interface::default()
+        };
+        GatewayConfig {
+            device: Some(Device {
+                driver: driver.into(),
+                eal: Some(Eal {
+                    pci_allow: vec!["0000:01:00.0".to_string(), "0000:01:00.1".to_string()],
+                    ..Eal::default()
+                }),
+                ports,
+                ..Device::default()
+            }),
+            underlay: Some(Underlay {
+                vrfs: vec![Vrf {
+                    name: "default".to_string(),
+                    interfaces: vec![
+                        iface("eth0", IfType::Ethernet),
+                        iface("eth1", IfType::Ethernet),
+                        iface("lo", IfType::Loopback),
+                    ],
+                    ..Vrf::default()
+                }],
+            }),
+            ..GatewayConfig::default()
+        }
+    }
+
+    fn port(name: &str, pci: &str, iface: &str) -> Ports {
+        Ports {
+            name: name.to_string(),
+            pci_address: Some(pci.to_string()),
+            rx_queues: Some(4),
+            rx_descriptors: Some(1024),
+            interface: Some(iface.to_string()),
+            ..Ports::default()
+        }
+    }
+
+    fn errors(config: &GatewayConfig) -> Vec<ValidationError> {
+        let mut errors = vec![];
+        check_ports(config, &mut errors);
+        errors
+    }
+
+    #[test]
+    fn test_dpdk_ports() {
+        let ports = vec![
+            port("p0", "01:00.0", "eth0"),
+            port("p1", "0000:01:00.1", "eth1"),
+        ];
+        assert_eq!(errors(&config(PacketDriver::Dpdk, ports.clone())), vec![]);
+
+        assert_eq!(
+            errors(&config(PacketDriver::Dpdk, ports[..1].to_vec())),
+            vec![ValidationError::InterfaceWithoutPort("eth1".to_string())]
+        );
+        assert_eq!(errors(&config(PacketDriver::Kernel, vec![])), vec![]);
+    }
+
+    #[test]
+    fn test_bad_ports() {
+        let mut ports = vec![
+            port("p0", "0000:01:00.0", "eth0"),
+            port("p0", "0000:02:00.0", "eth9"),
+            Ports {
+                name: "p2".to_string(),
+                tx_queues: Some(0),
+                tx_descriptors: Some(1000),
+                interface: Some("eth0".to_string()),
+                ..Ports::default()
+            },
+        ];
+        assert_eq!(
+            errors(&config(PacketDriver::Dpdk, ports.clone())),
+            vec![
+                ValidationError::DuplicatePortName("p0".to_string()),
+                ValidationError::PortNotInPciAllowList(
+                    "p0".to_string(),
+                    "0000:02:00.0".to_string()
+                ),
+                ValidationError::PortInterfaceNotFound("p0".to_string(), "eth9".to_string()),
+                ValidationError::MissingPciAddress("p2".to_string()),
+                ValidationError::ZeroQueues("p2".to_string()),
+                ValidationError::InvalidRingSize("p2".to_string(), 1000),
+                ValidationError::InterfaceOnMultiplePorts("eth0".to_string()),
+                ValidationError::InterfaceWithoutPort("eth1".to_string()),
+            ]
+        );
+
+        ports.truncate(1);
+        ports[0].pci_address = Some("01:00".to_string());
+        assert_eq!(
+            errors(&config(PacketDriver::Kernel, ports)),
+            vec![ValidationError::InvalidPciAddress("01:00".to_string())]
+        );
+    }
+}
@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::collections::HashSet;
+use std::net::IpAddr;
+
+use crate::config::{External, GatewayConfig, IfRole, Interface};
+use crate::validation::ValidationError;
+
+/// Names of the underlay VRFs designated as external by some attachment.
+#[must_use]
+pub fn external_vrfs(external: &External) -> HashSet<&str> {
+    external
+        .attachments
+        .iter()
+        .map(|attachment| attachment.vrf.as_str())
+        .collect()
+}
+
+fn is_external(iface: &Interface) -> bool {
+    iface.role == i32::from(IfRole::External)
+}
+
+pub(crate) fn check_external(config: &GatewayConfig, errors: &mut Vec<ValidationError>) {
+    let default = External::default();
+    let external = config.external.as_ref().unwrap_or(&default);
+    let designated = external_vrfs(external);
+    let vrfs = config
+        .underlay
+        .iter()
+        .flat_map(|underlay| &underlay.vrfs)
+        .collect::<Vec<_>>();
+    let vpcs = config
+        .overlay
+        .iter()
+        .flat_map(|overlay| &overlay.vpcs)
+        .collect::<Vec<_>>();
+
+    let mut names = HashSet::new();
+    for attachment in &external.attachments {
+        if !names.insert(&attachment.name) {
+            errors.push(ValidationError::DuplicateExternalAttachment(
+                attachment.name.clone(),
+            ));
+        }
+        match vrfs.iter().find(|vrf| vrf.name == attachment.vrf) {
+            Some(vrf) => {
+                if !vrf.interfaces.iter().any(is_external) {
+                    errors.push(ValidationError::ExternalVrfWithoutInterface(
+                        attachment.vrf.clone(),
+                    ));
+                }
+            }
+            None => errors.push(ValidationError::UnknownExternalVrf(
+                attachment.name.clone(),
+                attachment.vrf.clone(),
+            )),
+        }
+        for upstream in &attachment.upstreams {
+            if upstream.address.parse::<IpAddr>().is_err() {
+                errors.push(ValidationError::InvalidIpAddress(upstream.address.clone()));
+            }
+        }
+        for vpc in &attachment.vpcs {
+            if !vpcs.iter().any(|v| &v.name == vpc) {
+                errors.push(ValidationError::UnknownVpc(
+                    attachment.name.clone(),
+                    vpc.clone(),
+                ));
+            }
+        }
+    }
+
+    for vrf in &vrfs {
+        if designated.contains(vrf.name.as_str()) {
+            continue;
+        }
+        for iface in vrf.interfaces.iter().filter(|iface| is_external(iface)) {
+            errors.push(ValidationError::ExternalInterfaceOutsideExternalVrf(
+                iface.name.clone(),
+                vrf.name.clone(),
+            ));
+        }
+    }
+    for vpc in &vpcs {
+        for iface in vpc.interfaces.iter().filter(|iface| is_external(iface)) {
+            errors.push(ValidationError::ExternalInterfaceOutsideExternalVrf(
+                iface.name.clone(),
+                vpc.name.clone(),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::{BgpNeighbor, ExternalAttachment, IfType, Overlay, Underlay, Vpc, Vrf};
+
+    fn iface(name: &str, role: IfRole) -> Interface {
+        Interface {
+            name: name.to_string(),
+            r#type: IfType::Ethernet.into(),
+            role: role.into(),
+            ..Interface::default()
+        }
+    }
+
+    fn config(attachments: Vec<ExternalAttachment>) -> GatewayConfig {
+        GatewayConfig {
+            underlay: Some(Underlay {
+                vrfs: vec![
+                    Vrf {
+                        name: "default".to_string(),
+                        interfaces: vec![iface("eth0", IfRole::Fabric)],
+                        ..Vrf::default()
+                    },
+                    Vrf {
+                        name: "internet".to_string(),
+                        interfaces: vec![iface("eth1", IfRole::External)],
+                        ..Vrf::default()
+                    },
+                ],
+            }),
+            overlay: Some(Overlay {
+                vpcs: vec![Vpc {
+                    name: "vpc-1".to_string(),
+                    vni: 1,
+                    interfaces: vec![iface("vpc1", IfRole::Fabric)],
+                    ..Vpc::default()
+                }],
+                peerings: vec![],
+            }),
+            external: Some(External { attachments }),
+            ..GatewayConfig::default()
+        }
+    }
+
+    fn attachment(vrf: &str, vpcs: &[&str]) -> ExternalAttachment {
+        ExternalAttachment {
+            name: "inet".to_string(),
+            vrf: vrf.to_string(),
+            upstreams: vec![BgpNeighbor {
+                address: "192.0.2.1".to_string(),
+                remote_asn: "65001".to_string(),
+                ..BgpNeighbor::default()
+            }],
+            vpcs: vpcs.iter().map(ToString::to_string).collect(),
+            inject_default_route: true,
+        }
+    }
+
+    fn errors(config: &GatewayConfig) -> Vec<ValidationError> {
+        let mut errors = vec![];
+        check_external(config, &mut errors);
+        errors
+    }
+
+    #[test]
+    fn test_external() {
+        assert_eq!(
+            errors(&config(vec![attachment("internet", &["vpc-1"])])),
+            vec![]
+        );
+        assert_eq!(
+            errors(&config(vec![])),
+            vec![ValidationError::ExternalInterfaceOutsideExternalVrf(
+                "eth1".to_string(),
+                "internet".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_bad_external() {
+        let mut bad = attachment("default", &["vpc-2"]);
+        bad.upstreams[0].address = "upstream".to_string();
+        let mut config = config(vec![
+            attachment("internet", &[]),
+            bad,
+            attachment("missing", &[]),
+        ]);
+        if let Some(overlay) = config.overlay.as_mut() {
+            overlay.vpcs[0].interfaces[0].role = IfRole::External.into();
+        }
+        assert_eq!(
+            errors(&config),
+            vec![
+                ValidationError::DuplicateExternalAttachment("inet".to_string()),
+                ValidationError::ExternalVrfWithoutInterface("default".to_string()),
+                ValidationError::InvalidIpAddress("upstream".to_string()),
+                ValidationError::UnknownVpc("inet".to_string(), "vpc-2".to_string()),
+                ValidationError::DuplicateExternalAttachment("inet".to_string()),
+                ValidationError::UnknownExternalVrf("inet".to_string(), "missing".to_string()),
+                ValidationError::ExternalInterfaceOutsideExternalVrf(
+                    "vpc1".to_string(),
+                    "vpc-1".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -10,6 +10,18 @@ pub struct GetConfigRequest {}
 pub struct UpdateConfigRequest {
     #[prost(message, optional, tag = "1")]
     pub config: ::core::option::Option<GatewayConfig>,
+    /// if set, only apply if the running generation is still this one
+    #[prost(int64, optional, tag = "2")]
+    pub expected_generation: ::core::option::Option<i64>,
+    /// identity of the writer, must hold the config lock if there is one
+    #[prost(string, tag = "3")]
+    pub owner: ::prost::alloc::string::String,
+    /// if not zero, reverted unless confirmed with ConfirmConfig within this time
+    #[prost(uint32, tag = "4")]
+    pub confirm_timeout_seconds: u32,
+    /// if set, return at once with an operation id, the verdict is in the operation
+    #[prost(bool, tag = "5")]
+    pub async_apply: bool,
 }
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -18,15 +30,325 @@ pub struct UpdateConfigResponse {
     pub error: i32,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    /// set for async_apply updates, see GetOperation
+    #[prost(string, tag = "3")]
+    pub operation_id: ::prost::alloc::string::String,
+    /// what became of the device and each VRF, VPC and peering, notably on ERROR_APPLY_FAILED
+    #[prost(message, repeated, tag = "4")]
+    pub results: ::prost::alloc::vec::Vec<ObjectResult>,
+}
+/// Outcome of applying one config object, identified by kind and name
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ObjectResult {
+    #[prost(enumeration = "ObjectKind", tag = "1")]
+    pub kind: i32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "ApplyState", tag = "3")]
+    pub state: i32,
+    /// why the object failed or was skipped
+    #[prost(string, tag = "4")]
+    pub reason: ::prost::alloc::string::String,
 }
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct GetConfigGenerationRequest {}
 #[derive(::serde::Deserialize, ::serde::Serialize)]
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetConfigGenerationResponse {
     #[prost(int64, tag = "1")]
     pub generation: i64,
+    /// hex SHA-256 of the normalized config, generation excluded
+    #[prost(string, tag = "2")]
+    pub content_hash: ::prost::alloc::string::String,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetStatusRequest {}
+/// Packet and byte counters of an interface since dataplane start
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct InterfaceCounters {
+    #[prost(uint64, tag = "1")]
+    pub rx_packets: u64,
+    #[prost(uint64, tag = "2")]
+    pub rx_bytes: u64,
+    #[prost(uint64, tag = "3")]
+    pub rx_errors: u64,
+    #[prost(uint64, tag = "4")]
+    pub rx_drops: u64,
+    #[prost(uint64, tag = "5")]
+    pub tx_packets: u64,
+    #[prost(uint64, tag = "6")]
+    pub tx_bytes: u64,
+    #[prost(uint64, tag = "7")]
+    pub tx_errors: u64,
+    #[prost(uint64, tag = "8")]
+    pub tx_drops: u64,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct InterfaceStatus {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(enumeration = "OperState", tag = "2")]
+    pub oper_state: i32,
+    #[prost(message, optional, tag = "3")]
+    pub counters: ::core::option::Option<InterfaceCounters>,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BgpNeighborStatus {
+    #[prost(string, tag = "1")]
+    pub vrf: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(enumeration = "BgpSessionState", tag = "3")]
+    pub state: i32,
+    #[prost(uint64, tag = "4")]
+    pub prefixes_received: u64,
+    #[prost(uint64, tag = "5")]
+    pub prefixes_sent: u64,
+    /// time since the session was established, 0 if it is not
+    #[prost(uint64, tag = "6")]
+    pub established_seconds: u64,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VniStatus {
+    #[prost(uint32, tag = "1")]
+    pub vni: u32,
+    #[prost(string, tag = "2")]
+    pub vpc: ::prost::alloc::string::String,
+    #[prost(enumeration = "OperState", tag = "3")]
+    pub oper_state: i32,
+    /// addresses of the VTEPs learned for this VNI
+    #[prost(string, repeated, tag = "4")]
+    pub remote_vteps: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VtepStatus {
+    #[prost(string, tag = "1")]
+    pub address: ::prost::alloc::string::String,
+    #[prost(enumeration = "OperState", tag = "2")]
+    pub oper_state: i32,
+    #[prost(message, repeated, tag = "3")]
+    pub vnis: ::prost::alloc::vec::Vec<VniStatus>,
+}
+/// What the dataplane is actually running, as opposed to what is configured
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetStatusResponse {
+    /// generation of the currently applied config
+    #[prost(int64, tag = "1")]
+    pub generation: i64,
+    #[prost(message, repeated, tag = "2")]
+    pub interfaces: ::prost::alloc::vec::Vec<InterfaceStatus>,
+    #[prost(message, repeated, tag = "3")]
+    pub bgp_neighbors: ::prost::alloc::vec::Vec<BgpNeighborStatus>,
+    #[prost(message, optional, tag = "4")]
+    pub vtep: ::core::option::Option<VtepStatus>,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetCountersRequest {}
+/// Packet and byte counters for one direction since dataplane start
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TrafficCounters {
+    #[prost(uint64, tag = "1")]
+    pub packets: u64,
+    #[prost(uint64, tag = "2")]
+    pub bytes: u64,
+}
+/// Counters of a VPC, rx is traffic entering the VPC and tx traffic leaving it
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct VpcCounters {
+    #[prost(string, tag = "1")]
+    pub vpc: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub rx: ::core::option::Option<TrafficCounters>,
+    #[prost(message, optional, tag = "3")]
+    pub tx: ::core::option::Option<TrafficCounters>,
+    /// active stateful NAT sessions
+    #[prost(uint64, tag = "4")]
+    pub nat_sessions: u64,
+}
+/// Counters of one expose entry, from the point of view of the exposing VPC
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExposeCounters {
+    /// VPC of the PeeringEntryFor holding the expose
+    #[prost(string, tag = "1")]
+    pub vpc: ::prost::alloc::string::String,
+    /// position of the expose in that PeeringEntryFor
+    #[prost(uint32, tag = "2")]
+    pub index: u32,
+    #[prost(message, optional, tag = "3")]
+    pub rx: ::core::option::Option<TrafficCounters>,
+    #[prost(message, optional, tag = "4")]
+    pub tx: ::core::option::Option<TrafficCounters>,
+    /// active stateful NAT sessions
+    #[prost(uint64, tag = "5")]
+    pub nat_sessions: u64,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PeeringCounters {
+    /// VpcPeering name
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub exposes: ::prost::alloc::vec::Vec<ExposeCounters>,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCountersResponse {
+    /// generation of the currently applied config
+    #[prost(int64, tag = "1")]
+    pub generation: i64,
+    /// dataplane time of the sample, milliseconds since the Unix epoch
+    #[prost(uint64, tag = "2")]
+    pub timestamp_ms: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub vpcs: ::prost::alloc::vec::Vec<VpcCounters>,
+    #[prost(message, repeated, tag = "4")]
+    pub peerings: ::prost::alloc::vec::Vec<PeeringCounters>,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ComponentLogLevel {
+    #[prost(enumeration = "LogComponent", tag = "1")]
+    pub component: i32,
+    #[prost(enumeration = "LogLevel", tag = "2")]
+    pub level: i32,
+}
+/// Change log levels at runtime, without a config update or generation change
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLogLevelRequest {
+    /// for everything not listed in components, unchanged if unset
+    #[prost(enumeration = "LogLevel", optional, tag = "1")]
+    pub level: ::core::option::Option<i32>,
+    /// at most one entry per component
+    #[prost(message, repeated, tag = "2")]
+    pub components: ::prost::alloc::vec::Vec<ComponentLogLevel>,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetLogLevelResponse {
+    #[prost(enumeration = "Error", tag = "1")]
+    pub error: i32,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+/// Take or renew the exclusive right to update the config, until released or the lease expires
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcquireConfigLockRequest {
+    /// identity of the writer, not empty
+    #[prost(string, tag = "1")]
+    pub owner: ::prost::alloc::string::String,
+    /// not zero
+    #[prost(uint32, tag = "2")]
+    pub lease_seconds: u32,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AcquireConfigLockResponse {
+    #[prost(enumeration = "Error", tag = "1")]
+    pub error: i32,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    /// current holder of the lock, the requester if acquired
+    #[prost(string, tag = "3")]
+    pub owner: ::prost::alloc::string::String,
+    /// lease expiry of the current holder, ms since Unix epoch
+    #[prost(uint64, tag = "4")]
+    pub expires_at_ms: u64,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReleaseConfigLockRequest {
+    #[prost(string, tag = "1")]
+    pub owner: ::prost::alloc::string::String,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReleaseConfigLockResponse {
+    #[prost(enumeration = "Error", tag = "1")]
+    pub error: i32,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+/// Keep a config applied with a confirm timeout
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ConfirmConfigRequest {
+    /// generation of the config awaiting confirmation
+    #[prost(int64, tag = "1")]
+    pub generation: i64,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConfirmConfigResponse {
+    #[prost(enumeration = "Error", tag = "1")]
+    pub error: i32,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+/// Outcome of applying one config section, e.g. "device", "underlay" or "overlay"
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SectionResult {
+    #[prost(string, tag = "1")]
+    pub section: ::prost::alloc::string::String,
+    #[prost(enumeration = "Error", tag = "2")]
+    pub error: i32,
+    #[prost(string, tag = "3")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApplyOperation {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    /// generation of the config being applied
+    #[prost(int64, tag = "2")]
+    pub generation: i64,
+    #[prost(enumeration = "OperationState", tag = "3")]
+    pub state: i32,
+    #[prost(uint32, tag = "4")]
+    pub progress_percent: u32,
+    /// sections applied so far
+    #[prost(message, repeated, tag = "5")]
+    pub sections: ::prost::alloc::vec::Vec<SectionResult>,
+    /// final verdict, once done
+    #[prost(enumeration = "Error", tag = "6")]
+    pub error: i32,
+    #[prost(string, tag = "7")]
+    pub message: ::prost::alloc::string::String,
+    /// what became of each object, once done
+    #[prost(message, repeated, tag = "8")]
+    pub results: ::prost::alloc::vec::Vec<ObjectResult>,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetOperationRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+}
+/// Wait until the operation is done or the timeout expires, whichever comes first
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WaitOperationRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub timeout_seconds: u32,
 }
 /// OSPF Interface configuration
 #[derive(::serde::Deserialize, ::serde::Serialize)]
@@ -114,14 +436,70 @@ pub mod peering_as {
         Not(::prost::alloc::string::String),
     }
 }
+/// Inclusive range of L4 ports
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PortRange {
+    #[prost(uint32, tag = "1")]
+    pub start: u32,
+    #[prost(uint32, tag = "2")]
+    pub end: u32,
+}
+/// Addresses and ports stateful NAT allocates from
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct NatPool {
+    /// same address family as the exposed ips
+    #[prost(string, repeated, tag = "1")]
+    pub cidrs: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// source ports to allocate, dataplane default if unset
+    #[prost(message, optional, tag = "2")]
+    pub ports: ::core::option::Option<PortRange>,
+}
+/// Stateful (many-to-few, port translating) source NAT, a.k.a. masquerade
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct StatefulNat {
+    #[prost(message, optional, tag = "1")]
+    pub pool: ::core::option::Option<NatPool>,
+    /// seconds, dataplane default if unset
+    #[prost(uint32, optional, tag = "2")]
+    pub tcp_idle_timeout: ::core::option::Option<u32>,
+    /// seconds, dataplane default if unset
+    #[prost(uint32, optional, tag = "3")]
+    pub udp_idle_timeout: ::core::option::Option<u32>,
+    /// seconds, dataplane default if unset
+    #[prost(uint32, optional, tag = "4")]
+    pub icmp_idle_timeout: ::core::option::Option<u32>,
+}
+/// Restricts the traffic allowed through an expose
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct L4Filter {
+    #[prost(enumeration = "L4Protocol", tag = "1")]
+    pub protocol: i32,
+    /// destination ports, TCP and UDP only, empty for all
+    #[prost(message, repeated, tag = "2")]
+    pub ports: ::prost::alloc::vec::Vec<PortRange>,
+    /// ICMP and ICMPv6 only, empty for all
+    #[prost(uint32, repeated, tag = "3")]
+    pub icmp_types: ::prost::alloc::vec::Vec<u32>,
+}
 /// Defines a rule between exposing IP and translated
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Expose {
     #[prost(message, repeated, tag = "1")]
     pub ips: ::prost::alloc::vec::Vec<PeeringIPs>,
+    /// static prefix-to-prefix translation
     #[prost(message, repeated, tag = "2")]
     pub r#as: ::prost::alloc::vec::Vec<PeeringAs>,
+    /// stateful translation, excludes `as`
+    #[prost(message, optional, tag = "3")]
+    pub stateful_nat: ::core::option::Option<StatefulNat>,
+    /// traffic must match one of them, empty allows all
+    #[prost(message, repeated, tag = "4")]
+    pub filters: ::prost::alloc::vec::Vec<L4Filter>,
 }
 /// Defines a list of exposures per VPC
 #[derive(::serde::Deserialize, ::serde::Serialize)]
@@ -281,6 +659,33 @@ pub struct Underlay {
     #[prost(message, repeated, tag = "1")]
     pub vrfs: ::prost::alloc::vec::Vec<Vrf>,
 }
+/// Connectivity from VPCs to networks outside the fabric
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExternalAttachment {
+    /// unique
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// underlay VRF holding the external interfaces
+    #[prost(string, tag = "2")]
+    pub vrf: ::prost::alloc::string::String,
+    /// BGP sessions with upstream routers in that VRF
+    #[prost(message, repeated, tag = "3")]
+    pub upstreams: ::prost::alloc::vec::Vec<BgpNeighbor>,
+    /// names of the VPCs that may use this attachment
+    #[prost(string, repeated, tag = "4")]
+    pub vpcs: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// advertise a default route into those VPCs
+    #[prost(bool, tag = "5")]
+    pub inject_default_route: bool,
+}
+/// List of all external attachments
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct External {
+    #[prost(message, repeated, tag = "1")]
+    pub attachments: ::prost::alloc::vec::Vec<ExternalAttachment>,
+}
 /// Defines physical or system-level device
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -289,17 +694,52 @@ pub struct Ports {
     pub name: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub system_name: ::prost::alloc::string::String,
+    /// e.g. "0000:01:00.0", required for DPDK
+    #[prost(string, optional, tag = "3")]
+    pub pci_address: ::core::option::Option<::prost::alloc::string::String>,
+    /// kernel driver the device is bound to, e.g. "vfio-pci"
+    #[prost(string, optional, tag = "4")]
+    pub driver: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(uint32, optional, tag = "5")]
+    pub rx_queues: ::core::option::Option<u32>,
+    #[prost(uint32, optional, tag = "6")]
+    pub tx_queues: ::core::option::Option<u32>,
+    /// RX ring size, power of two
+    #[prost(uint32, optional, tag = "7")]
+    pub rx_descriptors: ::core::option::Option<u32>,
+    /// TX ring size, power of two
+    #[prost(uint32, optional, tag = "8")]
+    pub tx_descriptors: ::core::option::Option<u32>,
+    /// name of the Interface using this port
+    #[prost(string, optional, tag = "9")]
+    pub interface: ::core::option::Option<::prost::alloc::string::String>,
 }
 /// RTE EAL config options for DPDK
-///
-/// TODO:
-/// rte_eal params
-/// cpu list
-/// cpu affinities ...
-/// huge-pages
 #[derive(::serde::Deserialize, ::serde::Serialize)]
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct Eal {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Eal {
+    /// CPU list of lcores, e.g. "0-3,8" (-l)
+    #[prost(string, tag = "1")]
+    pub lcores: ::prost::alloc::string::String,
+    /// must be one of lcores (--main-lcore)
+    #[prost(uint32, optional, tag = "2")]
+    pub main_lcore: ::core::option::Option<u32>,
+    /// number of memory channels (-n)
+    #[prost(uint32, optional, tag = "3")]
+    pub memory_channels: ::core::option::Option<u32>,
+    /// size of the pre-reserved huge pages
+    #[prost(enumeration = "HugePageSize", tag = "4")]
+    pub hugepage_size: i32,
+    /// number of huge pages to use (-m), 0 for EAL default
+    #[prost(uint32, tag = "5")]
+    pub hugepages: u32,
+    /// PCI devices DPDK may probe, e.g. "0000:01:00.0" (-a)
+    #[prost(string, repeated, tag = "6")]
+    pub pci_allow: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// appended verbatim after all other args
+    #[prost(string, repeated, tag = "7")]
+    pub extra_args: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 /// System level config options
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -327,6 +767,80 @@ pub struct GatewayConfig {
     pub underlay: ::core::option::Option<Underlay>,
     #[prost(message, optional, tag = "4")]
     pub overlay: ::core::option::Option<Overlay>,
+    #[prost(message, optional, tag = "5")]
+    pub external: ::core::option::Option<External>,
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ObjectKind {
+    Unknown = 0,
+    Vrf = 1,
+    Vpc = 2,
+    Peering = 3,
+    /// named after the device hostname
+    Device = 4,
+}
+impl ObjectKind {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "OBJECT_KIND_UNKNOWN",
+            Self::Vrf => "OBJECT_KIND_VRF",
+            Self::Vpc => "OBJECT_KIND_VPC",
+            Self::Peering => "OBJECT_KIND_PEERING",
+            Self::Device => "OBJECT_KIND_DEVICE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OBJECT_KIND_UNKNOWN" => Some(Self::Unknown),
+            "OBJECT_KIND_VRF" => Some(Self::Vrf),
+            "OBJECT_KIND_VPC" => Some(Self::Vpc),
+            "OBJECT_KIND_PEERING" => Some(Self::Peering),
+            "OBJECT_KIND_DEVICE" => Some(Self::Device),
+            _ => None,
+        }
+    }
+}
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ApplyState {
+    Unknown = 0,
+    /// programmed in the dataplane
+    Applied = 1,
+    Failed = 2,
+    /// not attempted, e.g. because an earlier object failed
+    Skipped = 3,
+}
+impl ApplyState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unknown => "APPLY_STATE_UNKNOWN",
+            Self::Applied => "APPLY_STATE_APPLIED",
+            Self::Failed => "APPLY_STATE_FAILED",
+            Self::Skipped => "APPLY_STATE_SKIPPED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "APPLY_STATE_UNKNOWN" => Some(Self::Unknown),
+            "APPLY_STATE_APPLIED" => Some(Self::Applied),
+            "APPLY_STATE_FAILED" => Some(Self::Failed),
+            "APPLY_STATE_SKIPPED" => Some(Self::Skipped),
+            _ => None,
+        }
+    }
 }
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
@@ -336,6 +850,10 @@ pub enum Error {
     ValidationFailed = 1,
     ApplyFailed = 2,
     UnknownError = 3,
+    /// running generation is not the expected one
+    GenerationConflict = 4,
+    /// config is locked by another owner
+    Locked = 5,
 }
 impl Error {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -348,6 +866,8 @@ impl Error {
             Self::ValidationFailed => "ERROR_VALIDATION_FAILED",
             Self::ApplyFailed => "ERROR_APPLY_FAILED",
             Self::UnknownError => "ERROR_UNKNOWN_ERROR",
+            Self::GenerationConflict => "ERROR_GENERATION_CONFLICT",
+            Self::Locked => "ERROR_LOCKED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -357,57 +877,192 @@ impl Error {
             "ERROR_VALIDATION_FAILED" => Some(Self::ValidationFailed),
             "ERROR_APPLY_FAILED" => Some(Self::ApplyFailed),
             "ERROR_UNKNOWN_ERROR" => Some(Self::UnknownError),
+            "ERROR_GENERATION_CONFLICT" => Some(Self::GenerationConflict),
+            "ERROR_LOCKED" => Some(Self::Locked),
             _ => None,
         }
     }
 }
-/// OSPF Network Type
-#[cfg_attr(feature = "bolero", derive(::bolero::TypeGenerator))]
+/// Operational state of an interface, VTEP or VNI
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
-pub enum OspfNetworkType {
-    Broadcast = 0,
-    NonBroadcast = 1,
-    PointToPoint = 2,
-    PointToMultipoint = 3,
+pub enum OperState {
+    Unknown = 0,
+    Up = 1,
+    Down = 2,
 }
-impl OspfNetworkType {
+impl OperState {
     /// String value of the enum field names used in the ProtoBuf definition.
     ///
     /// The values are not transformed in any way and thus are considered stable
     /// (if the ProtoBuf definition does not change) and safe for programmatic use.
     pub fn as_str_name(&self) -> &'static str {
         match self {
-            Self::Broadcast => "BROADCAST",
-            Self::NonBroadcast => "NON_BROADCAST",
-            Self::PointToPoint => "POINT_TO_POINT",
-            Self::PointToMultipoint => "POINT_TO_MULTIPOINT",
+            Self::Unknown => "OPER_STATE_UNKNOWN",
+            Self::Up => "OPER_STATE_UP",
+            Self::Down => "OPER_STATE_DOWN",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
     pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
         match value {
-            "BROADCAST" => Some(Self::Broadcast),
-            "NON_BROADCAST" => Some(Self::NonBroadcast),
-            "POINT_TO_POINT" => Some(Self::PointToPoint),
-            "POINT_TO_MULTIPOINT" => Some(Self::PointToMultipoint),
+            "OPER_STATE_UNKNOWN" => Some(Self::Unknown),
+            "OPER_STATE_UP" => Some(Self::Up),
+            "OPER_STATE_DOWN" => Some(Self::Down),
             _ => None,
         }
     }
 }
-/// Defines interface representation on the Gateway
-#[cfg_attr(feature = "bolero", derive(::bolero::TypeGenerator))]
+/// BGP finite state machine states
 #[derive(::serde::Deserialize, ::serde::Serialize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
-pub enum IfType {
-    Ethernet = 0,
-    Vlan = 1,
-    Loopback = 2,
-    Vtep = 3,
+pub enum BgpSessionState {
+    Idle = 0,
+    Connect = 1,
+    Active = 2,
+    OpenSent = 3,
+    OpenConfirm = 4,
+    Established = 5,
 }
-impl IfType {
+impl BgpSessionState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Idle => "BGP_SESSION_STATE_IDLE",
+            Self::Connect => "BGP_SESSION_STATE_CONNECT",
+            Self::Active => "BGP_SESSION_STATE_ACTIVE",
+            Self::OpenSent => "BGP_SESSION_STATE_OPEN_SENT",
+            Self::OpenConfirm => "BGP_SESSION_STATE_OPEN_CONFIRM",
+            Self::Established => "BGP_SESSION_STATE_ESTABLISHED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BGP_SESSION_STATE_IDLE" => Some(Self::Idle),
+            "BGP_SESSION_STATE_CONNECT" => Some(Self::Connect),
+            "BGP_SESSION_STATE_ACTIVE" => Some(Self::Active),
+            "BGP_SESSION_STATE_OPEN_SENT" => Some(Self::OpenSent),
+            "BGP_SESSION_STATE_OPEN_CONFIRM" => Some(Self::OpenConfirm),
+            "BGP_SESSION_STATE_ESTABLISHED" => Some(Self::Established),
+            _ => None,
+        }
+    }
+}
+/// Dataplane components with separately adjustable log levels
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LogComponent {
+    Core = 0,
+    Dpdk = 1,
+    Nat = 2,
+    Routing = 3,
+}
+impl LogComponent {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Core => "LOG_COMPONENT_CORE",
+            Self::Dpdk => "LOG_COMPONENT_DPDK",
+            Self::Nat => "LOG_COMPONENT_NAT",
+            Self::Routing => "LOG_COMPONENT_ROUTING",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "LOG_COMPONENT_CORE" => Some(Self::Core),
+            "LOG_COMPONENT_DPDK" => Some(Self::Dpdk),
+            "LOG_COMPONENT_NAT" => Some(Self::Nat),
+            "LOG_COMPONENT_ROUTING" => Some(Self::Routing),
+            _ => None,
+        }
+    }
+}
+/// Progress of an UpdateConfig sent with async_apply
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OperationState {
+    Running = 0,
+    Done = 1,
+}
+impl OperationState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Running => "OPERATION_STATE_RUNNING",
+            Self::Done => "OPERATION_STATE_DONE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "OPERATION_STATE_RUNNING" => Some(Self::Running),
+            "OPERATION_STATE_DONE" => Some(Self::Done),
+            _ => None,
+        }
+    }
+}
+/// OSPF Network Type
+#[cfg_attr(feature = "bolero", derive(::bolero::TypeGenerator))]
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum OspfNetworkType {
+    Broadcast = 0,
+    NonBroadcast = 1,
+    PointToPoint = 2,
+    PointToMultipoint = 3,
+}
+impl OspfNetworkType {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Broadcast => "BROADCAST",
+            Self::NonBroadcast => "NON_BROADCAST",
+            Self::PointToPoint => "POINT_TO_POINT",
+            Self::PointToMultipoint => "POINT_TO_MULTIPOINT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BROADCAST" => Some(Self::Broadcast),
+            "NON_BROADCAST" => Some(Self::NonBroadcast),
+            "POINT_TO_POINT" => Some(Self::PointToPoint),
+            "POINT_TO_MULTIPOINT" => Some(Self::PointToMultipoint),
+            _ => None,
+        }
+    }
+}
+/// Defines interface representation on the Gateway
+#[cfg_attr(feature = "bolero", derive(::bolero::TypeGenerator))]
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum IfType {
+    Ethernet = 0,
+    Vlan = 1,
+    Loopback = 2,
+    Vtep = 3,
+}
+impl IfType {
     /// String value of the enum field names used in the ProtoBuf definition.
     ///
     /// The values are not transformed in any way and thus are considered stable
@@ -460,6 +1115,44 @@ impl IfRole {
         }
     }
 }
+/// L4 protocol matched by a filter
+#[cfg_attr(feature = "bolero", derive(::bolero::TypeGenerator))]
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum L4Protocol {
+    Any = 0,
+    Tcp = 1,
+    Udp = 2,
+    Icmp = 3,
+    Icmpv6 = 4,
+}
+impl L4Protocol {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Any => "L4_PROTOCOL_ANY",
+            Self::Tcp => "L4_PROTOCOL_TCP",
+            Self::Udp => "L4_PROTOCOL_UDP",
+            Self::Icmp => "L4_PROTOCOL_ICMP",
+            Self::Icmpv6 => "L4_PROTOCOL_ICMPV6",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "L4_PROTOCOL_ANY" => Some(Self::Any),
+            "L4_PROTOCOL_TCP" => Some(Self::Tcp),
+            "L4_PROTOCOL_UDP" => Some(Self::Udp),
+            "L4_PROTOCOL_ICMP" => Some(Self::Icmp),
+            "L4_PROTOCOL_ICMPV6" => Some(Self::Icmpv6),
+            _ => None,
+        }
+    }
+}
 /// AFIs supported for BGP peering
 #[cfg_attr(feature = "bolero", derive(::bolero::TypeGenerator))]
 #[derive(::serde::Deserialize, ::serde::Serialize)]
@@ -492,6 +1185,35 @@ impl BgpAf {
         }
     }
 }
+/// Huge page size backing DPDK memory
+#[cfg_attr(feature = "bolero", derive(::bolero::TypeGenerator))]
+#[derive(::serde::Deserialize, ::serde::Serialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum HugePageSize {
+    Hugepage2m = 0,
+    Hugepage1g = 1,
+}
+impl HugePageSize {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Hugepage2m => "HUGEPAGE_2M",
+            Self::Hugepage1g => "HUGEPAGE_1G",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "HUGEPAGE_2M" => Some(Self::Hugepage2m),
+            "HUGEPAGE_1G" => Some(Self::Hugepage1g),
+            _ => None,
+        }
+    }
+}
 /// Log-level for dataplane and DPDK
 #[cfg_attr(feature = "bolero", derive(::bolero::TypeGenerator))]
 #[derive(::serde::Deserialize, ::serde::Serialize)]
@@ -719,75 +1441,311 @@ pub mod config_service_client {
                 .insert(GrpcMethod::new("config.ConfigService", "UpdateConfig"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod config_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with ConfigServiceServer.
-    #[async_trait]
-    pub trait ConfigService: std::marker::Send + std::marker::Sync + 'static {
-        async fn get_config(
-            &self,
-            request: tonic::Request<super::GetConfigRequest>,
-        ) -> std::result::Result<tonic::Response<super::GatewayConfig>, tonic::Status>;
-        async fn get_config_generation(
-            &self,
-            request: tonic::Request<super::GetConfigGenerationRequest>,
+        pub async fn get_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStatusRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetConfigGenerationResponse>,
+            tonic::Response<super::GetStatusResponse>,
             tonic::Status,
-        >;
-        async fn update_config(
-            &self,
-            request: tonic::Request<super::UpdateConfigRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/GetStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "GetStatus"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_counters(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCountersRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::UpdateConfigResponse>,
+            tonic::Response<super::GetCountersResponse>,
             tonic::Status,
-        >;
-    }
-    #[derive(Debug)]
-    pub struct ConfigServiceServer<T> {
-        inner: Arc<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    impl<T> ConfigServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
-        }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/GetCounters",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "GetCounters"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
+        pub async fn set_log_level(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetLogLevelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetLogLevelResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/SetLogLevel",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "SetLogLevel"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn acquire_config_lock(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AcquireConfigLockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AcquireConfigLockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/AcquireConfigLock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "AcquireConfigLock"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn release_config_lock(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReleaseConfigLockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReleaseConfigLockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/ReleaseConfigLock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "ReleaseConfigLock"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn confirm_config(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ConfirmConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ConfirmConfigResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/ConfirmConfig",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "ConfirmConfig"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_operation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetOperationRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApplyOperation>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/GetOperation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "GetOperation"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn wait_operation(
+            &mut self,
+            request: impl tonic::IntoRequest<super::WaitOperationRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApplyOperation>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/config.ConfigService/WaitOperation",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("config.ConfigService", "WaitOperation"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod config_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with ConfigServiceServer.
+    #[async_trait]
+    pub trait ConfigService: std::marker::Send + std::marker::Sync + 'static {
+        async fn get_config(
+            &self,
+            request: tonic::Request<super::GetConfigRequest>,
+        ) -> std::result::Result<tonic::Response<super::GatewayConfig>, tonic::Status>;
+        async fn get_config_generation(
+            &self,
+            request: tonic::Request<super::GetConfigGenerationRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetConfigGenerationResponse>,
+            tonic::Status,
+        >;
+        async fn update_config(
+            &self,
+            request: tonic::Request<super::UpdateConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UpdateConfigResponse>,
+            tonic::Status,
+        >;
+        async fn get_status(
+            &self,
+            request: tonic::Request<super::GetStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStatusResponse>,
+            tonic::Status,
+        >;
+        async fn get_counters(
+            &self,
+            request: tonic::Request<super::GetCountersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCountersResponse>,
+            tonic::Status,
+        >;
+        async fn set_log_level(
+            &self,
+            request: tonic::Request<super::SetLogLevelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetLogLevelResponse>,
+            tonic::Status,
+        >;
+        async fn acquire_config_lock(
+            &self,
+            request: tonic::Request<super::AcquireConfigLockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AcquireConfigLockResponse>,
+            tonic::Status,
+        >;
+        async fn release_config_lock(
+            &self,
+            request: tonic::Request<super::ReleaseConfigLockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReleaseConfigLockResponse>,
+            tonic::Status,
+        >;
+        async fn confirm_config(
+            &self,
+            request: tonic::Request<super::ConfirmConfigRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ConfirmConfigResponse>,
+            tonic::Status,
+        >;
+        async fn get_operation(
+            &self,
+            request: tonic::Request<super::GetOperationRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApplyOperation>, tonic::Status>;
+        async fn wait_operation(
+            &self,
+            request: tonic::Request<super::WaitOperationRequest>,
+        ) -> std::result::Result<tonic::Response<super::ApplyOperation>, tonic::Status>;
+    }
+    #[derive(Debug)]
+    pub struct ConfigServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> ConfigServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
         }
         /// Compress responses with the given encoding, if the client supports it.
         #[must_use]
@@ -965,6 +1923,368 @@ pub mod config_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/config.ConfigService/GetStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStatusSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::GetStatusRequest>
+                    for GetStatusSvc<T> {
+                        type Response = super::GetStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::get_status(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/config.ConfigService/GetCounters" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCountersSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::GetCountersRequest>
+                    for GetCountersSvc<T> {
+                        type Response = super::GetCountersResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCountersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::get_counters(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCountersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/config.ConfigService/SetLogLevel" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetLogLevelSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::SetLogLevelRequest>
+                    for SetLogLevelSvc<T> {
+                        type Response = super::SetLogLevelResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetLogLevelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::set_log_level(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetLogLevelSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/config.ConfigService/AcquireConfigLock" => {
+                    #[allow(non_camel_case_types)]
+                    struct AcquireConfigLockSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::AcquireConfigLockRequest>
+                    for AcquireConfigLockSvc<T> {
+                        type Response = super::AcquireConfigLockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AcquireConfigLockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::acquire_config_lock(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AcquireConfigLockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/config.ConfigService/ReleaseConfigLock" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReleaseConfigLockSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::ReleaseConfigLockRequest>
+                    for ReleaseConfigLockSvc<T> {
+                        type Response = super::ReleaseConfigLockResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReleaseConfigLockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::release_config_lock(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReleaseConfigLockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/config.ConfigService/ConfirmConfig" => {
+                    #[allow(non_camel_case_types)]
+                    struct ConfirmConfigSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::ConfirmConfigRequest>
+                    for ConfirmConfigSvc<T> {
+                        type Response = super::ConfirmConfigResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ConfirmConfigRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::confirm_config(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ConfirmConfigSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/config.ConfigService/GetOperation" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetOperationSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::GetOperationRequest>
+                    for GetOperationSvc<T> {
+                        type Response = super::ApplyOperation;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetOperationRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::get_operation(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetOperationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/config.ConfigService/WaitOperation" => {
+                    #[allow(non_camel_case_types)]
+                    struct WaitOperationSvc<T: ConfigService>(pub Arc<T>);
+                    impl<
+                        T: ConfigService,
+                    > tonic::server::UnaryService<super::WaitOperationRequest>
+                    for WaitOperationSvc<T> {
+                        type Response = super::ApplyOperation;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::WaitOperationRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ConfigService>::wait_operation(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = WaitOperationSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(
@@ -11,11 +11,20 @@ pub mod config {
 // Note(manishv): This is incomplete and not needed really, remove?
 // See https://github.com/githedgehog/gateway-proto/issues/28
 pub use config::{
+    AcquireConfigLockRequest,
+    AcquireConfigLockResponse,
+    ApplyOperation,
+    ApplyState,
     BgpAddressFamilyIPv4,
     BgpAddressFamilyIPv6,
     BgpAddressFamilyL2vpnEvpn,
     BgpAf,
     BgpNeighbor,
+    BgpNeighborStatus,
+    BgpSessionState,
+    ComponentLogLevel,
+    ConfirmConfigRequest,
+    ConfirmConfigResponse,
     // Device related types
     Device,
     Eal,
@@ -23,16 +32,35 @@ pub use config::{
     Error,
 
     Expose,
+    ExposeCounters,
+    External,
+    ExternalAttachment,
     GatewayConfig,
     GetConfigGenerationRequest,
     GetConfigGenerationResponse,
     // Request/Response types
     GetConfigRequest,
+    GetCountersRequest,
+    GetCountersResponse,
+    GetOperationRequest,
+    GetStatusRequest,
+    GetStatusResponse,
+    HugePageSize,
     IfRole,
     IfType,
     // Interface related types
     Interface,
+    InterfaceCounters,
+    InterfaceStatus,
+    L4Filter,
+    L4Protocol,
+    LogComponent,
     LogLevel,
+    NatPool,
+    ObjectKind,
+    ObjectResult,
+    OperState,
+    OperationState,
     OspfConfig,
     OspfInterface,
 
@@ -41,20 +69,33 @@ pub use config::{
     PacketDriver,
 
     PeeringAs,
+    PeeringCounters,
     PeeringEntryFor,
     PeeringIPs,
+    PortRange,
     Ports,
+    ReleaseConfigLockRequest,
+    ReleaseConfigLockResponse,
     RouteMap,
 
     RouterConfig,
+    SectionResult,
+    SetLogLevelRequest,
+    SetLogLevelResponse,
+    StatefulNat,
+    TrafficCounters,
     // Underlay related types
     Underlay,
     UpdateConfigRequest,
     UpdateConfigResponse,
 
+    VniStatus,
     Vpc,
+    VpcCounters,
     VpcPeering,
     Vrf,
+    VtepStatus,
+    WaitOperationRequest,
     config_service_client::ConfigServiceClient,
 
     // Service definitions
@@ -68,5 +109,20 @@ pub fn get_proto_path() -> std::path::PathBuf {
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+pub mod apply;
 #[cfg(feature = "bolero")]
 pub mod bolero;
+pub mod builder;
+pub mod client;
+pub mod conformance;
+pub mod counters;
+pub mod device;
+pub mod external;
+pub mod logging;
+pub mod normalize;
+pub mod overlay;
+pub mod prefix;
+pub mod proxy;
+pub mod server;
+pub mod status;
+pub mod validation;
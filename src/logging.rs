@@ -0,0 +1,238 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::collections::BTreeMap;
+
+use thiserror::Error;
+
+use crate::config::{LogComponent, LogLevel, SetLogLevelRequest};
+
+/// A problem found in a [`SetLogLevelRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LogLevelError {
+    #[error("Unknown value for enum {0}: {1}")]
+    UnknownEnumValue(&'static str, i32),
+    #[error("Log level set more than once for component {0}")]
+    DuplicateComponent(String),
+}
+
+impl LogLevel {
+    /// Level name as used in `tracing` filter directives.
+    #[must_use]
+    pub fn as_directive(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warning => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+impl LogComponent {
+    /// `tracing` target the component logs under.
+    #[must_use]
+    pub fn target(&self) -> &'static str {
+        match self {
+            LogComponent::Core => "dataplane",
+            LogComponent::Dpdk => "dpdk",
+            LogComponent::Nat => "nat",
+            LogComponent::Routing => "routing",
+        }
+    }
+}
+
+/// Log levels currently in effect: one for everything plus per component overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLevels {
+    pub level: LogLevel,
+    pub components: BTreeMap<LogComponent, LogLevel>,
+}
+
+impl From<LogLevel> for LogLevels {
+    fn from(level: LogLevel) -> Self {
+        Self {
+            level,
+            components: BTreeMap::new(),
+        }
+    }
+}
+
+/// Levels of a request with the enum values decoded
+struct RequestLevels {
+    level: Option<LogLevel>,
+    components: BTreeMap<LogComponent, LogLevel>,
+}
+
+/// Decode the levels of a request, checking enum values and that no component is repeated.
+fn request_levels(request: &SetLogLevelRequest) -> Result<RequestLevels, Vec<LogLevelError>> {
+    let mut errors = vec![];
+    let level = request.level.and_then(|level| {
+        LogLevel::try_from(level)
+            .map_err(|_| errors.push(LogLevelError::UnknownEnumValue("LogLevel", level)))
+            .ok()
+    });
+    let mut components = BTreeMap::new();
+    for entry in &request.components {
+        let Ok(component) = LogComponent::try_from(entry.component) else {
+            errors.push(LogLevelError::UnknownEnumValue(
+                "LogComponent",
+                entry.component,
+            ));
+            continue;
+        };
+        let Ok(level) = LogLevel::try_from(entry.level) else {
+            errors.push(LogLevelError::UnknownEnumValue("LogLevel", entry.level));
+            continue;
+        };
+        if components.insert(component, level).is_some() {
+            errors.push(LogLevelError::DuplicateComponent(
+                component.as_str_name().to_string(),
+            ));
+        }
+    }
+    if errors.is_empty() {
+        Ok(RequestLevels { level, components })
+    } else {
+        Err(errors)
+    }
+}
+
+impl LogLevels {
+    /// Apply a [`SetLogLevelRequest`]: the request's level replaces the overall level and
+    /// its component levels are merged into the existing overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns the problems found in the request, nothing is changed in that case.
+    pub fn apply(&mut self, request: &SetLogLevelRequest) -> Result<(), Vec<LogLevelError>> {
+        let RequestLevels { level, components } = request_levels(request)?;
+        if let Some(level) = level {
+            self.level = level;
+        }
+        self.components.extend(components);
+        Ok(())
+    }
+
+    /// Filter directives in the syntax of `tracing_subscriber::EnvFilter`, e.g. `info,nat=debug`.
+    #[must_use]
+    pub fn directives(&self) -> String {
+        std::iter::once(self.level.as_directive().to_string())
+            .chain(self.components.iter().map(|(component, level)| {
+                format!("{}={}", component.target(), level.as_directive())
+            }))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// Filter directives for a [`SetLogLevelRequest`] on its own, without any previous state.
+///
+/// Without an overall level in the request only the component directives are returned.
+///
+/// # Errors
+///
+/// Returns the problems found in the request.
+pub fn filter_directives(request: &SetLogLevelRequest) -> Result<String, Vec<LogLevelError>> {
+    let RequestLevels { level, components } = request_levels(request)?;
+    Ok(level
+        .map(|level| level.as_directive().to_string())
+        .into_iter()
+        .chain(
+            components.iter().map(|(component, level)| {
+                format!("{}={}", component.target(), level.as_directive())
+            }),
+        )
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::ComponentLogLevel;
+
+    fn component(component: LogComponent, level: LogLevel) -> ComponentLogLevel {
+        ComponentLogLevel {
+            component: component.into(),
+            level: level.into(),
+        }
+    }
+
+    #[test]
+    fn test_filter_directives() {
+        let request = SetLogLevelRequest {
+            level: Some(LogLevel::Warning.into()),
+            components: vec![
+                component(LogComponent::Routing, LogLevel::Trace),
+                component(LogComponent::Dpdk, LogLevel::Error),
+            ],
+        };
+        assert_eq!(
+            filter_directives(&request).unwrap(),
+            "warn,dpdk=error,routing=trace"
+        );
+        assert_eq!(
+            filter_directives(&SetLogLevelRequest {
+                level: None,
+                components: vec![component(LogComponent::Nat, LogLevel::Debug)],
+            })
+            .unwrap(),
+            "nat=debug"
+        );
+
+        let bad = SetLogLevelRequest {
+            level: Some(9),
+            components: vec![
+                component(LogComponent::Nat, LogLevel::Debug),
+                component(LogComponent::Nat, LogLevel::Info),
+                ComponentLogLevel {
+                    component: 7,
+                    level: 0,
+                },
+            ],
+        };
+        assert_eq!(
+            filter_directives(&bad).unwrap_err(),
+            vec![
+                LogLevelError::UnknownEnumValue("LogLevel", 9),
+                LogLevelError::DuplicateComponent("LOG_COMPONENT_NAT".to_string()),
+                LogLevelError::UnknownEnumValue("LogComponent", 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_log_levels_apply() {
+        let mut levels = LogLevels::from(LogLevel::Info);
+        assert_eq!(levels.directives(), "info");
+        levels
+            .apply(&SetLogLevelRequest {
+                level: None,
+                components: vec![component(LogComponent::Core, LogLevel::Debug)],
+            })
+            .unwrap();
+        levels
+            .apply(&SetLogLevelRequest {
+                level: Some(LogLevel::Error.into()),
+                components: vec![component(LogComponent::Nat, LogLevel::Trace)],
+            })
+            .unwrap();
+        assert_eq!(levels.directives(), "error,dataplane=debug,nat=trace");
+
+        let before = levels.clone();
+        assert!(
+            levels
+                .apply(&SetLogLevelRequest {
+                    level: Some(LogLevel::Trace.into()),
+                    components: vec![ComponentLogLevel {
+                        component: 42,
+                        level: 0,
+                    }],
+                })
+                .is_err()
+        );
+        assert_eq!(levels, before);
+    }
+}
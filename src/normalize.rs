@@ -0,0 +1,397 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::fmt::Write;
+use std::net::IpAddr;
+
+use prost::Message;
+use sha2::{Digest, Sha256};
+
+use crate::config::{
+    BgpNeighbor, Device, Expose, External, GatewayConfig, Interface, L4Filter, Overlay,
+    RouterConfig, Underlay, bgp_neighbor_update_source, peering_as, peering_i_ps,
+};
+use crate::device::PciAddress;
+use crate::prefix::Prefix;
+
+fn canonical_ip(s: &mut String) {
+    if let Ok(addr) = s.parse::<IpAddr>() {
+        *s = addr.to_string();
+    }
+}
+
+/// Interface address: canonical notation, host bits are significant and kept.
+fn canonical_address(s: &mut String) {
+    if let Ok(prefix) = s.parse::<Prefix>() {
+        *s = prefix.to_string();
+    }
+}
+
+/// Prefix: canonical notation with host bits cleared.
+fn canonical_prefix(s: &mut String) {
+    if let Ok(prefix) = s.parse::<Prefix>() {
+        *s = prefix.network().to_string();
+    }
+}
+
+/// MAC address: lower case, colon separated.
+fn canonical_mac(s: &mut String) {
+    let octets = s
+        .split([':', '-'])
+        .map(|octet| {
+            (octet.len() == 2)
+                .then(|| u8::from_str_radix(octet, 16).ok())
+                .flatten()
+        })
+        .collect::<Option<Vec<u8>>>();
+    if let Some(octets) = octets.filter(|octets| octets.len() == 6) {
+        *s = octets
+            .iter()
+            .map(|octet| format!("{octet:02x}"))
+            .collect::<Vec<_>>()
+            .join(":");
+    }
+}
+
+fn canonical_pci(s: &mut String) {
+    if let Ok(pci) = s.parse::<PciAddress>() {
+        *s = pci.to_string();
+    }
+}
+
+fn sort_dedup<T: Ord>(list: &mut Vec<T>) {
+    list.sort();
+    list.dedup();
+}
+
+/// Sort by key, then drop entries identical to their predecessor.
+fn sort_dedup_by_key<T: PartialEq, K: Ord>(list: &mut Vec<T>, key: impl FnMut(&T) -> K) {
+    list.sort_by_key(key);
+    list.dedup();
+}
+
+fn normalize_interface(iface: &mut Interface) {
+    iface.ipaddrs.iter_mut().for_each(canonical_address);
+    sort_dedup(&mut iface.ipaddrs);
+    if let Some(mac) = iface.macaddr.as_mut() {
+        canonical_mac(mac);
+    }
+}
+
+fn normalize_interfaces(interfaces: &mut Vec<Interface>) {
+    interfaces.iter_mut().for_each(normalize_interface);
+    sort_dedup_by_key(interfaces, |iface| iface.name.clone());
+}
+
+fn normalize_neighbor(neighbor: &mut BgpNeighbor) {
+    canonical_ip(&mut neighbor.address);
+    sort_dedup(&mut neighbor.af_activate);
+    if let Some(bgp_neighbor_update_source::Source::Address(addr)) = neighbor
+        .update_source
+        .as_mut()
+        .and_then(|source| source.source.as_mut())
+    {
+        canonical_ip(addr);
+    }
+}
+
+fn normalize_neighbors(neighbors: &mut Vec<BgpNeighbor>) {
+    neighbors.iter_mut().for_each(normalize_neighbor);
+    sort_dedup_by_key(neighbors, |neighbor| neighbor.address.clone());
+}
+
+fn normalize_router(router: &mut RouterConfig) {
+    canonical_ip(&mut router.router_id);
+    normalize_neighbors(&mut router.neighbors);
+    if let Some(ipv4) = router.ipv4_unicast.as_mut() {
+        ipv4.networks.iter_mut().for_each(canonical_prefix);
+        sort_dedup(&mut ipv4.networks);
+    }
+    if let Some(ipv6) = router.ipv6_unicast.as_mut() {
+        ipv6.networks.iter_mut().for_each(canonical_prefix);
+        sort_dedup(&mut ipv6.networks);
+    }
+    for route_map in &mut router.route_maps {
+        sort_dedup(&mut route_map.match_prefix_lists);
+    }
+    sort_dedup_by_key(&mut router.route_maps, |route_map| {
+        (route_map.name.clone(), route_map.sequence)
+    });
+}
+
+fn normalize_underlay(underlay: &mut Underlay) {
+    for vrf in &mut underlay.vrfs {
+        normalize_interfaces(&mut vrf.interfaces);
+        if let Some(router) = vrf.router.as_mut() {
+            normalize_router(router);
+        }
+        if let Some(ospf) = vrf.ospf.as_mut() {
+            canonical_ip(&mut ospf.router_id);
+        }
+    }
+    sort_dedup_by_key(&mut underlay.vrfs, |vrf| vrf.name.clone());
+}
+
+fn normalize_filter(filter: &mut L4Filter) {
+    sort_dedup_by_key(&mut filter.ports, |ports| (ports.start, ports.end));
+    sort_dedup(&mut filter.icmp_types);
+}
+
+fn normalize_expose(expose: &mut Expose) {
+    for ips in &mut expose.ips {
+        if let Some(peering_i_ps::Rule::Cidr(cidr) | peering_i_ps::Rule::Not(cidr)) =
+            ips.rule.as_mut()
+        {
+            canonical_prefix(cidr);
+        }
+    }
+    sort_dedup_by_key(&mut expose.ips, |ips| ips.rule.clone().map(ips_key));
+    for r#as in &mut expose.r#as {
+        if let Some(peering_as::Rule::Cidr(cidr) | peering_as::Rule::Not(cidr)) = r#as.rule.as_mut()
+        {
+            canonical_prefix(cidr);
+        }
+    }
+    sort_dedup_by_key(&mut expose.r#as, |r#as| r#as.rule.clone().map(as_key));
+    if let Some(pool) = expose
+        .stateful_nat
+        .as_mut()
+        .and_then(|nat| nat.pool.as_mut())
+    {
+        pool.cidrs.iter_mut().for_each(canonical_prefix);
+        sort_dedup(&mut pool.cidrs);
+    }
+    expose.filters.iter_mut().for_each(normalize_filter);
+    sort_dedup_by_key(&mut expose.filters, |filter| {
+        (
+            filter.protocol,
+            filter
+                .ports
+                .iter()
+                .map(|ports| (ports.start, ports.end))
+                .collect::<Vec<_>>(),
+            filter.icmp_types.clone(),
+        )
+    });
+}
+
+fn ips_key(rule: peering_i_ps::Rule) -> (bool, String) {
+    match rule {
+        peering_i_ps::Rule::Cidr(cidr) => (false, cidr),
+        peering_i_ps::Rule::Not(cidr) => (true, cidr),
+    }
+}
+
+fn as_key(rule: peering_as::Rule) -> (bool, String) {
+    match rule {
+        peering_as::Rule::Cidr(cidr) => (false, cidr),
+        peering_as::Rule::Not(cidr) => (true, cidr),
+    }
+}
+
+fn normalize_overlay(overlay: &mut Overlay) {
+    for vpc in &mut overlay.vpcs {
+        normalize_interfaces(&mut vpc.interfaces);
+    }
+    sort_dedup_by_key(&mut overlay.vpcs, |vpc| vpc.name.clone());
+    for peering in &mut overlay.peerings {
+        for entry in &mut peering.r#for {
+            entry.expose.iter_mut().for_each(normalize_expose);
+        }
+        peering.r#for.sort_by(|a, b| a.vpc.cmp(&b.vpc));
+    }
+    sort_dedup_by_key(&mut overlay.peerings, |peering| peering.name.clone());
+}
+
+fn normalize_external(external: &mut External) {
+    for attachment in &mut external.attachments {
+        normalize_neighbors(&mut attachment.upstreams);
+        sort_dedup(&mut attachment.vpcs);
+    }
+    sort_dedup_by_key(&mut external.attachments, |attachment| {
+        attachment.name.clone()
+    });
+}
+
+fn normalize_device(device: &mut Device) {
+    if let Some(eal) = device.eal.as_mut() {
+        eal.pci_allow.iter_mut().for_each(canonical_pci);
+        sort_dedup(&mut eal.pci_allow);
+    }
+    for port in &mut device.ports {
+        if let Some(pci) = port.pci_address.as_mut() {
+            canonical_pci(pci);
+        }
+    }
+    sort_dedup_by_key(&mut device.ports, |port| port.name.clone());
+}
+
+/// Bring a config into its canonical form, in place. Normalizing twice changes nothing.
+///
+/// Only what does not change the meaning of a config is rewritten: the order of keyed lists,
+/// the notation of addresses, prefixes, MACs and PCI addresses, and exact duplicates. Values
+/// that do not parse are left untouched, rejecting them is the job of [`validate_config`].
+/// The order of exposes is kept since counters refer to them by index.
+///
+/// [`validate_config`]: crate::validation::validate_config
+pub fn normalize(config: &mut GatewayConfig) {
+    if let Some(device) = config.device.as_mut() {
+        normalize_device(device);
+    }
+    if let Some(underlay) = config.underlay.as_mut() {
+        normalize_underlay(underlay);
+    }
+    if let Some(overlay) = config.overlay.as_mut() {
+        normalize_overlay(overlay);
+    }
+    if let Some(external) = config.external.as_mut() {
+        normalize_external(external);
+    }
+}
+
+/// A normalized copy of a config.
+#[must_use]
+pub fn normalized(config: &GatewayConfig) -> GatewayConfig {
+    let mut config = config.clone();
+    normalize(&mut config);
+    config
+}
+
+/// Whether two configs mean the same, i.e. are equal once normalized.
+///
+/// The generation is not part of the config contents and is ignored.
+#[must_use]
+pub fn semantic_eq(a: &GatewayConfig, b: &GatewayConfig) -> bool {
+    let (mut a, mut b) = (normalized(a), normalized(b));
+    a.generation = 0;
+    b.generation = 0;
+    a == b
+}
+
+/// Content hash of a config, suitable as an `ETag`: hex SHA-256 of the protobuf encoding of
+/// the normalized config with the generation cleared.
+///
+/// Configs that are [`semantic_eq`] have the same hash.
+#[must_use]
+pub fn content_hash(config: &GatewayConfig) -> String {
+    let mut config = normalized(config);
+    config.generation = 0;
+    Sha256::digest(config.encode_to_vec()).iter().fold(
+        String::with_capacity(64),
+        |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        },
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::{PeeringEntryFor, PeeringIPs, Vpc, VpcPeering, Vrf};
+
+    fn interface(name: &str, ipaddrs: &[&str], mac: &str) -> Interface {
+        Interface {
+            name: name.to_string(),
+            ipaddrs: ipaddrs.iter().map(ToString::to_string).collect(),
+            macaddr: Some(mac.to_string()),
+            ..Interface::default()
+        }
+    }
+
+    fn cidr(cidr: &str) -> PeeringIPs {
+        PeeringIPs {
+            rule: Some(peering_i_ps::Rule::Cidr(cidr.to_string())),
+        }
+    }
+
+    fn config(generation: i64, interfaces: Vec<Interface>, exposed: Vec<&str>) -> GatewayConfig {
+        GatewayConfig {
+            generation,
+            underlay: Some(Underlay {
+                vrfs: vec![Vrf {
+                    name: "default".to_string(),
+                    interfaces,
+                    ..Vrf::default()
+                }],
+            }),
+            overlay: Some(Overlay {
+                vpcs: vec![],
+                peerings: vec![VpcPeering {
+                    name: "vpc-1--vpc-2".to_string(),
+                    r#for: vec![PeeringEntryFor {
+                        vpc: "vpc-1".to_string(),
+                        expose: vec![Expose {
+                            ips: exposed.into_iter().map(cidr).collect(),
+                            ..Expose::default()
+                        }],
+                    }],
+                }],
+            }),
+            ..GatewayConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut messy = config(
+            1,
+            vec![
+                interface("eth1", &["2001:DB8:0:0::1/64"], "02-AA-BB-CC-DD-EE"),
+                interface("eth0", &["10.0.0.3/31", "10.0.0.3/31"], "not a mac"),
+            ],
+            vec!["10.1.2.3/24", "10.0.0.0/24", "10.1.2.0/24"],
+        );
+        normalize(&mut messy);
+        assert_eq!(
+            messy,
+            config(
+                1,
+                vec![
+                    interface("eth0", &["10.0.0.3/31"], "not a mac"),
+                    interface("eth1", &["2001:db8::1/64"], "02:aa:bb:cc:dd:ee"),
+                ],
+                vec!["10.0.0.0/24", "10.1.2.0/24"],
+            )
+        );
+
+        let once = messy.clone();
+        normalize(&mut messy);
+        assert_eq!(messy, once);
+    }
+
+    #[test]
+    fn test_semantic_eq() {
+        let a = config(
+            1,
+            vec![interface("eth0", &["10.0.0.2/31"], "02:00:00:00:00:01")],
+            vec!["10.1.0.0/16", "10.2.0.0/16"],
+        );
+        let b = config(
+            2,
+            vec![interface("eth0", &["10.0.0.2/31"], "02:00:00:00:00:01")],
+            vec!["10.2.0.5/16", "10.1.0.0/16"],
+        );
+        assert!(semantic_eq(&a, &b));
+        assert_eq!(content_hash(&a), content_hash(&b));
+        assert_eq!(content_hash(&a).len(), 64);
+
+        // Host bits of interface addresses matter
+        let c = config(
+            1,
+            vec![interface("eth0", &["10.0.0.3/31"], "02:00:00:00:00:01")],
+            vec!["10.1.0.0/16", "10.2.0.0/16"],
+        );
+        assert!(!semantic_eq(&a, &c));
+        assert_ne!(content_hash(&a), content_hash(&c));
+
+        let mut d = a.clone();
+        if let Some(overlay) = d.overlay.as_mut() {
+            overlay.vpcs.push(Vpc {
+                name: "vpc-1".to_string(),
+                ..Vpc::default()
+            });
+        }
+        assert!(!semantic_eq(&a, &d));
+    }
+}
@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::net::IpAddr;
+
+use crate::config::{Expose, PeeringIPs, peering_i_ps};
+use crate::overlay::{L4Flow, filters_overlap, filters_permit};
+use crate::prefix::Prefix;
+
+fn prefixes(ips: &[PeeringIPs], not: bool) -> impl Iterator<Item = Prefix> + '_ {
+    ips.iter().filter_map(move |ip| match &ip.rule {
+        Some(peering_i_ps::Rule::Cidr(cidr)) if !not => cidr.parse().ok(),
+        Some(peering_i_ps::Rule::Not(cidr)) if not => cidr.parse().ok(),
+        _ => None,
+    })
+}
+
+/// Address family of the exposed ips, `None` if there are no parsable prefixes.
+#[must_use]
+pub fn expose_is_ipv4(expose: &Expose) -> Option<bool> {
+    prefixes(&expose.ips, false)
+        .chain(prefixes(&expose.ips, true))
+        .map(|prefix| prefix.is_ipv4())
+        .next()
+}
+
+/// Whether the address is exposed, i.e. in one of the `cidr` rules and in none of the `not` rules.
+#[must_use]
+pub fn expose_contains(expose: &Expose, addr: IpAddr) -> bool {
+    prefixes(&expose.ips, false).any(|prefix| prefix.contains(addr))
+        && !prefixes(&expose.ips, true).any(|prefix| prefix.contains(addr))
+}
+
+/// Whether the expose lets the flow to the (untranslated) address through.
+#[must_use]
+pub fn expose_permits(expose: &Expose, addr: IpAddr, flow: L4Flow) -> bool {
+    expose_contains(expose, addr) && filters_permit(&expose.filters, flow)
+}
+
+/// Whether some flow to some address is let through by both exposes.
+///
+/// `not` rules are not taken into account, so this may report an overlap
+/// for exposes whose exclusions carve out exactly the shared addresses.
+#[must_use]
+pub fn exposes_overlap(a: &Expose, b: &Expose) -> bool {
+    prefixes(&a.ips, false).any(|a| prefixes(&b.ips, false).any(|b| a.overlaps(&b)))
+        && filters_overlap(&a.filters, &b.filters)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::{L4Filter, L4Protocol, PortRange};
+
+    fn expose(cidrs: &[&str], nots: &[&str], filters: Vec<L4Filter>) -> Expose {
+        let rule = |cidr: &&str, not: bool| PeeringIPs {
+            rule: Some(if not {
+                peering_i_ps::Rule::Not((*cidr).to_string())
+            } else {
+                peering_i_ps::Rule::Cidr((*cidr).to_string())
+            }),
+        };
+        Expose {
+            ips: cidrs
+                .iter()
+                .map(|c| rule(c, false))
+                .chain(nots.iter().map(|c| rule(c, true)))
+                .collect(),
+            filters,
+            ..Expose::default()
+        }
+    }
+
+    fn tcp(port: u32) -> L4Filter {
+        L4Filter {
+            protocol: L4Protocol::Tcp.into(),
+            ports: vec![PortRange {
+                start: port,
+                end: port,
+            }],
+            icmp_types: vec![],
+        }
+    }
+
+    #[test]
+    fn test_expose_permits() {
+        let https = expose(&["10.0.0.0/24"], &["10.0.0.128/25"], vec![tcp(443)]);
+        let addr = |s: &str| s.parse::<IpAddr>().unwrap();
+        assert_eq!(expose_is_ipv4(&https), Some(true));
+        assert!(expose_permits(
+            &https,
+            addr("10.0.0.1"),
+            L4Flow::Tcp { dst_port: 443 }
+        ));
+        assert!(!expose_permits(
+            &https,
+            addr("10.0.0.129"),
+            L4Flow::Tcp { dst_port: 443 }
+        ));
+        assert!(!expose_permits(
+            &https,
+            addr("10.0.0.1"),
+            L4Flow::Tcp { dst_port: 22 }
+        ));
+        assert!(!expose_permits(
+            &https,
+            addr("10.0.1.1"),
+            L4Flow::Tcp { dst_port: 443 }
+        ));
+
+        let all = expose(&["10.0.0.0/24"], &[], vec![]);
+        assert!(expose_permits(&all, addr("10.0.0.1"), L4Flow::Other(47)));
+        assert_eq!(expose_is_ipv4(&expose(&[], &[], vec![])), None);
+    }
+
+    #[test]
+    fn test_exposes_overlap() {
+        let https = expose(&["10.0.0.0/24"], &[], vec![tcp(443)]);
+        let ssh = expose(&["10.0.0.0/16"], &[], vec![tcp(22)]);
+        let all = expose(&["10.0.0.0/16"], &[], vec![]);
+        let other = expose(&["10.1.0.0/16"], &[], vec![]);
+        assert!(!exposes_overlap(&https, &ssh));
+        assert!(exposes_overlap(&https, &all));
+        assert!(!exposes_overlap(&all, &other));
+    }
+}
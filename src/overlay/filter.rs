@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use crate::config::{Expose, L4Filter, L4Protocol, PortRange};
+use crate::overlay::{check_port_range, expose_is_ipv4};
+use crate::validation::ValidationError;
+
+/// A packet as far as the L4 filters of an expose are concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum L4Flow {
+    Tcp {
+        dst_port: u16,
+    },
+    Udp {
+        dst_port: u16,
+    },
+    Icmp {
+        icmp_type: u8,
+    },
+    Icmpv6 {
+        icmp_type: u8,
+    },
+    /// Any other IP protocol, by protocol number
+    Other(u8),
+}
+
+fn ports_contain(ports: &[PortRange], port: u16) -> bool {
+    ports.is_empty()
+        || ports
+            .iter()
+            .any(|range| (range.start..=range.end).contains(&u32::from(port)))
+}
+
+fn icmp_types_contain(types: &[u32], icmp_type: u8) -> bool {
+    types.is_empty() || types.contains(&u32::from(icmp_type))
+}
+
+/// Whether a single filter lets the flow through.
+#[must_use]
+pub fn filter_matches(filter: &L4Filter, flow: L4Flow) -> bool {
+    match (filter.protocol(), flow) {
+        (L4Protocol::Any, _) => true,
+        (L4Protocol::Tcp, L4Flow::Tcp { dst_port })
+        | (L4Protocol::Udp, L4Flow::Udp { dst_port }) => ports_contain(&filter.ports, dst_port),
+        (L4Protocol::Icmp, L4Flow::Icmp { icmp_type })
+        | (L4Protocol::Icmpv6, L4Flow::Icmpv6 { icmp_type }) => {
+            icmp_types_contain(&filter.icmp_types, icmp_type)
+        }
+        _ => false,
+    }
+}
+
+/// Whether a list of filters lets the flow through, an empty list allows everything.
+#[must_use]
+pub fn filters_permit(filters: &[L4Filter], flow: L4Flow) -> bool {
+    filters.is_empty() || filters.iter().any(|filter| filter_matches(filter, flow))
+}
+
+fn port_ranges_overlap(a: &[PortRange], b: &[PortRange]) -> bool {
+    a.is_empty()
+        || b.is_empty()
+        || a.iter()
+            .any(|a| b.iter().any(|b| a.start <= b.end && b.start <= a.end))
+}
+
+fn icmp_types_overlap(a: &[u32], b: &[u32]) -> bool {
+    a.is_empty() || b.is_empty() || a.iter().any(|t| b.contains(t))
+}
+
+/// Whether some flow exists that both filters let through.
+#[must_use]
+pub fn filter_overlaps(a: &L4Filter, b: &L4Filter) -> bool {
+    match (a.protocol(), b.protocol()) {
+        (L4Protocol::Any, _) | (_, L4Protocol::Any) => true,
+        (L4Protocol::Tcp, L4Protocol::Tcp) | (L4Protocol::Udp, L4Protocol::Udp) => {
+            port_ranges_overlap(&a.ports, &b.ports)
+        }
+        (L4Protocol::Icmp, L4Protocol::Icmp) | (L4Protocol::Icmpv6, L4Protocol::Icmpv6) => {
+            icmp_types_overlap(&a.icmp_types, &b.icmp_types)
+        }
+        _ => false,
+    }
+}
+
+/// Whether some flow exists that both filter lists let through, empty lists allow everything.
+#[must_use]
+pub fn filters_overlap(a: &[L4Filter], b: &[L4Filter]) -> bool {
+    a.is_empty() || b.is_empty() || a.iter().any(|a| b.iter().any(|b| filter_overlaps(a, b)))
+}
+
+pub(crate) fn check_filters(peering: &str, expose: &Expose, errors: &mut Vec<ValidationError>) {
+    let v4 = expose_is_ipv4(expose);
+    for filter in &expose.filters {
+        let Ok(protocol) = L4Protocol::try_from(filter.protocol) else {
+            errors.push(ValidationError::UnknownEnumValue(
+                "L4Protocol",
+                filter.protocol,
+            ));
+            continue;
+        };
+        if !filter.ports.is_empty() && !matches!(protocol, L4Protocol::Tcp | L4Protocol::Udp) {
+            errors.push(ValidationError::PortsWithoutTcpUdp(peering.to_string()));
+        }
+        for range in &filter.ports {
+            check_port_range(*range, errors);
+        }
+        if !filter.icmp_types.is_empty()
+            && !matches!(protocol, L4Protocol::Icmp | L4Protocol::Icmpv6)
+        {
+            errors.push(ValidationError::IcmpTypesWithoutIcmp(peering.to_string()));
+        }
+        for icmp_type in &filter.icmp_types {
+            if *icmp_type > u32::from(u8::MAX) {
+                errors.push(ValidationError::InvalidIcmpType(
+                    peering.to_string(),
+                    *icmp_type,
+                ));
+            }
+        }
+        match (protocol, v4) {
+            (L4Protocol::Icmp, Some(false)) | (L4Protocol::Icmpv6, Some(true)) => {
+                errors.push(ValidationError::IcmpFamilyMismatch(peering.to_string()));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::{PeeringIPs, peering_i_ps};
+
+    fn filter(protocol: L4Protocol, ports: &[(u32, u32)], icmp_types: &[u32]) -> L4Filter {
+        L4Filter {
+            protocol: protocol.into(),
+            ports: ports
+                .iter()
+                .map(|&(start, end)| PortRange { start, end })
+                .collect(),
+            icmp_types: icmp_types.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_filters_permit() {
+        let https = filter(L4Protocol::Tcp, &[(443, 443)], &[]);
+        let ping = filter(L4Protocol::Icmp, &[], &[8]);
+        let filters = [https.clone(), ping];
+        assert!(filters_permit(&filters, L4Flow::Tcp { dst_port: 443 }));
+        assert!(!filters_permit(&filters, L4Flow::Tcp { dst_port: 80 }));
+        assert!(!filters_permit(&filters, L4Flow::Udp { dst_port: 443 }));
+        assert!(filters_permit(&filters, L4Flow::Icmp { icmp_type: 8 }));
+        assert!(!filters_permit(&filters, L4Flow::Icmpv6 { icmp_type: 8 }));
+        assert!(!filters_permit(&filters, L4Flow::Other(47)));
+        assert!(filters_permit(&[], L4Flow::Other(47)));
+        assert!(filter_matches(
+            &filter(L4Protocol::Any, &[], &[]),
+            L4Flow::Other(47)
+        ));
+        assert!(filter_matches(
+            &filter(L4Protocol::Udp, &[], &[]),
+            L4Flow::Udp { dst_port: 53 }
+        ));
+    }
+
+    #[test]
+    fn test_filters_overlap() {
+        let web = [filter(L4Protocol::Tcp, &[(80, 80), (443, 443)], &[])];
+        let high = [filter(L4Protocol::Tcp, &[(1024, 65535)], &[])];
+        let tls = [filter(L4Protocol::Tcp, &[(400, 500)], &[])];
+        let dns = [filter(L4Protocol::Udp, &[(53, 53)], &[])];
+        assert!(filters_overlap(&web, &tls));
+        assert!(!filters_overlap(&web, &high));
+        assert!(!filters_overlap(&web, &dns));
+        assert!(filters_overlap(&web, &[]));
+        assert!(filters_overlap(&[filter(L4Protocol::Any, &[], &[])], &dns));
+        assert!(!filters_overlap(
+            &[filter(L4Protocol::Icmp, &[], &[0])],
+            &[filter(L4Protocol::Icmp, &[], &[8])]
+        ));
+    }
+
+    #[test]
+    fn test_check_filters() {
+        let peering = || "a--b".to_string();
+        let expose = Expose {
+            ips: vec![PeeringIPs {
+                rule: Some(peering_i_ps::Rule::Cidr("10.0.0.0/24".to_string())),
+            }],
+            filters: vec![
+                filter(L4Protocol::Tcp, &[(443, 443)], &[]),
+                filter(L4Protocol::Icmp, &[(1, 2)], &[8, 300]),
+                filter(L4Protocol::Udp, &[(0, 10)], &[3]),
+                filter(L4Protocol::Icmpv6, &[], &[]),
+                L4Filter {
+                    protocol: 42,
+                    ..L4Filter::default()
+                },
+            ],
+            ..Expose::default()
+        };
+        let mut errors = vec![];
+        check_filters(&peering(), &expose, &mut errors);
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::PortsWithoutTcpUdp(peering()),
+                ValidationError::InvalidIcmpType(peering(), 300),
+                ValidationError::InvalidPortRange(0, 10),
+                ValidationError::IcmpTypesWithoutIcmp(peering()),
+                ValidationError::IcmpFamilyMismatch(peering()),
+                ValidationError::UnknownEnumValue("L4Protocol", 42),
+            ]
+        );
+    }
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+mod analysis;
+mod filter;
+mod nat;
+
+pub use analysis::*;
+pub use filter::*;
+pub use nat::*;
+
+use std::collections::HashSet;
+
+use crate::config::{Expose, Overlay, peering_as, peering_i_ps};
+use crate::prefix::Prefix;
+use crate::validation::ValidationError;
+
+/// Check that all prefixes of an expose, including `not` rules, parse.
+fn check_expose_cidrs(expose: &Expose, errors: &mut Vec<ValidationError>) {
+    let ips = expose.ips.iter().filter_map(|ips| match &ips.rule {
+        Some(peering_i_ps::Rule::Cidr(cidr) | peering_i_ps::Rule::Not(cidr)) => Some(cidr),
+        None => None,
+    });
+    let r#as = expose.r#as.iter().filter_map(|r#as| match &r#as.rule {
+        Some(peering_as::Rule::Cidr(cidr) | peering_as::Rule::Not(cidr)) => Some(cidr),
+        None => None,
+    });
+    for cidr in ips.chain(r#as) {
+        if let Err(e) = cidr.parse::<Prefix>() {
+            errors.push(e);
+        }
+    }
+}
+
+pub(crate) fn check_overlay(overlay: &Overlay, errors: &mut Vec<ValidationError>) {
+    let mut vnis = HashSet::new();
+    for vpc in &overlay.vpcs {
+        if !vnis.insert(vpc.vni) {
+            errors.push(ValidationError::DuplicateVni(vpc.vni));
+        }
+    }
+    let vpcs = overlay
+        .vpcs
+        .iter()
+        .map(|vpc| vpc.name.as_str())
+        .collect::<HashSet<_>>();
+    for peering in &overlay.peerings {
+        for entry in &peering.r#for {
+            if !vpcs.contains(entry.vpc.as_str()) {
+                errors.push(ValidationError::UnknownVpc(
+                    peering.name.clone(),
+                    entry.vpc.clone(),
+                ));
+            }
+            for expose in &entry.expose {
+                check_expose_cidrs(expose, errors);
+                check_stateful_nat(&peering.name, expose, errors);
+                check_filters(&peering.name, expose, errors);
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use crate::config::{Expose, PeeringAs, PortRange, StatefulNat, peering_i_ps};
+use crate::overlay::expose_is_ipv4;
+use crate::prefix::Prefix;
+use crate::validation::ValidationError;
+
+/// How the addresses of an [`Expose`] are translated towards the peer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExposeNat<'a> {
+    /// Addresses are exposed as they are
+    None,
+    /// Static prefix-to-prefix translation
+    Static(&'a [PeeringAs]),
+    /// Stateful, port translating source NAT
+    Stateful(&'a StatefulNat),
+}
+
+#[must_use]
+pub fn expose_nat(expose: &Expose) -> ExposeNat<'_> {
+    match (&expose.stateful_nat, expose.r#as.as_slice()) {
+        (Some(nat), _) => ExposeNat::Stateful(nat),
+        (None, []) => ExposeNat::None,
+        (None, r#as) => ExposeNat::Static(r#as),
+    }
+}
+
+/// Upper bound of the number of addresses an expose makes visible, `not` rules are ignored.
+#[must_use]
+pub fn exposed_size(expose: &Expose) -> u128 {
+    expose
+        .ips
+        .iter()
+        .filter_map(|ip| match &ip.rule {
+            Some(peering_i_ps::Rule::Cidr(cidr)) => cidr.parse::<Prefix>().ok(),
+            _ => None,
+        })
+        .fold(0, |size, prefix| size.saturating_add(prefix.size()))
+}
+
+pub(crate) fn check_port_range(range: PortRange, errors: &mut Vec<ValidationError>) {
+    if range.start == 0 || range.start > range.end || range.end > u32::from(u16::MAX) {
+        errors.push(ValidationError::InvalidPortRange(range.start, range.end));
+    }
+}
+
+pub(crate) fn check_stateful_nat(
+    peering: &str,
+    expose: &Expose,
+    errors: &mut Vec<ValidationError>,
+) {
+    let Some(nat) = &expose.stateful_nat else {
+        return;
+    };
+    if !expose.r#as.is_empty() {
+        errors.push(ValidationError::StaticAndStatefulNat(peering.to_string()));
+    }
+
+    let pool = nat.pool.clone().unwrap_or_default();
+    if pool.cidrs.is_empty() {
+        errors.push(ValidationError::EmptyNatPool(peering.to_string()));
+    }
+    let exposed_v4 = expose_is_ipv4(expose);
+    let mut pool_size = 0_u128;
+    for cidr in &pool.cidrs {
+        match cidr.parse::<Prefix>() {
+            Ok(prefix) => {
+                if exposed_v4.is_some_and(|v4| v4 != prefix.is_ipv4()) {
+                    errors.push(ValidationError::NatPoolFamilyMismatch(
+                        peering.to_string(),
+                        cidr.clone(),
+                    ));
+                }
+                pool_size = pool_size.saturating_add(prefix.size());
+            }
+            Err(e) => errors.push(e),
+        }
+    }
+    // Masquerading is many-to-few, a pool larger than what it hides is a mistake
+    let exposed = exposed_size(expose);
+    if exposed > 0 && pool_size > exposed {
+        errors.push(ValidationError::NatPoolTooLarge(
+            peering.to_string(),
+            pool_size,
+            exposed,
+        ));
+    }
+    if let Some(ports) = &pool.ports {
+        check_port_range(*ports, errors);
+    }
+    for timeout in [
+        nat.tcp_idle_timeout,
+        nat.udp_idle_timeout,
+        nat.icmp_idle_timeout,
+    ] {
+        if timeout == Some(0) {
+            errors.push(ValidationError::ZeroIdleTimeout(peering.to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::{NatPool, PeeringIPs, peering_as};
+
+    fn ips(cidrs: &[&str]) -> Vec<PeeringIPs> {
+        cidrs
+            .iter()
+            .map(|cidr| PeeringIPs {
+                rule: Some(peering_i_ps::Rule::Cidr((*cidr).to_string())),
+            })
+            .collect()
+    }
+
+    fn stateful(cidrs: &[&str]) -> StatefulNat {
+        StatefulNat {
+            pool: Some(NatPool {
+                cidrs: cidrs.iter().map(ToString::to_string).collect(),
+                ports: Some(PortRange {
+                    start: 1024,
+                    end: 65535,
+                }),
+            }),
+            tcp_idle_timeout: Some(300),
+            ..StatefulNat::default()
+        }
+    }
+
+    fn errors(expose: &Expose) -> Vec<ValidationError> {
+        let mut errors = vec![];
+        check_stateful_nat("a--b", expose, &mut errors);
+        errors
+    }
+
+    #[test]
+    fn test_expose_nat() {
+        let mut expose = Expose {
+            ips: ips(&["10.0.0.0/24"]),
+            ..Expose::default()
+        };
+        assert_eq!(expose_nat(&expose), ExposeNat::None);
+        expose.r#as = vec![PeeringAs {
+            rule: Some(peering_as::Rule::Cidr("192.168.0.0/24".to_string())),
+        }];
+        assert!(matches!(expose_nat(&expose), ExposeNat::Static(_)));
+        expose.r#as.clear();
+        expose.stateful_nat = Some(stateful(&["192.168.0.1/32"]));
+        assert!(matches!(expose_nat(&expose), ExposeNat::Stateful(_)));
+        assert_eq!(exposed_size(&expose), 256);
+        assert_eq!(errors(&expose), vec![]);
+    }
+
+    #[test]
+    fn test_bad_stateful_nat() {
+        let peering = || "a--b".to_string();
+        let mut expose = Expose {
+            ips: ips(&["10.0.0.0/30"]),
+            r#as: vec![PeeringAs::default()],
+            stateful_nat: Some(stateful(&["2001:db8::1/128", "192.168.0.0/29", "bad"])),
+            filters: vec![],
+        };
+        if let Some(nat) = expose.stateful_nat.as_mut() {
+            nat.udp_idle_timeout = Some(0);
+            if let Some(pool) = nat.pool.as_mut() {
+                pool.ports = Some(PortRange {
+                    start: 2000,
+                    end: 1000,
+                });
+            }
+        }
+        assert_eq!(
+            errors(&expose),
+            vec![
+                ValidationError::StaticAndStatefulNat(peering()),
+                ValidationError::NatPoolFamilyMismatch(peering(), "2001:db8::1/128".to_string()),
+                ValidationError::InvalidCidr("bad".to_string()),
+                ValidationError::NatPoolTooLarge(peering(), 9, 4),
+                ValidationError::InvalidPortRange(2000, 1000),
+                ValidationError::ZeroIdleTimeout(peering()),
+            ]
+        );
+
+        expose.r#as.clear();
+        expose.stateful_nat = Some(StatefulNat::default());
+        assert_eq!(
+            errors(&expose),
+            vec![ValidationError::EmptyNatPool(peering())]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use crate::validation::ValidationError;
+
+/// An IPv4 or IPv6 prefix in CIDR notation, e.g. `10.0.0.0/24`.
+///
+/// Host bits are kept as given, use [`Prefix::network`] to clear them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Prefix {
+    addr: IpAddr,
+    len: u8,
+}
+
+impl Prefix {
+    /// Create a prefix from an address and a mask length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the mask length is longer than the address.
+    pub fn new(addr: IpAddr, len: u8) -> Result<Self, ValidationError> {
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        if len > max {
+            return Err(ValidationError::InvalidCidr(format!("{addr}/{len}")));
+        }
+        Ok(Prefix { addr, len })
+    }
+
+    #[must_use]
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    #[must_use]
+    pub fn mask_len(&self) -> u8 {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_ipv4(&self) -> bool {
+        self.addr.is_ipv4()
+    }
+
+    /// The prefix with all host bits cleared.
+    #[must_use]
+    pub fn network(&self) -> Prefix {
+        let addr = match self.addr {
+            IpAddr::V4(addr) => IpAddr::V4(Ipv4Addr::from(
+                addr.to_bits() & u32::MAX.unbounded_shl(32 - u32::from(self.len)),
+            )),
+            IpAddr::V6(addr) => IpAddr::V6(Ipv6Addr::from(
+                addr.to_bits() & u128::MAX.unbounded_shl(128 - u32::from(self.len)),
+            )),
+        };
+        Prefix {
+            addr,
+            len: self.len,
+        }
+    }
+
+    /// Number of addresses covered, saturating at `u128::MAX` for `::/0`.
+    #[must_use]
+    pub fn size(&self) -> u128 {
+        let host_bits = if self.is_ipv4() { 32 } else { 128 } - u32::from(self.len);
+        1_u128.checked_shl(host_bits).unwrap_or(u128::MAX)
+    }
+
+    #[must_use]
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        match Prefix::new(addr, self.len) {
+            Ok(other) => other.network().addr == self.network().addr,
+            Err(_) => false,
+        }
+    }
+
+    /// Whether the two prefixes share at least one address.
+    #[must_use]
+    pub fn overlaps(&self, other: &Prefix) -> bool {
+        self.contains(other.addr) || other.contains(self.addr)
+    }
+}
+
+impl FromStr for Prefix {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ValidationError::InvalidCidr(s.to_string());
+        let (addr, len) = s.split_once('/').ok_or_else(err)?;
+        let addr = addr.parse::<IpAddr>().map_err(|_| err())?;
+        let len = len.parse::<u8>().map_err(|_| err())?;
+        Prefix::new(addr, len).map_err(|_| err())
+    }
+}
+
+impl fmt::Display for Prefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Prefix;
+    use std::net::IpAddr;
+
+    #[test]
+    fn test_prefix() {
+        let prefix = "10.1.2.3/24".parse::<Prefix>().unwrap();
+        assert_eq!(prefix.network().to_string(), "10.1.2.0/24");
+        assert_eq!(prefix.size(), 256);
+        assert!(prefix.contains("10.1.2.200".parse::<IpAddr>().unwrap()));
+        assert!(!prefix.contains("10.1.3.0".parse::<IpAddr>().unwrap()));
+        assert!(!prefix.contains("::1".parse::<IpAddr>().unwrap()));
+        assert!(prefix.overlaps(&"10.0.0.0/8".parse().unwrap()));
+        assert!(!prefix.overlaps(&"10.1.3.0/24".parse().unwrap()));
+
+        let prefix = "2001:DB8::1/64".parse::<Prefix>().unwrap();
+        assert_eq!(prefix.network().to_string(), "2001:db8::/64");
+        assert_eq!(prefix.size(), 1 << 64);
+        assert_eq!("::/0".parse::<Prefix>().unwrap().size(), u128::MAX);
+        assert_eq!("0.0.0.0/0".parse::<Prefix>().unwrap().size(), 1 << 32);
+
+        for bad in [
+            "10.0.0.0",
+            "10.0.0.0/33",
+            "::/129",
+            "10.0.0/8",
+            "10.0.0.0/-1",
+            "/8",
+        ] {
+            assert!(bad.parse::<Prefix>().is_err(), "{bad} parsed");
+        }
+    }
+}
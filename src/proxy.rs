@@ -0,0 +1,423 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tonic::transport::Channel;
+use tonic::{Code, Request, Response, Status};
+
+use crate::client::GatewayClient;
+use crate::config::{
+    AcquireConfigLockRequest, AcquireConfigLockResponse, ApplyOperation, ConfirmConfigRequest,
+    ConfirmConfigResponse, GatewayConfig, GetConfigGenerationRequest, GetConfigGenerationResponse,
+    GetConfigRequest, GetCountersRequest, GetCountersResponse, GetOperationRequest,
+    GetStatusRequest, GetStatusResponse, ReleaseConfigLockRequest, ReleaseConfigLockResponse,
+    SetLogLevelRequest, SetLogLevelResponse, UpdateConfigRequest, UpdateConfigResponse,
+    WaitOperationRequest, config_service_client::ConfigServiceClient,
+    config_service_server::ConfigService,
+};
+
+#[derive(Debug, Error)]
+pub enum RecordingError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid record on line {0}: {1}")]
+    Parse(usize, serde_json::Error),
+}
+
+/// A gRPC error as recorded, [`Status`] itself is not serializable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedStatus {
+    pub code: i32,
+    pub message: String,
+}
+
+impl From<&Status> for RecordedStatus {
+    fn from(status: &Status) -> Self {
+        Self {
+            code: status.code().into(),
+            message: status.message().to_string(),
+        }
+    }
+}
+
+impl From<RecordedStatus> for Status {
+    fn from(status: RecordedStatus) -> Self {
+        Status::new(Code::from(status.code), status.message)
+    }
+}
+
+/// A request and the response or error it got.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Exchange<Req, Resp> {
+    pub request: Req,
+    pub response: Result<Resp, RecordedStatus>,
+}
+
+/// One `ConfigService` call, by method.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Call {
+    GetConfig(Exchange<GetConfigRequest, GatewayConfig>),
+    GetConfigGeneration(Exchange<GetConfigGenerationRequest, GetConfigGenerationResponse>),
+    UpdateConfig(Exchange<UpdateConfigRequest, UpdateConfigResponse>),
+    GetStatus(Exchange<GetStatusRequest, GetStatusResponse>),
+    GetCounters(Exchange<GetCountersRequest, GetCountersResponse>),
+    SetLogLevel(Exchange<SetLogLevelRequest, SetLogLevelResponse>),
+    AcquireConfigLock(Exchange<AcquireConfigLockRequest, AcquireConfigLockResponse>),
+    ReleaseConfigLock(Exchange<ReleaseConfigLockRequest, ReleaseConfigLockResponse>),
+    ConfirmConfig(Exchange<ConfirmConfigRequest, ConfirmConfigResponse>),
+    GetOperation(Exchange<GetOperationRequest, ApplyOperation>),
+    WaitOperation(Exchange<WaitOperationRequest, ApplyOperation>),
+}
+
+/// A call and when the proxy received it, one JSON line of a recorded session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedCall {
+    pub timestamp_ms: u64,
+    pub call: Call,
+}
+
+fn now_ms() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    u64::try_from(now).unwrap_or(u64::MAX)
+}
+
+/// Appends recorded calls to a session file, one JSON object per line.
+#[derive(Debug)]
+pub struct Recorder {
+    file: Mutex<File>,
+}
+
+impl Recorder {
+    /// Record to `path`, appending to the session already there if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, RecordingError> {
+        let file = File::options().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the call cannot be written.
+    pub fn record(&self, call: &RecordedCall) -> Result<(), RecordingError> {
+        let mut line = serde_json::to_vec(call).map_err(std::io::Error::from)?;
+        line.push(b'\n');
+        let mut file = self
+            .file
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        file.write_all(&line)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Read a session written by a [`Recorder`].
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a line is not a recorded call.
+pub fn load_session(path: impl AsRef<Path>) -> Result<Vec<RecordedCall>, RecordingError> {
+    BufReader::new(File::open(path)?)
+        .lines()
+        .zip(1..)
+        .filter(|(line, _)| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+        .map(|(line, n)| serde_json::from_str(&line?).map_err(|e| RecordingError::Parse(n, e)))
+        .collect()
+}
+
+fn exchange<Req, Resp>(
+    request: Req,
+    response: &Result<Response<Resp>, Status>,
+) -> Exchange<Req, Resp>
+where
+    Resp: Clone,
+{
+    Exchange {
+        request,
+        response: response
+            .as_ref()
+            .map(|response| response.get_ref().clone())
+            .map_err(RecordedStatus::from),
+    }
+}
+
+/// [`ConfigService`] forwarding every call to an upstream dataplane and recording it.
+///
+/// A call that cannot be recorded fails with `Internal` after being forwarded, so that a
+/// session never silently misses a call.
+#[derive(Debug)]
+pub struct RecordingProxy {
+    upstream: ConfigServiceClient<Channel>,
+    recorder: Recorder,
+}
+
+impl RecordingProxy {
+    #[must_use]
+    pub fn new(upstream: Channel, recorder: Recorder) -> Self {
+        Self {
+            upstream: ConfigServiceClient::new(upstream),
+            recorder,
+        }
+    }
+
+    fn record(&self, timestamp_ms: u64, call: Call) -> Result<(), RecordingError> {
+        self.recorder.record(&RecordedCall { timestamp_ms, call })
+    }
+}
+
+#[tonic::async_trait]
+impl ConfigService for RecordingProxy {
+    async fn get_config(
+        &self,
+        request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self.upstream.clone().get_config(request).await;
+        let call = Call::GetConfig(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn get_config_generation(
+        &self,
+        request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<GetConfigGenerationResponse>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self.upstream.clone().get_config_generation(request).await;
+        let call = Call::GetConfigGeneration(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn update_config(
+        &self,
+        request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<UpdateConfigResponse>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self.upstream.clone().update_config(request.clone()).await;
+        let call = Call::UpdateConfig(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn get_status(
+        &self,
+        request: Request<GetStatusRequest>,
+    ) -> Result<Response<GetStatusResponse>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self.upstream.clone().get_status(request).await;
+        let call = Call::GetStatus(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn get_counters(
+        &self,
+        request: Request<GetCountersRequest>,
+    ) -> Result<Response<GetCountersResponse>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self.upstream.clone().get_counters(request).await;
+        let call = Call::GetCounters(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn set_log_level(
+        &self,
+        request: Request<SetLogLevelRequest>,
+    ) -> Result<Response<SetLogLevelResponse>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self.upstream.clone().set_log_level(request.clone()).await;
+        let call = Call::SetLogLevel(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn acquire_config_lock(
+        &self,
+        request: Request<AcquireConfigLockRequest>,
+    ) -> Result<Response<AcquireConfigLockResponse>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self
+            .upstream
+            .clone()
+            .acquire_config_lock(request.clone())
+            .await;
+        let call = Call::AcquireConfigLock(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn release_config_lock(
+        &self,
+        request: Request<ReleaseConfigLockRequest>,
+    ) -> Result<Response<ReleaseConfigLockResponse>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self
+            .upstream
+            .clone()
+            .release_config_lock(request.clone())
+            .await;
+        let call = Call::ReleaseConfigLock(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn confirm_config(
+        &self,
+        request: Request<ConfirmConfigRequest>,
+    ) -> Result<Response<ConfirmConfigResponse>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self.upstream.clone().confirm_config(request).await;
+        let call = Call::ConfirmConfig(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn get_operation(
+        &self,
+        request: Request<GetOperationRequest>,
+    ) -> Result<Response<ApplyOperation>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self.upstream.clone().get_operation(request.clone()).await;
+        let call = Call::GetOperation(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+
+    async fn wait_operation(
+        &self,
+        request: Request<WaitOperationRequest>,
+    ) -> Result<Response<ApplyOperation>, Status> {
+        let (timestamp_ms, request) = (now_ms(), request.into_inner());
+        let response = self.upstream.clone().wait_operation(request.clone()).await;
+        let call = Call::WaitOperation(exchange(request, &response));
+        self.record(timestamp_ms, call)
+            .map_err(|e| Status::internal(format!("recording failed: {e}")))?;
+        response
+    }
+}
+
+/// Re-send every request of a recorded session to `client`, in order and without delays,
+/// and return what this server answered, in the same form as the recording.
+pub async fn replay(session: &[RecordedCall], client: &mut GatewayClient) -> Vec<RecordedCall> {
+    let client = client.inner();
+    let mut replayed = Vec::with_capacity(session.len());
+    for recorded in session {
+        let timestamp_ms = now_ms();
+        let call = match &recorded.call {
+            Call::GetConfig(e) => {
+                let response = client.get_config(e.request).await;
+                Call::GetConfig(exchange(e.request, &response))
+            }
+            Call::GetConfigGeneration(e) => {
+                let response = client.get_config_generation(e.request).await;
+                Call::GetConfigGeneration(exchange(e.request, &response))
+            }
+            Call::UpdateConfig(e) => {
+                let response = client.update_config(e.request.clone()).await;
+                Call::UpdateConfig(exchange(e.request.clone(), &response))
+            }
+            Call::GetStatus(e) => {
+                let response = client.get_status(e.request).await;
+                Call::GetStatus(exchange(e.request, &response))
+            }
+            Call::GetCounters(e) => {
+                let response = client.get_counters(e.request).await;
+                Call::GetCounters(exchange(e.request, &response))
+            }
+            Call::SetLogLevel(e) => {
+                let response = client.set_log_level(e.request.clone()).await;
+                Call::SetLogLevel(exchange(e.request.clone(), &response))
+            }
+            Call::AcquireConfigLock(e) => {
+                let response = client.acquire_config_lock(e.request.clone()).await;
+                Call::AcquireConfigLock(exchange(e.request.clone(), &response))
+            }
+            Call::ReleaseConfigLock(e) => {
+                let response = client.release_config_lock(e.request.clone()).await;
+                Call::ReleaseConfigLock(exchange(e.request.clone(), &response))
+            }
+            Call::ConfirmConfig(e) => {
+                let response = client.confirm_config(e.request).await;
+                Call::ConfirmConfig(exchange(e.request, &response))
+            }
+            Call::GetOperation(e) => {
+                let response = client.get_operation(e.request.clone()).await;
+                Call::GetOperation(exchange(e.request.clone(), &response))
+            }
+            Call::WaitOperation(e) => {
+                let response = client.wait_operation(e.request.clone()).await;
+                Call::WaitOperation(exchange(e.request.clone(), &response))
+            }
+        };
+        replayed.push(RecordedCall { timestamp_ms, call });
+    }
+    replayed
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_session_file() {
+        let path = std::env::temp_dir().join(format!("session-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let call = RecordedCall {
+            timestamp_ms: 42,
+            call: Call::GetConfigGeneration(Exchange {
+                request: GetConfigGenerationRequest {},
+                response: Err(RecordedStatus::from(&Status::unavailable("down"))),
+            }),
+        };
+        let recorder = Recorder::open(&path).unwrap();
+        recorder.record(&call).unwrap();
+        recorder.record(&call).unwrap();
+        assert_eq!(load_session(&path).unwrap(), [call.clone(), call]);
+
+        std::fs::OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"timestamp_ms\": 1}\n")
+            .unwrap();
+        assert!(matches!(
+            load_session(&path),
+            Err(RecordingError::Parse(3, _))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_recorded_status() {
+        let status = Status::from(RecordedStatus::from(&Status::unavailable("down")));
+        assert_eq!(status.code(), Code::Unavailable);
+        assert_eq!(status.message(), "down");
+    }
+}
@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use thiserror::Error;
+use tokio::task::JoinHandle;
+
+use crate::config::{ConfirmConfigRequest, ConfirmConfigResponse, Error, GatewayConfig};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConfirmError {
+    #[error("No config awaits confirmation")]
+    NothingPending,
+    #[error("Generation {1} awaits confirmation, not {0}")]
+    WrongGeneration(i64, i64),
+}
+
+#[derive(Debug)]
+struct Pending {
+    generation: i64,
+    rollback: GatewayConfig,
+    timer: JoinHandle<()>,
+}
+
+/// Commit-confirmed bookkeeping for servers: a config applied with a confirm timeout is
+/// reverted when the timeout expires, unless `ConfirmConfig` arrives first.
+///
+/// Clones share the same pending config.
+#[derive(Debug, Clone, Default)]
+pub struct ConfirmManager {
+    pending: Arc<Mutex<Option<Pending>>>,
+}
+
+fn lock(pending: &Mutex<Option<Pending>>) -> MutexGuard<'_, Option<Pending>> {
+    pending.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl ConfirmManager {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generation of the config awaiting confirmation, if any.
+    #[must_use]
+    pub fn pending_generation(&self) -> Option<i64> {
+        lock(&self.pending)
+            .as_ref()
+            .map(|pending| pending.generation)
+    }
+
+    /// Call `revert` with `generation` after `timeout` unless `generation`, just applied, is
+    /// confirmed first. If a config was already awaiting confirmation, its rollback config
+    /// is kept, so that an expiry goes back to the last confirmed config.
+    ///
+    /// `revert` must first stop updates of the running config, then get the rollback config
+    /// from [`expire`](Self::expire) and only revert if there is one: an update that landed
+    /// after the timeout expired has cancelled or replaced the pending config.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn schedule<F, Fut>(
+        &self,
+        generation: i64,
+        rollback: GatewayConfig,
+        timeout: Duration,
+        revert: F,
+    ) where
+        F: FnOnce(i64) -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        let mut pending = lock(&self.pending);
+        let rollback = match pending.take() {
+            Some(previous) => {
+                previous.timer.abort();
+                previous.rollback
+            }
+            None => rollback,
+        };
+        let timer = tokio::spawn(async move {
+            tokio::time::sleep(timeout).await;
+            revert(generation).await;
+        });
+        *pending = Some(Pending {
+            generation,
+            rollback,
+            timer,
+        });
+    }
+
+    /// Take the rollback config of `generation` once its timeout expired, `None` if it was
+    /// confirmed, cancelled or replaced by another pending config in the meantime.
+    #[must_use]
+    pub fn expire(&self, generation: i64) -> Option<GatewayConfig> {
+        let mut pending = lock(&self.pending);
+        match pending.take() {
+            Some(expired) if expired.generation == generation => Some(expired.rollback),
+            other => {
+                *pending = other;
+                None
+            }
+        }
+    }
+
+    /// Drop the pending rollback, e.g. because a config without confirm timeout replaced
+    /// the one awaiting confirmation.
+    pub fn cancel(&self) {
+        if let Some(pending) = lock(&self.pending).take() {
+            pending.timer.abort();
+        }
+    }
+
+    /// Keep the config of `generation` for good.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no config or a config of another generation awaits confirmation.
+    pub fn confirm(&self, generation: i64) -> Result<(), ConfirmError> {
+        let mut pending = lock(&self.pending);
+        match pending.take() {
+            Some(confirmed) if confirmed.generation == generation => {
+                confirmed.timer.abort();
+                Ok(())
+            }
+            Some(other) => {
+                let awaiting = other.generation;
+                *pending = Some(other);
+                Err(ConfirmError::WrongGeneration(generation, awaiting))
+            }
+            None => Err(ConfirmError::NothingPending),
+        }
+    }
+
+    /// Handle a `ConfirmConfig` request.
+    #[must_use]
+    pub fn handle_confirm(&self, request: &ConfirmConfigRequest) -> ConfirmConfigResponse {
+        match self.confirm(request.generation) {
+            Ok(()) => ConfirmConfigResponse {
+                error: Error::None.into(),
+                message: String::new(),
+            },
+            Err(e) => ConfirmConfigResponse {
+                error: Error::GenerationConflict.into(),
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use tokio::sync::mpsc;
+
+    fn config(generation: i64) -> GatewayConfig {
+        GatewayConfig {
+            generation,
+            ..GatewayConfig::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_confirm_and_expiry() {
+        let confirms = ConfirmManager::new();
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let revert = {
+            let confirms = confirms.clone();
+            move |generation| {
+                let (tx, confirms) = (tx.clone(), confirms.clone());
+                async move {
+                    if let Some(rollback) = confirms.expire(generation) {
+                        tx.send(rollback).unwrap();
+                    }
+                }
+            }
+        };
+        assert_eq!(confirms.confirm(1), Err(ConfirmError::NothingPending));
+
+        confirms.schedule(2, config(1), Duration::from_millis(20), revert.clone());
+        assert_eq!(confirms.pending_generation(), Some(2));
+        assert_eq!(
+            confirms.confirm(3),
+            Err(ConfirmError::WrongGeneration(3, 2))
+        );
+        assert_eq!(confirms.confirm(2), Ok(()));
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert!(rx.try_recv().is_err());
+
+        // Expiry of a second pending config goes back to the last confirmed one
+        confirms.schedule(3, config(2), Duration::from_millis(20), revert.clone());
+        confirms.schedule(4, config(3), Duration::from_millis(20), revert.clone());
+        assert_eq!(rx.recv().await.unwrap(), config(2));
+        assert_eq!(confirms.pending_generation(), None);
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert!(rx.try_recv().is_err());
+
+        // Nothing to revert once a later update replaced the expired config
+        confirms.schedule(5, config(4), Duration::from_millis(20), revert);
+        confirms.cancel();
+        assert_eq!(confirms.expire(5), None);
+    }
+}
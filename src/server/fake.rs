@@ -0,0 +1,553 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tokio::sync::RwLock;
+use tonic::{Request, Response, Status};
+
+use crate::apply::object_results;
+use crate::config::{
+    AcquireConfigLockRequest, AcquireConfigLockResponse, ApplyOperation, ApplyState,
+    BgpNeighborStatus, BgpSessionState, ConfirmConfigRequest, ConfirmConfigResponse, Device, Error,
+    ExposeCounters, GatewayConfig, GetConfigGenerationRequest, GetConfigGenerationResponse,
+    GetConfigRequest, GetCountersRequest, GetCountersResponse, GetOperationRequest,
+    GetStatusRequest, GetStatusResponse, IfType, InterfaceCounters, InterfaceStatus, LogLevel,
+    OperState, PeeringCounters, ReleaseConfigLockRequest, ReleaseConfigLockResponse, SectionResult,
+    SetLogLevelRequest, SetLogLevelResponse, TrafficCounters, UpdateConfigRequest,
+    UpdateConfigResponse, VniStatus, VpcCounters, VtepStatus, WaitOperationRequest,
+    config_service_server::ConfigService,
+};
+use crate::logging::LogLevels;
+use crate::normalize::content_hash;
+use crate::server::{
+    ConfigStore, ConfirmManager, LockManager, OperationTracker, StoreError, generation_conflict,
+    update_response,
+};
+use crate::validation::{all_interfaces, validate_config};
+
+/// In-memory [`ConfigService`] for tests, the Rust counterpart of `gwtestctl`'s fake server.
+///
+/// Every config passing [`validate_config`] is accepted and returned as is,
+/// unless the update expects another generation than the running one.
+/// Status and counters are synthesized from the current config with all state up and
+/// counters that grow with every status or counters request.
+/// Log levels are reset to the config's by every accepted update and changed by
+/// `SetLogLevel` in between.
+/// With a [`ConfigStore`], accepted configs are persisted and restored on startup. Configs
+/// with a confirm timeout are only persisted once confirmed, so a restart goes back to the
+/// last confirmed config.
+/// While the config lock is held, only its owner can update the config.
+/// Updates with a confirm timeout are reverted unless confirmed in time.
+/// Accepted updates report the device and every VRF, VPC and peering as applied.
+/// Async updates are applied in a background task once `UpdateConfig` has returned, their
+/// operation reports one section every [`SECTION_DELAY`] and the config is only running
+/// once all are reported. Config reads wait for updates in progress.
+///
+/// Clones share the same state.
+#[derive(Debug, Clone)]
+pub struct FakeConfigService {
+    config: Arc<RwLock<GatewayConfig>>,
+    ticks: Arc<AtomicU64>,
+    log_levels: Arc<RwLock<LogLevels>>,
+    store: Option<ConfigStore>,
+    locks: LockManager,
+    confirms: ConfirmManager,
+    operations: OperationTracker,
+}
+
+impl Default for FakeConfigService {
+    fn default() -> Self {
+        Self::with_config(GatewayConfig::default())
+    }
+}
+
+/// Config sections reported by async updates, in the order they are applied.
+const SECTIONS: [&str; 3] = ["device", "underlay", "overlay"];
+
+/// Time the fake takes to apply each section of an async update.
+pub const SECTION_DELAY: Duration = Duration::from_millis(50);
+
+fn error_message(errors: &[impl ToString]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Log levels of `config`, without any component overrides.
+fn config_log_levels(config: &GatewayConfig) -> LogLevels {
+    LogLevels::from(
+        config
+            .device
+            .as_ref()
+            .map_or(LogLevel::default(), Device::loglevel),
+    )
+}
+
+impl FakeConfigService {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_config(config: GatewayConfig) -> Self {
+        Self {
+            log_levels: Arc::new(RwLock::new(config_log_levels(&config))),
+            config: Arc::new(RwLock::new(config)),
+            ticks: Arc::new(AtomicU64::new(0)),
+            store: None,
+            locks: LockManager::new(),
+            confirms: ConfirmManager::new(),
+            operations: OperationTracker::new(),
+        }
+    }
+
+    /// Start from the config in `store`, or the default one if it is empty, and persist
+    /// every accepted config to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stored config cannot be read.
+    pub fn with_store(store: ConfigStore) -> Result<Self, StoreError> {
+        let config = store.load()?.unwrap_or_default();
+        Ok(Self {
+            store: Some(store),
+            ..Self::with_config(config)
+        })
+    }
+
+    /// The config last accepted by the service.
+    pub async fn config(&self) -> GatewayConfig {
+        self.config.read().await.clone()
+    }
+
+    /// The log levels currently in effect.
+    pub async fn log_levels(&self) -> LogLevels {
+        self.log_levels.read().await.clone()
+    }
+
+    /// Validate and apply an update, reporting each section to `operation` as it is applied
+    /// if there is one, or right away otherwise.
+    async fn apply(
+        &self,
+        request: UpdateConfigRequest,
+        operation: Option<(&OperationTracker, &str)>,
+    ) -> UpdateConfigResponse {
+        let UpdateConfigRequest {
+            config,
+            expected_generation,
+            owner,
+            confirm_timeout_seconds,
+            async_apply: _,
+        } = request;
+        // Locks are acquired while holding the running config, so none can be taken between
+        // the check and the write
+        let mut running = self.config.write().await;
+        if let Err(e) = self.locks.check_writer(&owner) {
+            return update_response(e.code(), e.to_string());
+        }
+        let Some(config) = config else {
+            return update_response(Error::ValidationFailed, "missing config");
+        };
+        if let Err(errors) = validate_config(&config) {
+            return update_response(Error::ValidationFailed, error_message(&errors));
+        }
+        if let Some(conflict) = generation_conflict(expected_generation, running.generation) {
+            return conflict;
+        }
+        let store = self.store.as_ref().filter(|_| confirm_timeout_seconds == 0);
+        let failure = match store.map(|store| store.save(&config)) {
+            Some(Err(e)) => Some(format!("failed to persist config: {e}")),
+            _ => None,
+        };
+        if let Some((operations, id)) = operation {
+            let (error, message) = failure
+                .as_ref()
+                .map_or((Error::None, ""), |reason| (Error::ApplyFailed, reason));
+            for (done, section) in (1..).zip(SECTIONS) {
+                tokio::time::sleep(SECTION_DELAY).await;
+                let result = SectionResult {
+                    section: section.to_string(),
+                    error: error.into(),
+                    message: message.to_string(),
+                };
+                operations.report(id, result, done * 100 / 3);
+            }
+        }
+        if let Some(reason) = failure {
+            return UpdateConfigResponse {
+                results: object_results(&config, ApplyState::Skipped, &reason),
+                ..update_response(Error::ApplyFailed, reason)
+            };
+        }
+        let results = object_results(&config, ApplyState::Applied, "");
+        *self.log_levels.write().await = config_log_levels(&config);
+        let previous = std::mem::replace(&mut *running, config);
+        if confirm_timeout_seconds == 0 {
+            self.confirms.cancel();
+        } else {
+            let (shared, log_levels, confirms) = (
+                self.config.clone(),
+                self.log_levels.clone(),
+                self.confirms.clone(),
+            );
+            self.confirms.schedule(
+                running.generation,
+                previous,
+                Duration::from_secs(confirm_timeout_seconds.into()),
+                move |generation| async move {
+                    let mut running = shared.write().await;
+                    // An update that got the running config first cancelled or replaced the
+                    // pending one
+                    if running.generation != generation {
+                        return;
+                    }
+                    let Some(rollback) = confirms.expire(generation) else {
+                        return;
+                    };
+                    *log_levels.write().await = config_log_levels(&rollback);
+                    *running = rollback;
+                },
+            );
+        }
+        UpdateConfigResponse {
+            results,
+            ..update_response(Error::None, "")
+        }
+    }
+}
+
+/// Status of a dataplane that has fully applied `config`, counters are scaled by `ticks`.
+#[must_use]
+pub fn synthetic_status(config: &GatewayConfig, ticks: u64) -> GetStatusResponse {
+    let interfaces = all_interfaces(config)
+        .zip(1_u64..)
+        .map(|(iface, n)| {
+            let packets = ticks * n * 100;
+            InterfaceStatus {
+                name: iface.name.clone(),
+                oper_state: OperState::Up.into(),
+                counters: Some(InterfaceCounters {
+                    rx_packets: packets,
+                    rx_bytes: packets * 512,
+                    tx_packets: packets,
+                    tx_bytes: packets * 512,
+                    ..InterfaceCounters::default()
+                }),
+            }
+        })
+        .collect();
+    let bgp_neighbors = config
+        .underlay
+        .iter()
+        .flat_map(|underlay| &underlay.vrfs)
+        .flat_map(|vrf| {
+            vrf.router
+                .iter()
+                .flat_map(|router| &router.neighbors)
+                .map(|neighbor| BgpNeighborStatus {
+                    vrf: vrf.name.clone(),
+                    address: neighbor.address.clone(),
+                    state: BgpSessionState::Established.into(),
+                    prefixes_received: 1,
+                    prefixes_sent: 1,
+                    established_seconds: ticks,
+                })
+        })
+        .collect();
+    let vtep = all_interfaces(config)
+        .find(|iface| iface.r#type == i32::from(IfType::Vtep))
+        .map(|iface| VtepStatus {
+            address: iface
+                .ipaddrs
+                .first()
+                .and_then(|addr| addr.split('/').next())
+                .unwrap_or_default()
+                .to_string(),
+            oper_state: OperState::Up.into(),
+            vnis: config
+                .overlay
+                .iter()
+                .flat_map(|overlay| &overlay.vpcs)
+                .map(|vpc| VniStatus {
+                    vni: vpc.vni,
+                    vpc: vpc.name.clone(),
+                    oper_state: OperState::Up.into(),
+                    remote_vteps: vec![],
+                })
+                .collect(),
+        });
+
+    GetStatusResponse {
+        generation: config.generation,
+        interfaces,
+        bgp_neighbors,
+        vtep,
+    }
+}
+
+/// Per VPC and per peering counters of a dataplane that has fully applied `config`,
+/// scaled by `ticks`. VPC counters are the sums of the counters of their exposes.
+#[must_use]
+pub fn synthetic_counters(
+    config: &GatewayConfig,
+    ticks: u64,
+    timestamp_ms: u64,
+) -> GetCountersResponse {
+    let overlay = config.overlay.clone().unwrap_or_default();
+    let peerings = overlay
+        .peerings
+        .iter()
+        .map(|peering| PeeringCounters {
+            name: peering.name.clone(),
+            exposes: peering
+                .r#for
+                .iter()
+                .flat_map(|entry| {
+                    entry.expose.iter().zip(0_u32..).map(|(expose, index)| {
+                        let packets = ticks * (u64::from(index) + 1) * 10;
+                        let traffic = TrafficCounters {
+                            packets,
+                            bytes: packets * 512,
+                        };
+                        ExposeCounters {
+                            vpc: entry.vpc.clone(),
+                            index,
+                            rx: Some(traffic),
+                            tx: Some(traffic),
+                            nat_sessions: if expose.stateful_nat.is_some() {
+                                ticks
+                            } else {
+                                0
+                            },
+                        }
+                    })
+                })
+                .collect(),
+        })
+        .collect::<Vec<_>>();
+    let vpcs = overlay
+        .vpcs
+        .iter()
+        .map(|vpc| {
+            let exposes = peerings
+                .iter()
+                .flat_map(|peering| &peering.exposes)
+                .filter(|expose| expose.vpc == vpc.name);
+            let mut counters = VpcCounters {
+                vpc: vpc.name.clone(),
+                rx: Some(TrafficCounters::default()),
+                tx: Some(TrafficCounters::default()),
+                nat_sessions: 0,
+            };
+            for expose in exposes {
+                counters.rx = Some(counters.rx.unwrap_or_default() + expose.rx.unwrap_or_default());
+                counters.tx = Some(counters.tx.unwrap_or_default() + expose.tx.unwrap_or_default());
+                counters.nat_sessions += expose.nat_sessions;
+            }
+            counters
+        })
+        .collect();
+
+    GetCountersResponse {
+        generation: config.generation,
+        timestamp_ms,
+        vpcs,
+        peerings,
+    }
+}
+
+#[tonic::async_trait]
+impl ConfigService for FakeConfigService {
+    async fn get_config(
+        &self,
+        _request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        Ok(Response::new(self.config().await))
+    }
+
+    async fn get_config_generation(
+        &self,
+        _request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<GetConfigGenerationResponse>, Status> {
+        let config = self.config.read().await;
+        Ok(Response::new(GetConfigGenerationResponse {
+            generation: config.generation,
+            content_hash: content_hash(&config),
+        }))
+    }
+
+    async fn update_config(
+        &self,
+        request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<UpdateConfigResponse>, Status> {
+        let request = request.into_inner();
+        if !request.async_apply {
+            return Ok(Response::new(self.apply(request, None).await));
+        }
+        let generation = request
+            .config
+            .as_ref()
+            .map_or(0, |config| config.generation);
+        let service = self.clone();
+        let id = self
+            .operations
+            .spawn(generation, |operations, id| async move {
+                let response = service.apply(request, Some((&operations, &id))).await;
+                (response.error(), response.message, response.results)
+            });
+        Ok(Response::new(UpdateConfigResponse {
+            operation_id: id,
+            ..update_response(Error::None, "")
+        }))
+    }
+
+    async fn get_status(
+        &self,
+        _request: Request<GetStatusRequest>,
+    ) -> Result<Response<GetStatusResponse>, Status> {
+        let ticks = self.ticks.fetch_add(1, Ordering::Relaxed) + 1;
+        Ok(Response::new(synthetic_status(
+            &*self.config.read().await,
+            ticks,
+        )))
+    }
+
+    async fn get_counters(
+        &self,
+        _request: Request<GetCountersRequest>,
+    ) -> Result<Response<GetCountersResponse>, Status> {
+        let ticks = self.ticks.fetch_add(1, Ordering::Relaxed) + 1;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        Ok(Response::new(synthetic_counters(
+            &*self.config.read().await,
+            ticks,
+            u64::try_from(now).unwrap_or(u64::MAX),
+        )))
+    }
+
+    async fn set_log_level(
+        &self,
+        request: Request<SetLogLevelRequest>,
+    ) -> Result<Response<SetLogLevelResponse>, Status> {
+        let response = match self.log_levels.write().await.apply(request.get_ref()) {
+            Ok(()) => SetLogLevelResponse {
+                error: Error::None.into(),
+                message: String::new(),
+            },
+            Err(errors) => SetLogLevelResponse {
+                error: Error::ValidationFailed.into(),
+                message: error_message(&errors),
+            },
+        };
+        Ok(Response::new(response))
+    }
+
+    async fn acquire_config_lock(
+        &self,
+        request: Request<AcquireConfigLockRequest>,
+    ) -> Result<Response<AcquireConfigLockResponse>, Status> {
+        // Wait for updates in progress, they checked the lock before it was taken
+        let _running = self.config.read().await;
+        Ok(Response::new(self.locks.handle_acquire(request.get_ref())))
+    }
+
+    async fn release_config_lock(
+        &self,
+        request: Request<ReleaseConfigLockRequest>,
+    ) -> Result<Response<ReleaseConfigLockResponse>, Status> {
+        Ok(Response::new(self.locks.handle_release(request.get_ref())))
+    }
+
+    async fn confirm_config(
+        &self,
+        request: Request<ConfirmConfigRequest>,
+    ) -> Result<Response<ConfirmConfigResponse>, Status> {
+        let request = request.into_inner();
+        // Held so that the config awaiting confirmation stays the running one, and written
+        // so that concurrent confirms do not save it at the same time
+        let running = self.config.write().await;
+        let pending = self.confirms.pending_generation() == Some(request.generation);
+        if let Some(store) = self.store.as_ref().filter(|_| pending) {
+            if let Err(e) = store.save(&running) {
+                return Ok(Response::new(ConfirmConfigResponse {
+                    error: Error::ApplyFailed.into(),
+                    message: format!("failed to persist config: {e}"),
+                }));
+            }
+        }
+        Ok(Response::new(self.confirms.handle_confirm(&request)))
+    }
+
+    async fn get_operation(
+        &self,
+        request: Request<GetOperationRequest>,
+    ) -> Result<Response<ApplyOperation>, Status> {
+        self.operations
+            .get(&request.get_ref().id)
+            .map(Response::new)
+            .map_err(|e| Status::not_found(e.to_string()))
+    }
+
+    async fn wait_operation(
+        &self,
+        request: Request<WaitOperationRequest>,
+    ) -> Result<Response<ApplyOperation>, Status> {
+        let WaitOperationRequest {
+            id,
+            timeout_seconds,
+        } = request.into_inner();
+        self.operations
+            .wait(&id, Duration::from_secs(timeout_seconds.into()))
+            .await
+            .map(Response::new)
+            .map_err(|e| Status::not_found(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn request(generation: i64, confirm_timeout_seconds: u32) -> UpdateConfigRequest {
+        UpdateConfigRequest {
+            config: Some(GatewayConfig {
+                generation,
+                ..GatewayConfig::default()
+            }),
+            expected_generation: None,
+            owner: String::new(),
+            confirm_timeout_seconds,
+            async_apply: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_at_confirm_expiry() {
+        let service = Arc::new(FakeConfigService::new());
+        assert_eq!(service.apply(request(1, 1), None).await.error(), Error::None);
+
+        // An update waits for the running config when the confirm timeout expires
+        let running = service.config.read().await;
+        let update = tokio::spawn({
+            let service = service.clone();
+            async move { service.apply(request(2, 0), None).await }
+        });
+        tokio::time::sleep(Duration::from_millis(1100)).await;
+        drop(running);
+        assert_eq!(update.await.unwrap().error(), Error::None);
+
+        // The expired rollback does not overwrite it
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(service.config().await.generation, 2);
+        assert_eq!(service.confirms.pending_generation(), None);
+    }
+}
@@ -0,0 +1,482 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::future::{Future, pending};
+use std::io;
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::time::{Instant, sleep};
+use tonic::transport::server::{Connected, TcpConnectInfo};
+use tonic::{Request, Response, Status};
+
+use crate::apply::object_results;
+use crate::config::{
+    AcquireConfigLockRequest, AcquireConfigLockResponse, ApplyOperation, ApplyState,
+    ConfirmConfigRequest,
+    ConfirmConfigResponse, Error, GatewayConfig, GetConfigGenerationRequest,
+    GetConfigGenerationResponse, GetConfigRequest, GetCountersRequest, GetCountersResponse,
+    GetOperationRequest, GetStatusRequest, GetStatusResponse, ReleaseConfigLockRequest,
+    ReleaseConfigLockResponse, SetLogLevelRequest, SetLogLevelResponse, UpdateConfigRequest,
+    UpdateConfigResponse, WaitOperationRequest, config_service_server::ConfigService,
+    config_service_server::ConfigServiceServer,
+};
+use crate::server::{FakeConfigService, update_response};
+
+#[derive(Debug, Error)]
+pub enum PolicyError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Invalid fault policy: {0}")]
+    Parse(#[from] serde_json::Error),
+}
+
+/// Faults injected by [`FaultyConfigService`]. Calls are numbered from 1 in the order they
+/// arrive, across all methods, updates are numbered separately.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FaultPolicy {
+    /// Delay before handling every call
+    pub delay_ms: u64,
+    /// Updates answered with `ApplyFailed` without being applied, every object skipped
+    pub apply_failed_updates: Vec<u64>,
+    /// How long `GetConfigGeneration` keeps reporting the previous generation after an update
+    pub generation_lag_ms: u64,
+    /// Calls failed with a transient `Unavailable` status
+    pub unavailable_calls: Vec<u64>,
+    /// Calls on which every connection to the service is dropped
+    pub dropped_calls: Vec<u64>,
+}
+
+impl FaultPolicy {
+    /// Read a policy from a JSON file, missing fields inject no fault.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a valid policy.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, PolicyError> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+}
+
+/// [`ConfigService`] passing calls to another implementation, the fake server by default,
+/// with the faults of a [`FaultPolicy`].
+///
+/// Dropped connections need the service to be run with [`serve`](Self::serve), otherwise
+/// dropped calls fail with `Unavailable`.
+#[derive(Debug)]
+pub struct FaultyConfigService<S = FakeConfigService> {
+    inner: S,
+    policy: FaultPolicy,
+    calls: AtomicU64,
+    updates: AtomicU64,
+    /// Generation reported while lagging and when lagging ends
+    lagging: Mutex<Option<(GetConfigGenerationResponse, Instant)>>,
+    /// Bumped to drop every connection accepted by `serve()`
+    drops: watch::Sender<u64>,
+}
+
+impl<S: ConfigService> FaultyConfigService<S> {
+    #[must_use]
+    pub fn new(inner: S, policy: FaultPolicy) -> Self {
+        Self {
+            inner,
+            policy,
+            calls: AtomicU64::new(0),
+            updates: AtomicU64::new(0),
+            lagging: Mutex::new(None),
+            drops: watch::Sender::new(0),
+        }
+    }
+
+    /// The wrapped service.
+    pub fn inner(&self) -> &S {
+        &self.inner
+    }
+
+    /// Serve on `listener` until an error occurs, connections are dropped on the calls
+    /// listed by the policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server fails.
+    pub async fn serve(self, listener: TcpListener) -> Result<(), tonic::transport::Error> {
+        let drops = self.drops.subscribe();
+        let incoming = futures::stream::unfold(listener, move |listener| {
+            let drops = drops.clone();
+            async move {
+                let stream = listener
+                    .accept()
+                    .await
+                    .map(|(stream, _)| DroppableStream::new(stream, drops));
+                Some((stream, listener))
+            }
+        });
+        tonic::transport::Server::builder()
+            .add_service(ConfigServiceServer::new(self))
+            .serve_with_incoming(incoming)
+            .await
+    }
+
+    /// Apply the faults common to all calls, returning the status the call must fail with.
+    async fn inject(&self) -> Option<Status> {
+        let call = self.calls.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.policy.delay_ms > 0 {
+            sleep(Duration::from_millis(self.policy.delay_ms)).await;
+        }
+        if self.policy.unavailable_calls.contains(&call) {
+            return Some(Status::unavailable(format!("call {call} unavailable")));
+        }
+        if self.policy.dropped_calls.contains(&call) {
+            // Only the receiver kept by serve() is left when not served by it
+            if self.drops.receiver_count() <= 1 {
+                return Some(Status::unavailable(format!("call {call} dropped")));
+            }
+            self.drops.send_modify(|drops| *drops += 1);
+            // Never answered, the call is cancelled when its connection goes away
+            pending::<()>().await;
+        }
+        None
+    }
+}
+
+#[tonic::async_trait]
+impl<S: ConfigService> ConfigService for FaultyConfigService<S> {
+    async fn get_config(
+        &self,
+        request: Request<GetConfigRequest>,
+    ) -> Result<Response<GatewayConfig>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        self.inner.get_config(request).await
+    }
+
+    async fn get_config_generation(
+        &self,
+        request: Request<GetConfigGenerationRequest>,
+    ) -> Result<Response<GetConfigGenerationResponse>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        let lagging = self
+            .lagging
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+            .filter(|(_, until)| Instant::now() < *until);
+        match lagging {
+            Some((previous, _)) => Ok(Response::new(previous)),
+            None => self.inner.get_config_generation(request).await,
+        }
+    }
+
+    async fn update_config(
+        &self,
+        request: Request<UpdateConfigRequest>,
+    ) -> Result<Response<UpdateConfigResponse>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        let update = self.updates.fetch_add(1, Ordering::Relaxed) + 1;
+        if self.policy.apply_failed_updates.contains(&update) {
+            let reason = format!("update {update} failed to apply");
+            let results = request.get_ref().config.as_ref().map_or(vec![], |config| {
+                object_results(config, ApplyState::Skipped, &reason)
+            });
+            return Ok(Response::new(UpdateConfigResponse {
+                results,
+                ..update_response(Error::ApplyFailed, reason)
+            }));
+        }
+        if self.policy.generation_lag_ms == 0 {
+            return self.inner.update_config(request).await;
+        }
+        let previous = self
+            .inner
+            .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+            .await?
+            .into_inner();
+        let response = self.inner.update_config(request).await?;
+        if response.get_ref().error() == Error::None {
+            let until = Instant::now() + Duration::from_millis(self.policy.generation_lag_ms);
+            let mut lagging = self
+                .lagging
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            // Still reporting an older generation, keep it
+            let previous = match lagging.take() {
+                Some((older, end)) if Instant::now() < end => older,
+                _ => previous,
+            };
+            *lagging = Some((previous, until));
+        }
+        Ok(response)
+    }
+
+    async fn get_status(
+        &self,
+        request: Request<GetStatusRequest>,
+    ) -> Result<Response<GetStatusResponse>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        self.inner.get_status(request).await
+    }
+
+    async fn get_counters(
+        &self,
+        request: Request<GetCountersRequest>,
+    ) -> Result<Response<GetCountersResponse>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        self.inner.get_counters(request).await
+    }
+
+    async fn set_log_level(
+        &self,
+        request: Request<SetLogLevelRequest>,
+    ) -> Result<Response<SetLogLevelResponse>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        self.inner.set_log_level(request).await
+    }
+
+    async fn acquire_config_lock(
+        &self,
+        request: Request<AcquireConfigLockRequest>,
+    ) -> Result<Response<AcquireConfigLockResponse>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        self.inner.acquire_config_lock(request).await
+    }
+
+    async fn release_config_lock(
+        &self,
+        request: Request<ReleaseConfigLockRequest>,
+    ) -> Result<Response<ReleaseConfigLockResponse>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        self.inner.release_config_lock(request).await
+    }
+
+    async fn confirm_config(
+        &self,
+        request: Request<ConfirmConfigRequest>,
+    ) -> Result<Response<ConfirmConfigResponse>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        self.inner.confirm_config(request).await
+    }
+
+    async fn get_operation(
+        &self,
+        request: Request<GetOperationRequest>,
+    ) -> Result<Response<ApplyOperation>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        self.inner.get_operation(request).await
+    }
+
+    async fn wait_operation(
+        &self,
+        request: Request<WaitOperationRequest>,
+    ) -> Result<Response<ApplyOperation>, Status> {
+        if let Some(status) = self.inject().await {
+            return Err(status);
+        }
+        self.inner.wait_operation(request).await
+    }
+}
+
+/// Accepted connection that fails all I/O once connections are dropped.
+struct DroppableStream {
+    stream: TcpStream,
+    dropped: Pin<Box<dyn Future<Output = ()> + Send>>,
+    closed: bool,
+}
+
+impl DroppableStream {
+    fn new(stream: TcpStream, mut drops: watch::Receiver<u64>) -> Self {
+        drops.borrow_and_update();
+        let dropped = Box::pin(async move {
+            if drops.changed().await.is_err() {
+                pending::<()>().await;
+            }
+        });
+        Self {
+            stream,
+            dropped,
+            closed: false,
+        }
+    }
+
+    fn check(&mut self, cx: &mut Context<'_>) -> io::Result<()> {
+        if !self.closed && self.dropped.as_mut().poll(cx).is_ready() {
+            self.closed = true;
+        }
+        if self.closed {
+            return Err(io::ErrorKind::ConnectionReset.into());
+        }
+        Ok(())
+    }
+}
+
+impl Connected for DroppableStream {
+    type ConnectInfo = TcpConnectInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.stream.connect_info()
+    }
+}
+
+impl AsyncRead for DroppableStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        self.check(cx)?;
+        Pin::new(&mut self.stream).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for DroppableStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        self.check(cx)?;
+        Pin::new(&mut self.stream).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::{Device, ObjectKind};
+
+    fn service(policy: FaultPolicy) -> FaultyConfigService {
+        FaultyConfigService::new(FakeConfigService::new(), policy)
+    }
+
+    fn update(generation: i64) -> Request<UpdateConfigRequest> {
+        Request::new(UpdateConfigRequest {
+            config: Some(GatewayConfig {
+                generation,
+                ..GatewayConfig::default()
+            }),
+            expected_generation: None,
+            owner: String::new(),
+            confirm_timeout_seconds: 0,
+            async_apply: false,
+        })
+    }
+
+    async fn generation(service: &FaultyConfigService) -> Result<i64, Status> {
+        Ok(service
+            .get_config_generation(Request::new(GetConfigGenerationRequest {}))
+            .await?
+            .into_inner()
+            .generation)
+    }
+
+    #[tokio::test]
+    async fn test_apply_failed_and_unavailable() {
+        let service = service(FaultPolicy {
+            apply_failed_updates: vec![2],
+            unavailable_calls: vec![1, 4],
+            ..FaultPolicy::default()
+        });
+        let status = service.update_config(update(1)).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        let response = service.update_config(update(2)).await.unwrap().into_inner();
+        assert_eq!(response.error(), Error::None);
+        let mut failed = update(3);
+        if let Some(config) = failed.get_mut().config.as_mut() {
+            config.device = Some(Device {
+                hostname: "gw-1".to_string(),
+                ..Device::default()
+            });
+        }
+        let response = service.update_config(failed).await.unwrap().into_inner();
+        assert_eq!(response.error(), Error::ApplyFailed);
+        let device = response.result(ObjectKind::Device, "gw-1").unwrap();
+        assert_eq!(device.state(), ApplyState::Skipped);
+        let status = generation(&service).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        assert_eq!(generation(&service).await.unwrap(), 2);
+        assert_eq!(service.inner().config().await.generation, 2);
+    }
+
+    #[tokio::test]
+    async fn test_delay_and_generation_lag() {
+        let service = service(FaultPolicy {
+            delay_ms: 20,
+            generation_lag_ms: 300,
+            ..FaultPolicy::default()
+        });
+        let start = Instant::now();
+        service.update_config(update(1)).await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(20));
+        service.update_config(update(2)).await.unwrap();
+        assert_eq!(generation(&service).await.unwrap(), 0);
+        sleep(Duration::from_millis(300)).await;
+        assert_eq!(generation(&service).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_call_not_served() {
+        let service = service(FaultPolicy {
+            dropped_calls: vec![1],
+            ..FaultPolicy::default()
+        });
+        let status = generation(&service).await.unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+        assert_eq!(generation(&service).await.unwrap(), 0);
+    }
+
+    #[test]
+    fn test_load_policy() {
+        let path = std::env::temp_dir().join(format!("policy-{}.json", std::process::id()));
+        std::fs::write(&path, r#"{"delay_ms": 5, "dropped_calls": [3]}"#).unwrap();
+        assert_eq!(
+            FaultPolicy::load(&path).unwrap(),
+            FaultPolicy {
+                delay_ms: 5,
+                dropped_calls: vec![3],
+                ..FaultPolicy::default()
+            }
+        );
+        std::fs::write(&path, r#"{"delay_ms": "slow"}"#).unwrap();
+        assert!(matches!(
+            FaultPolicy::load(&path),
+            Err(PolicyError::Parse(_))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
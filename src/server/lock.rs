@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+
+use crate::config::{
+    AcquireConfigLockRequest, AcquireConfigLockResponse, Error, ReleaseConfigLockRequest,
+    ReleaseConfigLockResponse,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LockError {
+    #[error("Lock owner must not be empty")]
+    EmptyOwner,
+    #[error("Lock lease must not be zero")]
+    ZeroLease,
+    #[error("Config is locked by {0}")]
+    Locked(String),
+}
+
+impl LockError {
+    /// Error code reported for this error.
+    #[must_use]
+    pub fn code(&self) -> Error {
+        match self {
+            LockError::EmptyOwner | LockError::ZeroLease => Error::ValidationFailed,
+            LockError::Locked(_) => Error::Locked,
+        }
+    }
+}
+
+/// Holder of the config lock and when its lease ends.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigLock {
+    pub owner: String,
+    pub expires_at: SystemTime,
+}
+
+impl ConfigLock {
+    #[must_use]
+    pub fn expires_at_ms(&self) -> u64 {
+        let ms = self
+            .expires_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        u64::try_from(ms).unwrap_or(u64::MAX)
+    }
+}
+
+/// Exclusive config lock with lease expiry, for servers implementing the
+/// `AcquireConfigLock` and `ReleaseConfigLock` RPCs.
+///
+/// Clones share the same lock.
+#[derive(Debug, Clone, Default)]
+pub struct LockManager {
+    lock: Arc<Mutex<Option<ConfigLock>>>,
+}
+
+impl LockManager {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `f` on the lock, cleared first if its lease has expired.
+    fn with_lock<T>(&self, f: impl FnOnce(&mut Option<ConfigLock>) -> T) -> T {
+        let mut lock = self
+            .lock
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if lock
+            .as_ref()
+            .is_some_and(|lock| lock.expires_at <= SystemTime::now())
+        {
+            *lock = None;
+        }
+        f(&mut lock)
+    }
+
+    /// The current holder of the lock, if any.
+    #[must_use]
+    pub fn holder(&self) -> Option<ConfigLock> {
+        self.with_lock(|lock| lock.clone())
+    }
+
+    /// Take the lock for `owner`, or renew it if `owner` already holds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request is invalid or another owner holds the lock.
+    pub fn acquire(&self, owner: &str, lease: Duration) -> Result<ConfigLock, LockError> {
+        if owner.is_empty() {
+            return Err(LockError::EmptyOwner);
+        }
+        if lease.is_zero() {
+            return Err(LockError::ZeroLease);
+        }
+        self.with_lock(|lock| match lock {
+            Some(held) if held.owner != owner => Err(LockError::Locked(held.owner.clone())),
+            _ => {
+                let acquired = ConfigLock {
+                    owner: owner.to_string(),
+                    expires_at: SystemTime::now() + lease,
+                };
+                *lock = Some(acquired.clone());
+                Ok(acquired)
+            }
+        })
+    }
+
+    /// Give up the lock held by `owner`, releasing a lock that is not held is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if another owner holds the lock.
+    pub fn release(&self, owner: &str) -> Result<(), LockError> {
+        self.with_lock(|lock| match lock {
+            Some(held) if held.owner != owner => Err(LockError::Locked(held.owner.clone())),
+            _ => {
+                *lock = None;
+                Ok(())
+            }
+        })
+    }
+
+    /// Whether `owner` may update the config, i.e. holds the lock or nobody does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if another owner holds the lock.
+    pub fn check_writer(&self, owner: &str) -> Result<(), LockError> {
+        self.with_lock(|lock| match lock {
+            Some(held) if held.owner != owner => Err(LockError::Locked(held.owner.clone())),
+            _ => Ok(()),
+        })
+    }
+
+    /// Handle an `AcquireConfigLock` request.
+    #[must_use]
+    pub fn handle_acquire(&self, request: &AcquireConfigLockRequest) -> AcquireConfigLockResponse {
+        let lease = Duration::from_secs(request.lease_seconds.into());
+        let result = self.acquire(&request.owner, lease);
+        let holder = self.holder();
+        AcquireConfigLockResponse {
+            error: result
+                .as_ref()
+                .map_or_else(LockError::code, |_| Error::None)
+                .into(),
+            message: result.err().map(|e| e.to_string()).unwrap_or_default(),
+            owner: holder
+                .as_ref()
+                .map(|lock| lock.owner.clone())
+                .unwrap_or_default(),
+            expires_at_ms: holder.as_ref().map_or(0, ConfigLock::expires_at_ms),
+        }
+    }
+
+    /// Handle a `ReleaseConfigLock` request.
+    #[must_use]
+    pub fn handle_release(&self, request: &ReleaseConfigLockRequest) -> ReleaseConfigLockResponse {
+        match self.release(&request.owner) {
+            Ok(()) => ReleaseConfigLockResponse {
+                error: Error::None.into(),
+                message: String::new(),
+            },
+            Err(e) => ReleaseConfigLockResponse {
+                error: e.code().into(),
+                message: e.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_lock_manager() {
+        let locks = LockManager::new();
+        let lease = Duration::from_secs(60);
+        assert_eq!(locks.check_writer(""), Ok(()));
+        assert_eq!(locks.acquire("", lease), Err(LockError::EmptyOwner));
+        assert_eq!(
+            locks.acquire("a", Duration::ZERO),
+            Err(LockError::ZeroLease)
+        );
+
+        let lock = locks.acquire("a", lease).unwrap();
+        assert_eq!(locks.holder(), Some(lock));
+        assert_eq!(locks.check_writer("a"), Ok(()));
+        assert_eq!(
+            locks.check_writer(""),
+            Err(LockError::Locked("a".to_string()))
+        );
+        assert_eq!(
+            locks.acquire("b", lease),
+            Err(LockError::Locked("a".to_string()))
+        );
+        assert_eq!(locks.release("b"), Err(LockError::Locked("a".to_string())));
+        assert!(locks.acquire("a", lease).is_ok());
+
+        assert_eq!(locks.release("a"), Ok(()));
+        assert_eq!(locks.release("a"), Ok(()));
+        assert_eq!(locks.holder(), None);
+        assert!(locks.acquire("b", lease).is_ok());
+    }
+
+    #[test]
+    fn test_lease_expiry() {
+        let locks = LockManager::new();
+        locks.acquire("a", Duration::from_millis(20)).unwrap();
+        assert!(locks.check_writer("b").is_err());
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(locks.check_writer("b"), Ok(()));
+        assert_eq!(locks.holder(), None);
+    }
+
+    #[test]
+    fn test_handle_acquire() {
+        let locks = LockManager::new();
+        let request = |owner: &str| AcquireConfigLockRequest {
+            owner: owner.to_string(),
+            lease_seconds: 60,
+        };
+        let response = locks.handle_acquire(&request("a"));
+        assert_eq!(response.error(), Error::None);
+        assert_eq!(response.owner, "a");
+        assert!(response.expires_at_ms > 0);
+        let response = locks.handle_acquire(&request("b"));
+        assert_eq!(response.error(), Error::Locked);
+        assert_eq!(response.owner, "a");
+        assert_eq!(response.message, "Config is locked by a");
+    }
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+mod confirm;
+mod fake;
+mod faulty;
+mod lock;
+mod operation;
+mod store;
+
+pub use confirm::*;
+pub use fake::*;
+pub use faulty::*;
+pub use lock::*;
+pub use operation::*;
+pub use store::*;
+
+use crate::config::{Error, UpdateConfigResponse};
+
+pub(crate) fn update_response(error: Error, message: impl Into<String>) -> UpdateConfigResponse {
+    UpdateConfigResponse {
+        error: error.into(),
+        message: message.into(),
+        operation_id: String::new(),
+        results: Vec::new(),
+    }
+}
+
+/// `GenerationConflict` response if an update expects another generation than the
+/// `running` one.
+#[must_use]
+pub fn generation_conflict(
+    expected_generation: Option<i64>,
+    running: i64,
+) -> Option<UpdateConfigResponse> {
+    let expected = expected_generation.filter(|expected| *expected != running)?;
+    Some(update_response(
+        Error::GenerationConflict,
+        format!("expected generation {expected}, running generation is {running}"),
+    ))
+}
@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use thiserror::Error;
+use tokio::sync::watch;
+use tokio::time::Instant;
+
+use crate::config::{ApplyOperation, Error, ObjectResult, OperationState, SectionResult};
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum OperationError {
+    #[error("No operation {0}")]
+    NotFound(String),
+}
+
+/// Number of finished operations an [`OperationTracker`] keeps by default.
+pub const RETAINED_OPERATIONS: usize = 1000;
+
+#[derive(Debug, Default)]
+struct Operations {
+    by_id: HashMap<String, ApplyOperation>,
+    /// Ids of the finished operations, oldest first
+    finished: VecDeque<String>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    operations: Mutex<Operations>,
+    next_id: AtomicU64,
+    changed: watch::Sender<()>,
+    retained: usize,
+}
+
+/// Long-running `UpdateConfig` operations, for servers implementing `async_apply` and
+/// the `GetOperation` and `WaitOperation` RPCs.
+///
+/// Clones share the same operations. Running operations are kept until they finish, then
+/// only the most recently finished ones are kept.
+#[derive(Debug, Clone)]
+pub struct OperationTracker {
+    shared: Arc<Shared>,
+}
+
+impl Default for OperationTracker {
+    fn default() -> Self {
+        Self::with_retention(RETAINED_OPERATIONS)
+    }
+}
+
+impl OperationTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracker keeping at most `retained` finished operations, the oldest are forgotten.
+    #[must_use]
+    pub fn with_retention(retained: usize) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                operations: Mutex::new(Operations::default()),
+                next_id: AtomicU64::new(1),
+                changed: watch::channel(()).0,
+                retained,
+            }),
+        }
+    }
+
+    fn operations(&self) -> MutexGuard<'_, Operations> {
+        self.shared
+            .operations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Run `f` on operation `id` and wake up waiters, a no-op if there is no such operation.
+    fn modify(&self, id: &str, f: impl FnOnce(&mut ApplyOperation)) {
+        if let Some(operation) = self.operations().by_id.get_mut(id) {
+            f(operation);
+            self.shared.changed.send_replace(());
+        }
+    }
+
+    /// Track a new running operation applying `generation`, returns its id.
+    #[must_use]
+    pub fn start(&self, generation: i64) -> String {
+        let id = format!("op-{}", self.shared.next_id.fetch_add(1, Ordering::Relaxed));
+        let operation = ApplyOperation {
+            id: id.clone(),
+            generation,
+            state: OperationState::Running.into(),
+            ..ApplyOperation::default()
+        };
+        self.operations().by_id.insert(id.clone(), operation);
+        id
+    }
+
+    /// Record that a section of operation `id` was applied, `progress_percent` of the
+    /// whole config being done.
+    pub fn report(&self, id: &str, section: SectionResult, progress_percent: u32) {
+        self.modify(id, |operation| {
+            operation.sections.push(section);
+            operation.progress_percent = progress_percent.min(100);
+        });
+    }
+
+    /// Mark operation `id` done with its final verdict and per-object results, forgetting
+    /// the oldest finished operation if more than the retained number are finished.
+    pub fn finish(
+        &self,
+        id: &str,
+        error: Error,
+        message: impl Into<String>,
+        results: Vec<ObjectResult>,
+    ) {
+        let mut operations = self.operations();
+        let Some(operation) = operations.by_id.get_mut(id) else {
+            return;
+        };
+        if operation.state() == OperationState::Done {
+            return;
+        }
+        operation.set_state(OperationState::Done);
+        operation.progress_percent = 100;
+        operation.set_error(error);
+        operation.message = message.into();
+        operation.results = results;
+        operations.finished.push_back(id.to_string());
+        while operations.finished.len() > self.shared.retained {
+            if let Some(oldest) = operations.finished.pop_front() {
+                operations.by_id.remove(&oldest);
+            }
+        }
+        self.shared.changed.send_replace(());
+    }
+
+    /// Start an operation applying `generation` and run `apply` for it in a new task,
+    /// returns the operation id. `apply` reports sections through the tracker and
+    /// returns the final verdict and per-object results.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn spawn<F, Fut>(&self, generation: i64, apply: F) -> String
+    where
+        F: FnOnce(OperationTracker, String) -> Fut + Send + 'static,
+        Fut: Future<Output = (Error, String, Vec<ObjectResult>)> + Send,
+    {
+        let id = self.start(generation);
+        let (tracker, task_id) = (self.clone(), id.clone());
+        tokio::spawn(async move {
+            let (error, message, results) = apply(tracker.clone(), task_id.clone()).await;
+            tracker.finish(&task_id, error, message, results);
+        });
+        id
+    }
+
+    /// Current state of operation `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such operation.
+    pub fn get(&self, id: &str) -> Result<ApplyOperation, OperationError> {
+        self.operations()
+            .by_id
+            .get(id)
+            .cloned()
+            .ok_or_else(|| OperationError::NotFound(id.to_string()))
+    }
+
+    /// State of operation `id` once it is done, or when `timeout` expires.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such operation.
+    pub async fn wait(
+        &self,
+        id: &str,
+        timeout: Duration,
+    ) -> Result<ApplyOperation, OperationError> {
+        let deadline = Instant::now() + timeout;
+        let mut changed = self.shared.changed.subscribe();
+        loop {
+            changed.borrow_and_update();
+            let operation = self.get(id)?;
+            if operation.state() == OperationState::Done
+                || tokio::time::timeout_at(deadline, changed.changed())
+                    .await
+                    .is_err()
+            {
+                return Ok(operation);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn section(name: &str) -> SectionResult {
+        SectionResult {
+            section: name.to_string(),
+            error: Error::None.into(),
+            message: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_operation_tracker() {
+        let operations = OperationTracker::new();
+        assert_eq!(
+            operations.get("op-0"),
+            Err(OperationError::NotFound("op-0".to_string()))
+        );
+
+        let id = operations.start(2);
+        let operation = operations.get(&id).unwrap();
+        assert_eq!(operation.generation, 2);
+        assert_eq!(operation.state(), OperationState::Running);
+        operations.report(&id, section("device"), 50);
+        let operation = operations
+            .wait(&id, Duration::from_millis(20))
+            .await
+            .unwrap();
+        assert_eq!(operation.state(), OperationState::Running);
+        assert_eq!(operation.progress_percent, 50);
+        assert_eq!(operation.sections, vec![section("device")]);
+
+        let failed = ObjectResult {
+            name: "vpc-1".to_string(),
+            ..ObjectResult::default()
+        };
+        operations.finish(&id, Error::ApplyFailed, "boom", vec![failed.clone()]);
+        let operation = operations.get(&id).unwrap();
+        assert_eq!(operation.state(), OperationState::Done);
+        assert_eq!(operation.error(), Error::ApplyFailed);
+        assert_eq!(operation.message, "boom");
+        assert_eq!(operation.results, vec![failed]);
+        assert_eq!(operation.progress_percent, 100);
+    }
+
+    #[test]
+    fn test_operation_retention() {
+        let operations = OperationTracker::with_retention(2);
+        let ids = (0..4).map(|_| operations.start(1)).collect::<Vec<_>>();
+        for id in &ids[..3] {
+            operations.finish(id, Error::None, "", vec![]);
+        }
+        // Finishing twice does not count twice
+        operations.finish(&ids[2], Error::None, "", vec![]);
+        assert_eq!(
+            operations.get(&ids[0]),
+            Err(OperationError::NotFound(ids[0].clone()))
+        );
+        assert_eq!(operations.get(&ids[1]).unwrap().state(), OperationState::Done);
+        assert_eq!(operations.get(&ids[2]).unwrap().state(), OperationState::Done);
+        // Running operations are never forgotten
+        assert_eq!(
+            operations.get(&ids[3]).unwrap().state(),
+            OperationState::Running
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spawned_operation() {
+        let operations = OperationTracker::new();
+        let id = operations.spawn(3, |operations, id| async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            operations.report(&id, section("underlay"), 100);
+            (Error::None, String::new(), vec![])
+        });
+        assert_eq!(
+            operations.get(&id).unwrap().state(),
+            OperationState::Running
+        );
+        let operation = operations.wait(&id, Duration::from_secs(5)).await.unwrap();
+        assert_eq!(operation.state(), OperationState::Done);
+        assert_eq!(operation.error(), Error::None);
+        assert_eq!(operation.sections, vec![section("underlay")]);
+    }
+}
@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use prost::Message;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::config::GatewayConfig;
+
+const DIGEST_LEN: usize = 32;
+
+#[derive(Debug, Error)]
+pub enum StoreError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Stored config {0} is corrupt")]
+    Corrupt(PathBuf),
+}
+
+/// File holding the last accepted config of a server, so that it survives restarts.
+///
+/// The file is the SHA-256 digest of the protobuf encoded config followed by the config.
+/// It is replaced atomically by writing a temporary file next to it and renaming it, a
+/// temporary file left by an interrupted save is discarded by [`load`](Self::load).
+#[derive(Debug, Clone)]
+pub struct ConfigStore {
+    path: PathBuf,
+}
+
+impl ConfigStore {
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn temp_path(&self) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(".tmp");
+        self.path.with_file_name(name)
+    }
+
+    /// Persist `config`, replacing the stored one only once it is fully on disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the config cannot be written, the stored one is then unchanged.
+    pub fn save(&self, config: &GatewayConfig) -> Result<(), StoreError> {
+        let encoded = config.encode_to_vec();
+        let temp = self.temp_path();
+        let mut file = File::create(&temp)?;
+        file.write_all(&Sha256::digest(&encoded))?;
+        file.write_all(&encoded)?;
+        file.sync_all()?;
+        fs::rename(&temp, &self.path)?;
+        // Make the rename itself durable
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            File::open(dir)?.sync_all()?;
+        }
+        Ok(())
+    }
+
+    /// The stored config, `None` if nothing was saved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or does not hold a complete config.
+    pub fn load(&self) -> Result<Option<GatewayConfig>, StoreError> {
+        match fs::remove_file(self.temp_path()) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e.into()),
+            _ => {}
+        }
+        let data = match fs::read(&self.path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let corrupt = || StoreError::Corrupt(self.path.clone());
+        if data.len() < DIGEST_LEN {
+            return Err(corrupt());
+        }
+        let (digest, encoded) = data.split_at(DIGEST_LEN);
+        if Sha256::digest(encoded).as_slice() != digest {
+            return Err(corrupt());
+        }
+        GatewayConfig::decode(encoded)
+            .map(Some)
+            .map_err(|_| corrupt())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builder::GatewayConfigBuilder;
+
+    fn store(name: &str) -> ConfigStore {
+        let dir = std::env::temp_dir().join(format!("store-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        ConfigStore::new(dir.join("config.pb"))
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let store = store("save");
+        assert!(store.load().unwrap().is_none());
+        let config = GatewayConfigBuilder::new()
+            .generation(5)
+            .vrf("default")
+            .vpc("vpc-1", 100)
+            .build()
+            .unwrap();
+        store.save(&config).unwrap();
+        assert_eq!(store.load().unwrap(), Some(config.clone()));
+
+        let newer = GatewayConfig {
+            generation: 6,
+            ..config
+        };
+        store.save(&newer).unwrap();
+        assert_eq!(store.load().unwrap(), Some(newer));
+        fs::remove_dir_all(store.path().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_torn_write() {
+        let store = store("torn");
+        let config = GatewayConfig {
+            generation: 3,
+            ..GatewayConfig::default()
+        };
+        store.save(&config).unwrap();
+
+        // Interrupted before the rename
+        fs::write(store.temp_path(), b"partial").unwrap();
+        assert_eq!(store.load().unwrap(), Some(config));
+        assert!(!store.temp_path().exists());
+
+        // Truncated in place
+        let data = fs::read(store.path()).unwrap();
+        fs::write(store.path(), &data[..data.len() - 1]).unwrap();
+        assert!(matches!(store.load(), Err(StoreError::Corrupt(_))));
+        fs::remove_dir_all(store.path().parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use crate::config::{
+    BgpNeighborStatus, BgpSessionState, GetStatusResponse, InterfaceStatus, OperState,
+};
+
+impl GetStatusResponse {
+    /// Status of the interface with the given name.
+    #[must_use]
+    pub fn interface(&self, name: &str) -> Option<&InterfaceStatus> {
+        self.interfaces.iter().find(|iface| iface.name == name)
+    }
+
+    /// Interfaces that are not operationally up.
+    pub fn interfaces_not_up(&self) -> impl Iterator<Item = &InterfaceStatus> {
+        self.interfaces
+            .iter()
+            .filter(|iface| iface.oper_state() != OperState::Up)
+    }
+
+    /// Status of the BGP session with the given neighbor in the given VRF.
+    #[must_use]
+    pub fn bgp_neighbor(&self, vrf: &str, address: &str) -> Option<&BgpNeighborStatus> {
+        self.bgp_neighbors
+            .iter()
+            .find(|neighbor| neighbor.vrf == vrf && neighbor.address == address)
+    }
+
+    /// BGP sessions that are not established.
+    pub fn bgp_neighbors_not_established(&self) -> impl Iterator<Item = &BgpNeighborStatus> {
+        self.bgp_neighbors
+            .iter()
+            .filter(|neighbor| neighbor.state() != BgpSessionState::Established)
+    }
+
+    /// Whether all interfaces, BGP sessions, the VTEP and its VNIs are up.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.interfaces_not_up().next().is_none()
+            && self.bgp_neighbors_not_established().next().is_none()
+            && self.vtep.as_ref().is_none_or(|vtep| {
+                vtep.oper_state() == OperState::Up
+                    && vtep
+                        .vnis
+                        .iter()
+                        .all(|vni| vni.oper_state() == OperState::Up)
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::config::{VniStatus, VtepStatus};
+
+    #[test]
+    fn test_status_helpers() {
+        let mut status = GetStatusResponse {
+            generation: 1,
+            interfaces: vec![
+                InterfaceStatus {
+                    name: "eth0".to_string(),
+                    oper_state: OperState::Up.into(),
+                    counters: None,
+                },
+                InterfaceStatus {
+                    name: "eth1".to_string(),
+                    oper_state: OperState::Up.into(),
+                    counters: None,
+                },
+            ],
+            bgp_neighbors: vec![BgpNeighborStatus {
+                vrf: "default".to_string(),
+                address: "10.0.0.1".to_string(),
+                state: BgpSessionState::Established.into(),
+                ..BgpNeighborStatus::default()
+            }],
+            vtep: Some(VtepStatus {
+                address: "10.1.0.1".to_string(),
+                oper_state: OperState::Up.into(),
+                vnis: vec![VniStatus {
+                    vni: 100,
+                    vpc: "vpc-1".to_string(),
+                    oper_state: OperState::Up.into(),
+                    remote_vteps: vec![],
+                }],
+            }),
+        };
+        assert!(status.is_healthy());
+        assert!(status.interface("eth1").is_some());
+        assert!(status.bgp_neighbor("default", "10.0.0.1").is_some());
+        assert!(status.bgp_neighbor("other", "10.0.0.1").is_none());
+
+        status.interfaces[1].oper_state = OperState::Down.into();
+        status.bgp_neighbors[0].state = BgpSessionState::Active.into();
+        assert!(!status.is_healthy());
+        assert_eq!(
+            status
+                .interfaces_not_up()
+                .map(|iface| iface.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["eth1"]
+        );
+        assert_eq!(status.bgp_neighbors_not_established().count(), 1);
+
+        status.interfaces[1].oper_state = OperState::Up.into();
+        status.bgp_neighbors[0].state = BgpSessionState::Established.into();
+        if let Some(vtep) = status.vtep.as_mut() {
+            vtep.vnis[0].oper_state = OperState::Unknown.into();
+        }
+        assert!(!status.is_healthy());
+    }
+}
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: Apache-2.0
+// Copyright 2025 Hedgehog
+
+use thiserror::Error;
+
+use crate::config::{GatewayConfig, IfType, Interface};
+use crate::device::{check_device, check_ports};
+use crate::external::check_external;
+use crate::overlay::check_overlay;
+use crate::prefix::Prefix;
+
+/// A single semantic problem found in a [`GatewayConfig`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ValidationError {
+    #[error("Unknown value for enum {0}: {1}")]
+    UnknownEnumValue(&'static str, i32),
+    #[error("Invalid CIDR: {0}")]
+    InvalidCidr(String),
+    #[error("Invalid port range: {0}-{1}")]
+    InvalidPortRange(u32, u32),
+    #[error("Invalid IP address: {0}")]
+    InvalidIpAddress(String),
+    #[error("{0} refers to unknown VPC {1}")]
+    UnknownVpc(String, String),
+    #[error("Interface {0} has a VLAN ID but is not a VLAN interface")]
+    VlanOnNonVlanInterface(String),
+
+    // Device
+    #[error("Invalid CPU list {0:?}: {1}")]
+    InvalidCpuList(String, String),
+    #[error("Main lcore {0} is not in lcore list {1:?}")]
+    MainLcoreNotInList(u32, String),
+    #[error("Main lcore {0} is set without an lcore list")]
+    MainLcoreWithoutLcores(u32),
+    #[error("Number of memory channels must not be zero")]
+    ZeroMemoryChannels,
+    #[error("Invalid PCI address: {0}")]
+    InvalidPciAddress(String),
+    #[error("Duplicate PCI address: {0}")]
+    DuplicatePciAddress(String),
+    #[error("Duplicate port name: {0}")]
+    DuplicatePortName(String),
+    #[error("Port {0} has no PCI address, required by the DPDK driver")]
+    MissingPciAddress(String),
+    #[error("PCI address {1} of port {0} is not in the EAL PCI allow list")]
+    PortNotInPciAllowList(String, String),
+    #[error("Port {0} must have at least one RX and TX queue")]
+    ZeroQueues(String),
+    #[error("Descriptor ring size of port {0} is not a power of two: {1}")]
+    InvalidRingSize(String, u32),
+    #[error("Port {0} refers to unknown interface {1}")]
+    PortInterfaceNotFound(String, String),
+    #[error("Interface {0} is used by more than one port")]
+    InterfaceOnMultiplePorts(String),
+    #[error("Ethernet interface {0} has no port, required by the DPDK driver")]
+    InterfaceWithoutPort(String),
+
+    // Overlay
+    #[error("Duplicate VNI: {0}")]
+    DuplicateVni(u32),
+    #[error("Expose in peering {0} has both static and stateful NAT")]
+    StaticAndStatefulNat(String),
+    #[error("Stateful NAT pool in peering {0} is empty")]
+    EmptyNatPool(String),
+    #[error("Stateful NAT pool in peering {0} has prefix {1} of another address family")]
+    NatPoolFamilyMismatch(String, String),
+    #[error("Stateful NAT pool in peering {0} has {1} addresses, more than the {2} it hides")]
+    NatPoolTooLarge(String, u128, u128),
+    #[error("Stateful NAT in peering {0} has a zero idle timeout")]
+    ZeroIdleTimeout(String),
+    #[error("L4 filter in peering {0} has ports but is not for TCP or UDP")]
+    PortsWithoutTcpUdp(String),
+    #[error("L4 filter in peering {0} has ICMP types but is not for ICMP")]
+    IcmpTypesWithoutIcmp(String),
+    #[error("L4 filter in peering {0} has invalid ICMP type {1}")]
+    InvalidIcmpType(String, u32),
+    #[error("L4 filter in peering {0} has an ICMP version not matching the exposed addresses")]
+    IcmpFamilyMismatch(String),
+
+    // External
+    #[error("Duplicate external attachment name: {0}")]
+    DuplicateExternalAttachment(String),
+    #[error("External attachment {0} refers to unknown VRF {1}")]
+    UnknownExternalVrf(String, String),
+    #[error("External VRF {0} has no external interface")]
+    ExternalVrfWithoutInterface(String),
+    #[error("External interface {0} is in {1} which is not an external VRF")]
+    ExternalInterfaceOutsideExternalVrf(String, String),
+}
+
+/// All interfaces in the config, underlay VRF interfaces first and then VPC interfaces.
+pub(crate) fn all_interfaces(config: &GatewayConfig) -> impl Iterator<Item = &Interface> {
+    let underlay = config
+        .underlay
+        .iter()
+        .flat_map(|underlay| &underlay.vrfs)
+        .flat_map(|vrf| &vrf.interfaces);
+    let overlay = config
+        .overlay
+        .iter()
+        .flat_map(|overlay| &overlay.vpcs)
+        .flat_map(|vpc| &vpc.interfaces);
+    underlay.chain(overlay)
+}
+
+fn check_interfaces(config: &GatewayConfig, errors: &mut Vec<ValidationError>) {
+    for iface in all_interfaces(config) {
+        for addr in &iface.ipaddrs {
+            if let Err(e) = addr.parse::<Prefix>() {
+                errors.push(e);
+            }
+        }
+        if iface.vlan.is_some() && iface.r#type != i32::from(IfType::Vlan) {
+            errors.push(ValidationError::VlanOnNonVlanInterface(iface.name.clone()));
+        }
+    }
+}
+
+/// Check a complete gateway config for semantic errors the proto schema cannot express.
+///
+/// # Errors
+///
+/// Returns all violations found, in the order of the config sections they were found in.
+pub fn validate_config(config: &GatewayConfig) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+    if let Some(device) = &config.device {
+        check_device(device, &mut errors);
+    }
+    check_interfaces(config, &mut errors);
+    check_ports(config, &mut errors);
+    if let Some(overlay) = &config.overlay {
+        check_overlay(overlay, &mut errors);
+    }
+    check_external(config, &mut errors);
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builder::{BuildError, ExposeBuilder, GatewayConfigBuilder, InterfaceBuilder};
+
+    #[test]
+    fn test_validate_config() {
+        let mut vlan_on_ethernet = InterfaceBuilder::ethernet("eth0").build();
+        vlan_on_ethernet.vlan = Some(10);
+        let result = GatewayConfigBuilder::new()
+            .vrf("default")
+            .interface(vlan_on_ethernet)
+            .interface(InterfaceBuilder::loopback("lo").address("10.1.0.1/33"))
+            .vpc("vpc-1", 100)
+            .interface(InterfaceBuilder::vlan("vlan10", 10, "eth1").address("10.10.0.1"))
+            .vpc("vpc-2", 100)
+            .peering("vpc-1", "vpc-3")
+            .expose("vpc-1", ExposeBuilder::new().ip("10.10.0.0/24").not("bad"))
+            .build();
+        assert_eq!(
+            result,
+            Err(BuildError::Invalid(vec![
+                ValidationError::VlanOnNonVlanInterface("eth0".to_string()),
+                ValidationError::InvalidCidr("10.1.0.1/33".to_string()),
+                ValidationError::InvalidCidr("10.10.0.1".to_string()),
+                ValidationError::DuplicateVni(100),
+                ValidationError::InvalidCidr("bad".to_string()),
+                ValidationError::UnknownVpc("vpc-1--vpc-3".to_string(), "vpc-3".to_string()),
+            ]))
+        );
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+// Not every test binary uses every helper
+#![allow(dead_code)]
+
+use std::net::SocketAddr;
+
+use gateway_config::{ConfigService, ConfigServiceServer};
+use tonic::transport::Channel;
+
+/// Serve `server` on an ephemeral localhost port and return a channel connected to it.
+pub async fn serve<S: ConfigService>(server: ConfigServiceServer<S>) -> Channel {
+    let addr: SocketAddr = "[::1]:0".parse().unwrap();
+    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    let server_addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        tonic::transport::Server::builder()
+            .add_service(server)
+            .serve_with_incoming(tokio_stream::wrappers::TcpListenerStream::new(listener))
+            .await
+            .unwrap();
+    });
+
+    tonic::transport::Channel::from_shared(format!(
+        "http://[{}]:{}",
+        server_addr.ip(),
+        server_addr.port()
+    ))
+    .unwrap()
+    .connect()
+    .await
+    .unwrap()
+}
@@ -0,0 +1,524 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use gateway_config::client::GatewayClient;
+use gateway_config::config::peering_i_ps;
+use gateway_config::conformance::run_conformance;
+use gateway_config::counters::counter_rates;
+use gateway_config::server::{ConfigStore, FakeConfigService, FaultPolicy, FaultyConfigService};
+use gateway_config::{
+    ApplyState, BgpNeighbor, BgpSessionState, ComponentLogLevel, ConfigService,
+    ConfigServiceServer, Device, Error, Expose, GatewayConfig, IfType, Interface, LogComponent,
+    LogLevel, NatPool, ObjectKind, OperState, OperationState, Overlay, PeeringEntryFor, PeeringIPs,
+    RouterConfig, SetLogLevelRequest, StatefulNat, Underlay, UpdateConfigRequest, Vpc, VpcPeering,
+    Vrf,
+};
+
+fn config(generation: i64) -> GatewayConfig {
+    GatewayConfig {
+        generation,
+        underlay: Some(Underlay {
+            vrfs: vec![Vrf {
+                name: "default".to_string(),
+                interfaces: vec![
+                    Interface {
+                        name: "eth0".to_string(),
+                        r#type: IfType::Ethernet.into(),
+                        ipaddrs: vec!["10.0.0.2/31".to_string()],
+                        ..Interface::default()
+                    },
+                    Interface {
+                        name: "vtep".to_string(),
+                        r#type: IfType::Vtep.into(),
+                        ipaddrs: vec!["10.1.0.1/32".to_string()],
+                        ..Interface::default()
+                    },
+                ],
+                router: Some(RouterConfig {
+                    asn: "65000".to_string(),
+                    router_id: "10.1.0.1".to_string(),
+                    neighbors: vec![BgpNeighbor {
+                        address: "10.0.0.3".to_string(),
+                        remote_asn: "65001".to_string(),
+                        ..BgpNeighbor::default()
+                    }],
+                    ..RouterConfig::default()
+                }),
+                ospf: None,
+            }],
+        }),
+        overlay: Some(Overlay {
+            vpcs: vec![Vpc {
+                id: "vpc01".to_string(),
+                name: "vpc-1".to_string(),
+                vni: 100,
+                interfaces: vec![],
+            }],
+            peerings: vec![],
+        }),
+        ..GatewayConfig::default()
+    }
+}
+
+#[tokio::test]
+async fn test_fake_server_status() {
+    let service = Arc::new(FakeConfigService::new());
+    let channel = common::serve(ConfigServiceServer::from_arc(service.clone())).await;
+    let mut client = GatewayClient::new(channel);
+
+    let response = client.update_config(config(7)).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    assert_eq!(client.get_generation().await.unwrap(), 7);
+    assert_eq!(client.get_config().await.unwrap(), config(7));
+    assert_eq!(service.config().await, config(7));
+    assert!(client.is_running(&config(8)).await.unwrap());
+    assert!(!client.is_running(&GatewayConfig::default()).await.unwrap());
+
+    let status = client.get_status().await.unwrap();
+    assert_eq!(status.generation, 7);
+    assert!(status.is_healthy());
+    assert_eq!(
+        status.interface("eth0").unwrap().oper_state(),
+        OperState::Up
+    );
+    assert_eq!(
+        status.bgp_neighbor("default", "10.0.0.3").unwrap().state(),
+        BgpSessionState::Established
+    );
+    let vtep = status.vtep.unwrap();
+    assert_eq!(vtep.address, "10.1.0.1");
+    assert_eq!(vtep.vnis[0].vni, 100);
+
+    // Counters keep growing
+    let rx = |status: &gateway_config::GetStatusResponse| {
+        status
+            .interface("eth0")
+            .unwrap()
+            .counters
+            .unwrap()
+            .rx_packets
+    };
+    let first = rx(&client.get_status().await.unwrap());
+    let second = rx(&client.get_status().await.unwrap());
+    assert!(second > first);
+}
+
+#[tokio::test]
+async fn test_fake_server_rejects_invalid_config() {
+    let channel = common::serve(ConfigServiceServer::new(FakeConfigService::new())).await;
+    let mut client = GatewayClient::new(channel);
+
+    let mut invalid = config(1);
+    invalid.external = Some(gateway_config::External {
+        attachments: vec![gateway_config::ExternalAttachment {
+            name: "inet".to_string(),
+            vrf: "missing".to_string(),
+            ..gateway_config::ExternalAttachment::default()
+        }],
+    });
+    let response = client.update_config(invalid).await.unwrap();
+    assert_eq!(response.error(), Error::ValidationFailed);
+    assert!(response.message.contains("missing"));
+    assert_eq!(client.get_generation().await.unwrap(), 0);
+}
+
+fn expose(cidr: &str, stateful: bool) -> Expose {
+    Expose {
+        ips: vec![PeeringIPs {
+            rule: Some(peering_i_ps::Rule::Cidr(cidr.to_string())),
+        }],
+        stateful_nat: stateful.then(|| StatefulNat {
+            pool: Some(NatPool {
+                cidrs: vec!["192.168.0.1/32".to_string()],
+                ports: None,
+            }),
+            ..StatefulNat::default()
+        }),
+        ..Expose::default()
+    }
+}
+
+#[tokio::test]
+async fn test_fake_server_counters() {
+    let mut config = config(3);
+    if let Some(overlay) = config.overlay.as_mut() {
+        overlay.vpcs.push(Vpc {
+            id: "vpc02".to_string(),
+            name: "vpc-2".to_string(),
+            vni: 200,
+            interfaces: vec![],
+        });
+        overlay.peerings.push(VpcPeering {
+            name: "vpc-1--vpc-2".to_string(),
+            r#for: vec![
+                PeeringEntryFor {
+                    vpc: "vpc-1".to_string(),
+                    expose: vec![expose("10.10.0.0/24", true), expose("10.10.1.0/24", false)],
+                },
+                PeeringEntryFor {
+                    vpc: "vpc-2".to_string(),
+                    expose: vec![expose("10.20.0.0/24", false)],
+                },
+            ],
+        });
+    }
+
+    let channel = common::serve(ConfigServiceServer::new(FakeConfigService::new())).await;
+    let mut client = GatewayClient::new(channel);
+    assert_eq!(
+        client.update_config(config).await.unwrap().error(),
+        Error::None
+    );
+
+    let first = client.get_counters().await.unwrap();
+    assert_eq!(first.generation, 3);
+    let peering = first.peering("vpc-1--vpc-2").unwrap();
+    assert_eq!(peering.exposes.len(), 3);
+    assert!(peering.expose("vpc-1", 0).unwrap().nat_sessions > 0);
+    assert_eq!(peering.expose("vpc-1", 1).unwrap().nat_sessions, 0);
+    let vpc1 = first.vpc("vpc-1").unwrap();
+    assert_eq!(vpc1.nat_sessions, peering.nat_sessions_total());
+    assert_eq!(
+        vpc1.rx.unwrap().packets + first.vpc("vpc-2").unwrap().rx.unwrap().packets,
+        peering.rx_total().packets
+    );
+
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    let second = client.get_counters().await.unwrap();
+    let rates = counter_rates(&first, &second).unwrap();
+    assert!(rates.vpcs["vpc-1"].rx.packets_per_second > 0.0);
+    assert!(rates.peerings["vpc-1--vpc-2"].tx.bytes_per_second > 0.0);
+}
+
+#[tokio::test]
+async fn test_fake_server_set_log_level() {
+    let service = Arc::new(FakeConfigService::new());
+    let channel = common::serve(ConfigServiceServer::from_arc(service.clone())).await;
+    let mut client = GatewayClient::new(channel);
+
+    let response = client
+        .set_log_level(SetLogLevelRequest {
+            level: Some(LogLevel::Warning.into()),
+            components: vec![ComponentLogLevel {
+                component: LogComponent::Nat.into(),
+                level: LogLevel::Debug.into(),
+            }],
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.error(), Error::None);
+    assert_eq!(service.log_levels().await.directives(), "warn,nat=debug");
+
+    let response = client
+        .set_log_level(SetLogLevelRequest {
+            level: Some(LogLevel::Trace.into()),
+            components: vec![ComponentLogLevel {
+                component: 42,
+                level: LogLevel::Debug.into(),
+            }],
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.error(), Error::ValidationFailed);
+    assert_eq!(service.log_levels().await.directives(), "warn,nat=debug");
+    assert_eq!(client.get_generation().await.unwrap(), 0);
+
+    // An accepted update resets the levels to the config's
+    let mut update = config(1);
+    update.device = Some(Device {
+        loglevel: LogLevel::Error.into(),
+        ..Device::default()
+    });
+    let response = client.update_config(update).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    assert_eq!(service.log_levels().await.directives(), "error");
+}
+
+#[tokio::test]
+async fn test_fake_server_conformance() {
+    let service = FakeConfigService::with_config(config(41));
+    let channel = common::serve(ConfigServiceServer::new(service)).await;
+
+    let report = run_conformance(GatewayClient::new(channel)).await;
+    assert!(report.passed(), "{report}");
+}
+
+#[tokio::test]
+async fn test_faulty_server_drops_connection() {
+    let listener = tokio::net::TcpListener::bind("[::1]:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let service = FaultyConfigService::new(
+        FakeConfigService::new(),
+        FaultPolicy {
+            dropped_calls: vec![2],
+            ..FaultPolicy::default()
+        },
+    );
+    tokio::spawn(service.serve(listener));
+    let mut client = GatewayClient::connect(format!("http://{addr}"))
+        .await
+        .unwrap();
+
+    assert_eq!(client.get_generation().await.unwrap(), 0);
+    let response = client.update_config(config(1)).await;
+    assert!(response.is_err(), "{response:?}");
+    // The channel reconnects, the dropped update was never applied
+    assert_eq!(client.get_generation().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn test_fake_server_restores_config() {
+    let dir = std::env::temp_dir().join(format!("fake-server-store-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let store = ConfigStore::new(dir.join("config.pb"));
+
+    let service = FakeConfigService::with_store(store.clone()).unwrap();
+    assert_eq!(service.config().await, GatewayConfig::default());
+    let channel = common::serve(ConfigServiceServer::new(service)).await;
+    let response = GatewayClient::new(channel)
+        .update_config(config(9))
+        .await
+        .unwrap();
+    assert_eq!(response.error(), Error::None);
+
+    let restarted = FakeConfigService::with_store(store).unwrap();
+    assert_eq!(restarted.config().await, config(9));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_fake_server_restores_confirmed_config() {
+    let dir = std::env::temp_dir().join(format!("fake-server-confirm-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let store = ConfigStore::new(dir.join("config.pb"));
+
+    let service = FakeConfigService::with_store(store.clone()).unwrap();
+    let channel = common::serve(ConfigServiceServer::new(service)).await;
+    let mut client = GatewayClient::new(channel);
+    let response = client.update_config(config(1)).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    let response = client.update_config_confirmed(config(2), 60).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+
+    // Restarting before the confirmation goes back to the last confirmed config
+    let restarted = FakeConfigService::with_store(store.clone()).unwrap();
+    assert_eq!(restarted.config().await, config(1));
+
+    let response = client.confirm_config(2).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    let restarted = FakeConfigService::with_store(store).unwrap();
+    assert_eq!(restarted.config().await, config(2));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_fake_server_expected_generation() {
+    let service = Arc::new(FakeConfigService::with_config(config(1)));
+    let channel = common::serve(ConfigServiceServer::from_arc(service.clone())).await;
+    let mut client = GatewayClient::new(channel);
+
+    let response = client.update_config_if(config(3), 2).await.unwrap();
+    assert_eq!(response.error(), Error::GenerationConflict);
+    assert_eq!(service.config().await, config(1));
+    let response = client.update_config_if(config(2), 1).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+
+    // Another writer gets in before the first attempt is sent
+    let mut attempts = 0;
+    let response = client
+        .read_modify_write(3, |running| {
+            attempts += 1;
+            if attempts == 1 {
+                let request = tonic::Request::new(UpdateConfigRequest {
+                    config: Some(config(10)),
+                    expected_generation: None,
+                    owner: String::new(),
+                    confirm_timeout_seconds: 0,
+                    async_apply: false,
+                });
+                futures::executor::block_on(service.update_config(request)).unwrap();
+            }
+            running.overlay.as_mut().unwrap().vpcs[0].vni = 200;
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.error(), Error::None);
+    assert_eq!(attempts, 2);
+    let running = service.config().await;
+    assert_eq!(running.generation, 11);
+    assert_eq!(running.overlay.unwrap().vpcs[0].vni, 200);
+}
+
+#[tokio::test]
+async fn test_fake_server_config_lock() {
+    let channel = common::serve(ConfigServiceServer::new(FakeConfigService::new())).await;
+    let mut alice = GatewayClient::new(channel.clone()).with_owner("alice");
+    let mut bob = GatewayClient::new(channel.clone()).with_owner("bob");
+    let mut anonymous = GatewayClient::new(channel);
+
+    let response = alice.acquire_config_lock(60).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    assert_eq!(response.owner, "alice");
+    let response = bob.acquire_config_lock(60).await.unwrap();
+    assert_eq!(response.error(), Error::Locked);
+    assert_eq!(response.owner, "alice");
+
+    let response = bob.update_config(config(1)).await.unwrap();
+    assert_eq!(response.error(), Error::Locked);
+    let response = anonymous.update_config(config(1)).await.unwrap();
+    assert_eq!(response.error(), Error::Locked);
+    let response = alice.update_config(config(2)).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+
+    let response = bob.release_config_lock().await.unwrap();
+    assert_eq!(response.error(), Error::Locked);
+    let response = alice.release_config_lock().await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    let response = bob.update_config(config(3)).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    assert_eq!(anonymous.get_generation().await.unwrap(), 3);
+}
+
+#[tokio::test]
+async fn test_fake_server_confirm_config() {
+    let service = Arc::new(FakeConfigService::with_config(config(1)));
+    let channel = common::serve(ConfigServiceServer::from_arc(service.clone())).await;
+    let mut client = GatewayClient::new(channel);
+
+    let response = client.confirm_config(1).await.unwrap();
+    assert_eq!(response.error(), Error::GenerationConflict);
+
+    let response = client.update_config_confirmed(config(2), 1).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    let response = client.confirm_config(3).await.unwrap();
+    assert_eq!(response.error(), Error::GenerationConflict);
+    let response = client.confirm_config(2).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+
+    // Not confirmed in time, back to the last confirmed generation and its log levels
+    let mut update = config(3);
+    update.device = Some(Device {
+        loglevel: LogLevel::Debug.into(),
+        ..Device::default()
+    });
+    let response = client.update_config_confirmed(update, 1).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    assert_eq!(client.get_generation().await.unwrap(), 3);
+    assert_eq!(service.log_levels().await.directives(), "debug");
+    tokio::time::sleep(Duration::from_millis(1200)).await;
+    assert_eq!(service.config().await, config(2));
+    assert_eq!(service.log_levels().await.directives(), "error");
+    let response = client.confirm_config(3).await.unwrap();
+    assert_eq!(response.error(), Error::GenerationConflict);
+}
+
+#[tokio::test]
+async fn test_fake_server_async_update() {
+    let channel = common::serve(ConfigServiceServer::new(FakeConfigService::new())).await;
+    let mut client = GatewayClient::new(channel);
+
+    // The update returns before its sections are applied
+    let response = client.update_config_async(config(1)).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    let operation = client.get_operation(&response.operation_id).await.unwrap();
+    assert_eq!(operation.state(), OperationState::Running);
+    assert!(operation.progress_percent < 100);
+    assert!(operation.sections.len() < 3);
+    let operation = client
+        .wait_operation(&response.operation_id, 5)
+        .await
+        .unwrap();
+    assert_eq!(operation.state(), OperationState::Done);
+    assert_eq!(operation.error(), Error::None);
+    assert_eq!(operation.generation, 1);
+    assert_eq!(operation.progress_percent, 100);
+    let sections: Vec<_> = operation.sections.iter().map(|s| &s.section).collect();
+    assert_eq!(sections, ["device", "underlay", "overlay"]);
+    let results: Vec<_> = operation.results.iter().map(ToString::to_string).collect();
+    assert_eq!(results, ["VRF default applied", "VPC vpc-1 applied"]);
+    assert_eq!(client.get_generation().await.unwrap(), 1);
+
+    let response = client.update_config_async(config(2)).await.unwrap();
+    let operation = client
+        .poll_operation(
+            &response.operation_id,
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert_eq!(operation.state(), OperationState::Done);
+    assert_eq!(operation.error(), Error::None);
+    assert_eq!(operation.sections.len(), 3);
+    assert_eq!(client.get_generation().await.unwrap(), 2);
+
+    // The verdict of a rejected config is in the operation
+    let mut invalid = config(3);
+    invalid.external = Some(gateway_config::External {
+        attachments: vec![gateway_config::ExternalAttachment {
+            name: "inet".to_string(),
+            vrf: "missing".to_string(),
+            ..gateway_config::ExternalAttachment::default()
+        }],
+    });
+    let response = client.update_config_async(invalid).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    let operation = client
+        .poll_operation(
+            &response.operation_id,
+            Duration::from_millis(10),
+            Duration::from_secs(5),
+        )
+        .await
+        .unwrap();
+    assert_eq!(operation.state(), OperationState::Done);
+    assert_eq!(operation.error(), Error::ValidationFailed);
+    assert!(operation.sections.is_empty());
+    assert_eq!(client.get_generation().await.unwrap(), 2);
+
+    let status = client.get_operation("op-0").await.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::NotFound);
+}
+
+#[tokio::test]
+async fn test_fake_server_object_results() {
+    let channel = common::serve(ConfigServiceServer::new(FakeConfigService::new())).await;
+    let mut client = GatewayClient::new(channel);
+    let response = client.update_config(config(1)).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    assert!(response.apply_summary().is_complete());
+    let vpc = response.result(ObjectKind::Vpc, "vpc-1").unwrap();
+    assert_eq!(vpc.state(), ApplyState::Applied);
+    assert!(response.result(ObjectKind::Vrf, "default").is_some());
+
+    // Nothing is programmed when the config cannot be persisted
+    let store = ConfigStore::new(std::env::temp_dir().join("missing-dir/config.pb"));
+    let service = FakeConfigService::with_store(store).unwrap();
+    let channel = common::serve(ConfigServiceServer::new(service)).await;
+    let mut client = GatewayClient::new(channel);
+    let response = client.update_config(config(1)).await.unwrap();
+    assert_eq!(response.error(), Error::ApplyFailed);
+    assert_eq!(response.apply_summary().skipped, 2);
+    assert_eq!(response.results_in(ApplyState::Skipped).count(), 2);
+
+    // Async updates report the failure in every section
+    let response = client.update_config_async(config(1)).await.unwrap();
+    let operation = client
+        .wait_operation(&response.operation_id, 5)
+        .await
+        .unwrap();
+    assert_eq!(operation.error(), Error::ApplyFailed);
+    assert_eq!(operation.sections.len(), 3);
+    assert!(
+        operation
+            .sections
+            .iter()
+            .all(|section| section.error() == Error::ApplyFailed)
+    );
+    assert_eq!(operation.results.len(), 2);
+}
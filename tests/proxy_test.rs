@@ -0,0 +1,70 @@
+// Copyright 2025 Hedgehog
+// SPDX-License-Identifier: Apache-2.0
+
+mod common;
+
+use std::sync::Arc;
+
+use gateway_config::builder::GatewayConfigBuilder;
+use gateway_config::client::GatewayClient;
+use gateway_config::proxy::{Call, RecordedCall, Recorder, RecordingProxy, load_session, replay};
+use gateway_config::server::FakeConfigService;
+use gateway_config::{ConfigServiceServer, Error, GatewayConfig, Vpc};
+
+#[tokio::test]
+async fn test_record_and_replay() {
+    let path = std::env::temp_dir().join(format!("proxy-test-{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let dataplane = common::serve(ConfigServiceServer::new(FakeConfigService::new())).await;
+    let proxy = RecordingProxy::new(dataplane, Recorder::open(&path).unwrap());
+    let mut client = GatewayClient::new(common::serve(ConfigServiceServer::new(proxy)).await);
+
+    let config = GatewayConfigBuilder::new()
+        .generation(3)
+        .vrf("default")
+        .vpc("vpc-1", 100)
+        .build()
+        .unwrap();
+    let mut invalid = config.clone();
+    invalid.generation = 4;
+    let vpcs = &mut invalid.overlay.as_mut().unwrap().vpcs;
+    vpcs.push(Vpc {
+        name: "vpc-2".to_string(),
+        ..vpcs[0].clone()
+    });
+    assert_eq!(client.get_generation().await.unwrap(), 0);
+    let response = client.update_config(config.clone()).await.unwrap();
+    assert_eq!(response.error(), Error::None);
+    let response = client.update_config(invalid).await.unwrap();
+    assert_eq!(response.error(), Error::ValidationFailed);
+    assert_eq!(client.get_config().await.unwrap(), config);
+
+    let session = load_session(&path).unwrap();
+    assert_eq!(session.len(), 4);
+    assert!(
+        session
+            .windows(2)
+            .all(|w| w[0].timestamp_ms <= w[1].timestamp_ms)
+    );
+    let Call::UpdateConfig(update) = &session[1].call else {
+        panic!("unexpected call {:?}", session[1].call);
+    };
+    assert_eq!(update.request.config.as_ref(), Some(&config));
+    assert_eq!(update.response.as_ref().unwrap().error(), Error::None);
+
+    let reproduction = Arc::new(FakeConfigService::new());
+    let channel = common::serve(ConfigServiceServer::from_arc(reproduction.clone())).await;
+    let replayed = replay(&session, &mut GatewayClient::new(channel)).await;
+    let calls = |session: &[_]| {
+        session
+            .iter()
+            .map(|recorded: &RecordedCall| recorded.call.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(calls(&replayed), calls(&session));
+    assert_eq!(reproduction.config().await, config);
+    assert_ne!(reproduction.config().await, GatewayConfig::default());
+
+    std::fs::remove_file(&path).unwrap();
+}
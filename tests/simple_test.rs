@@ -28,6 +28,7 @@ impl ConfigService for SimpleConfigService {
         println!("Server received get_config_generation request");
         Ok(Response::new(GetConfigGenerationResponse {
             generation: self.generation,
+            content_hash: String::new(),
         }))
     }
 
@@ -48,6 +49,78 @@ impl ConfigService for SimpleConfigService {
             "update_config not implemented in this test",
         ))
     }
+
+    async fn get_status(
+        &self,
+        _request: Request<gateway_config::GetStatusRequest>,
+    ) -> Result<Response<gateway_config::GetStatusResponse>, Status> {
+        Err(Status::unimplemented(
+            "get_status not implemented in this test",
+        ))
+    }
+
+    async fn get_counters(
+        &self,
+        _request: Request<gateway_config::GetCountersRequest>,
+    ) -> Result<Response<gateway_config::GetCountersResponse>, Status> {
+        Err(Status::unimplemented(
+            "get_counters not implemented in this test",
+        ))
+    }
+
+    async fn set_log_level(
+        &self,
+        _request: Request<gateway_config::SetLogLevelRequest>,
+    ) -> Result<Response<gateway_config::SetLogLevelResponse>, Status> {
+        Err(Status::unimplemented(
+            "set_log_level not implemented in this test",
+        ))
+    }
+
+    async fn acquire_config_lock(
+        &self,
+        _request: Request<gateway_config::AcquireConfigLockRequest>,
+    ) -> Result<Response<gateway_config::AcquireConfigLockResponse>, Status> {
+        Err(Status::unimplemented(
+            "acquire_config_lock not implemented in this test",
+        ))
+    }
+
+    async fn release_config_lock(
+        &self,
+        _request: Request<gateway_config::ReleaseConfigLockRequest>,
+    ) -> Result<Response<gateway_config::ReleaseConfigLockResponse>, Status> {
+        Err(Status::unimplemented(
+            "release_config_lock not implemented in this test",
+        ))
+    }
+
+    async fn confirm_config(
+        &self,
+        _request: Request<gateway_config::ConfirmConfigRequest>,
+    ) -> Result<Response<gateway_config::ConfirmConfigResponse>, Status> {
+        Err(Status::unimplemented(
+            "confirm_config not implemented in this test",
+        ))
+    }
+
+    async fn get_operation(
+        &self,
+        _request: Request<gateway_config::GetOperationRequest>,
+    ) -> Result<Response<gateway_config::ApplyOperation>, Status> {
+        Err(Status::unimplemented(
+            "get_operation not implemented in this test",
+        ))
+    }
+
+    async fn wait_operation(
+        &self,
+        _request: Request<gateway_config::WaitOperationRequest>,
+    ) -> Result<Response<gateway_config::ApplyOperation>, Status> {
+        Err(Status::unimplemented(
+            "wait_operation not implemented in this test",
+        ))
+    }
 }
 
 #[tokio::test]